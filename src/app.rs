@@ -1,17 +1,37 @@
 use super::config::Config;
 use super::user_config::UserConfig;
+use crate::clients::abuseipdb::{AbuseIpCheck, AbuseIpReports};
+use crate::clients::asn::{parse_asn, AsnContext};
 use crate::clients::censys::{
-    AutonomousSystem, CensysSearchIp, Coordinates, Location as CensysLocation, OperatingSystem,
+    AutonomousSystem, CensysCertificate, CensysCertificateHosts, CensysHostEvents,
+    CensysHostSearch, CensysSearchIp, Coordinates, Location as CensysLocation, OperatingSystem,
     Result, Services,
 };
-use crate::clients::shodan::{Location as ShodanLocation, ServiceData, ShodanSearchIp};
+use crate::clients::crtsh::CrtshCertificate;
+use crate::clients::dns::{DnsRecord, PassiveDnsRecord};
+use crate::clients::geoip::GeoIpRecord;
+use crate::clients::greynoise::GreyNoiseIp;
+use crate::clients::otx::{OtxIndicator, PulseIndicators};
+use crate::clients::rdap::IpNetwork;
+use crate::clients::securitytrails::{HistoryEntry, WhoisHistory};
+use crate::clients::shodan::{
+    Location as ShodanLocation, ServiceData, ShodanHostSearch, ShodanSearchIp,
+};
+use crate::clients::threatfox::ThreatFoxSearch;
+use crate::clients::urlhaus::UrlhausLookup;
+use crate::clients::urlscan::{UrlscanResult, UrlscanSearch};
 use crate::clients::virustotal::{
-    AnalysisStats, CommentAttributes, CommentAuthor, CommentVotes, IpAddress, IpAttributes,
-    IpCommentAttributes, IpCommentData, IpComments, IpData, Votes,
+    self, AnalysisStats, CommentAuthor, CommentVotes, IpAddress, IpAttributes, IpCommentAttributes,
+    IpCommentData, IpComments, IpData, Relationship, Votes,
 };
+use crate::indicator::{self, Indicator};
 use crate::network::IoEvent;
-use std::collections::HashMap;
+use crate::watchlist::{Watchlist, WatchlistEntry};
+use ipnet::IpNet;
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
 use std::sync::mpsc::Sender;
+use std::time::Instant;
 use tui::layout::Rect;
 
 const DEFAULT_ROUTE: Route = Route {
@@ -27,29 +47,71 @@ pub enum RouteId {
     SearchResult,
     Censys,
     CensysGeoLookup,
+    CensysHistory,
+    CensysCertificate,
+    CensysSearch,
+    Dns,
+    GeoIp,
+    Rdap,
+    Abuseipdb,
+    Greynoise,
+    Otx,
+    Abusech,
+    Crtsh,
+    Bulk,
+    SecurityTrails,
+    Urlscan,
+    Asn,
+    Extract,
     Shodan,
     ShodanGeoLookup,
+    ShodanSearch,
+    ShodanServiceDetail,
     VirustotalDetection,
     VirustotalDetails,
     VirustotalCommunity,
+    VirustotalRelations,
     Unloaded,
     NotQueried,
     NotFound,
     Error,
 }
 
-pub const VIRUSTOTAL_MENU: [&str; 3] = ["Detection", "Details", "Community"];
+// Bulk lookups beyond this many indicators are cut off, to keep resolvers and APIs happy
+pub const BULK_LIMIT: usize = 256;
+
+pub const VIRUSTOTAL_MENU: [&str; 4] = ["Detection", "Details", "Community", "Relations"];
+
+pub const SEARCH_RESULT_PANELS: [&str; 4] = ["Censys", "Shodan", "VirusTotal", "GeoIP"];
 
 pub struct Virustotal {
     pub status: ResultStatus,
+    pub indicator: String,
     pub selected_index: usize,
     pub analysis_result_index: usize,
     pub whois_result_index: usize,
     pub scan_table: ScanTable,
     pub ip_whois_items: IpAddress,
     pub ip_comment_items: IpComments,
-    pub comment_authors: CommentAuthor,
+    // Authors keyed by the id of the comment they wrote
+    pub comment_authors: HashMap<String, CommentAuthor>,
+    // Comments whose author has been requested and not answered yet
+    pub comment_authors_loading: HashSet<String>,
+    // Failed author lookups keyed by comment id, selecting the comment again retries them
+    pub comment_author_errors: HashMap<String, String>,
+    pub comment_index: usize,
     pub comment_scroll: u16,
+    pub comment_pivot_index: usize,
+    // Whether the next page of comments has been requested and not answered yet
+    pub comments_loading: bool,
+    pub relationship_index: usize,
+    pub relation_item_index: usize,
+    // Relationship pages keyed by relationship name, fetched lazily as they are selected
+    pub relationships: HashMap<String, Relationship>,
+    // Relationships whose next page has been requested and not answered yet
+    pub relationships_loading: HashSet<String>,
+    // Failed relationship lookups keyed by relationship name, shown in place of their items
+    pub relationship_errors: HashMap<String, String>,
 }
 
 pub const SHODAN_MENU: [&str; 2] = ["General", "Geo-Lookup"];
@@ -58,16 +120,269 @@ pub struct Shodan {
     pub status: ResultStatus,
     pub service_index: usize,
     pub menu_index: usize,
+    pub banner_scroll: u16,
+    pub search_index: usize,
+    pub search_page: u32,
+    pub search_query: String,
     pub search_ip_items: ShodanSearchIp,
+    pub search_items: ShodanHostSearch,
+    // Whether the next page of search matches is on its way
+    pub search_loading: bool,
 }
 
-pub const CENSYS_MENU: [&str; 2] = ["Summary", "Geo-Lookup"];
+pub const CENSYS_MENU: [&str; 3] = ["Summary", "Geo-Lookup", "History"];
 
 pub struct Censys {
     pub status: ResultStatus,
     pub service_index: usize,
     pub menu_index: usize,
+    pub history_index: usize,
+    pub certificate_host_index: usize,
+    pub search_index: usize,
+    pub search_query: String,
     pub search_ip_items: CensysSearchIp,
+    pub search_items: CensysHostSearch,
+    // Whether the next page of search hits is on its way
+    pub search_loading: bool,
+    // Host the History tab was last loaded for, fetched when the tab is opened
+    pub history_host: String,
+    pub history_status: ResultStatus,
+    // Why the history could not be loaded, e.g. an account without experimental API access
+    pub history_error: Option<String>,
+    pub history_items: CensysHostEvents,
+    // Certificate the certificate view was last opened for
+    pub certificate_fingerprint: String,
+    pub certificate_status: ResultStatus,
+    // Why the certificate or the hosts presenting it could not be loaded
+    pub certificate_error: Option<String>,
+    pub certificate_items: CensysCertificate,
+    pub certificate_host_items: CensysCertificateHosts,
+}
+
+pub struct Dns {
+    pub status: ResultStatus,
+    pub records: Vec<DnsRecord>,
+    pub record_index: usize,
+    pub passive_index: usize,
+}
+
+pub struct Abuseipdb {
+    pub status: ResultStatus,
+    pub check: AbuseIpCheck,
+    pub reports: AbuseIpReports,
+    pub report_index: usize,
+    // Whether the next page of reports is on its way
+    pub reports_loading: bool,
+}
+
+pub struct Greynoise {
+    pub status: ResultStatus,
+    pub ip: GreyNoiseIp,
+}
+
+pub struct Otx {
+    pub status: ResultStatus,
+    pub indicator: OtxIndicator,
+    pub pulse_index: usize,
+    // IOCs of the pulse opened with Enter, pulses only list them on request
+    pub pulse_indicators: PulseIndicators,
+    pub pulse_indicator_index: usize,
+}
+
+/// URLhaus and ThreatFox, Found when either of them lists the indicator
+pub struct Abusech {
+    pub status: ResultStatus,
+    pub urlhaus: UrlhausLookup,
+    pub threatfox: ThreatFoxSearch,
+    // Why either lookup failed, shown in its pane and left out of the score
+    pub urlhaus_error: Option<String>,
+    pub threatfox_error: Option<String>,
+    pub ioc_index: usize,
+    pub payload_index: usize,
+}
+
+pub struct Crtsh {
+    pub status: ResultStatus,
+    pub domain: String,
+    pub certificates: Vec<CrtshCertificate>,
+    pub subdomains: Vec<String>,
+    pub certificate_index: usize,
+    pub subdomain_index: usize,
+    // Set when crt.sh timed out or failed, shown in place of the certificate list
+    pub error: Option<String>,
+}
+
+pub struct SecurityTrails {
+    pub status: ResultStatus,
+    // Past addresses of a domain, or the domains pointing at an address
+    pub entries: Vec<HistoryEntry>,
+    pub whois: WhoisHistory,
+    pub entry_index: usize,
+    pub whois_index: usize,
+}
+
+pub struct Urlscan {
+    pub status: ResultStatus,
+    pub indicator: String,
+    pub search: UrlscanSearch,
+    pub scan_index: usize,
+    // Full result of the scan opened with Enter
+    pub result: Option<UrlscanResult>,
+    // Whether the user is being asked to confirm submitting a new scan
+    pub confirm_submit: bool,
+    pub message: Option<String>,
+    // When each scan submitted from osintui was accepted, until its result comes in
+    pub submitted: HashMap<String, Instant>,
+}
+
+pub struct Asn {
+    pub status: ResultStatus,
+    // Address searched last, Team Cymru only lists the peers of an AS through one
+    pub address: Option<IpAddr>,
+    pub context: AsnContext,
+    pub prefix_index: usize,
+    pub peer_index: usize,
+    // Hosts announced by the AS that Shodan and Censys have indexed
+    pub shodan_hosts: Option<i64>,
+    pub censys_hosts: Option<i64>,
+}
+
+/// Text pasted in to pull indicators out of, and the indicators found in it
+pub struct Extract {
+    pub text: String,
+    pub indicators: Vec<Indicator>,
+    pub selected: Vec<bool>,
+    // Reserved addresses and our own infrastructure left out of `indicators`
+    pub skipped: usize,
+    pub index: usize,
+}
+
+/// Indicators queued together, each resolved and enriched from local data only
+pub struct Bulk {
+    pub source: String,
+    pub items: Vec<BulkItem>,
+    pub index: usize,
+    // Set when the queue is the expansion of a network, whose hosts get aggregated
+    pub network: Option<IpNet>,
+    // Whether the hosts of the network are being looked up on VirusTotal
+    pub verdicts_loading: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct BulkItem {
+    pub indicator: String,
+    pub status: ResultStatus,
+    pub addresses: Vec<String>,
+    pub country: Option<String>,
+    pub asn: Option<String>,
+    pub ports: Vec<u16>,
+    pub verdict: Option<String>,
+}
+
+impl BulkItem {
+    pub fn new(indicator: String) -> BulkItem {
+        BulkItem {
+            indicator,
+            status: ResultStatus::NotQueried,
+            addresses: vec![],
+            country: None,
+            asn: None,
+            ports: vec![],
+            verdict: None,
+        }
+    }
+
+    pub fn summary_to_vec(&self) -> Vec<String> {
+        let status = match self.status {
+            ResultStatus::NotQueried | ResultStatus::Loading => "Queued",
+            ResultStatus::NotFound => "No records",
+            ResultStatus::Found => "Resolved",
+        };
+
+        vec![
+            self.indicator.to_owned(),
+            status.to_owned(),
+            self.addresses.join(", "),
+            self.country.clone().unwrap_or_default(),
+            self.asn.clone().unwrap_or_default(),
+            self.ports
+                .iter()
+                .map(u16::to_string)
+                .collect::<Vec<String>>()
+                .join(", "),
+            self.verdict.clone().unwrap_or_default(),
+        ]
+    }
+}
+
+impl Bulk {
+    /// Number of hosts with each port open, most common first
+    pub fn port_counts(&self) -> Vec<(u16, usize)> {
+        let mut counts: HashMap<u16, usize> = HashMap::new();
+        for port in self.items.iter().flat_map(|item| &item.ports) {
+            *counts.entry(*port).or_default() += 1;
+        }
+
+        let mut counts = counts.into_iter().collect::<Vec<(u16, usize)>>();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        counts
+    }
+
+    /// Number of hosts given each VirusTotal verdict, most common first
+    pub fn verdict_counts(&self) -> Vec<(String, usize)> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for verdict in self.items.iter().filter_map(|item| item.verdict.clone()) {
+            *counts.entry(verdict).or_default() += 1;
+        }
+
+        let mut counts = counts.into_iter().collect::<Vec<(String, usize)>>();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        counts
+    }
+}
+
+pub struct GeoIp {
+    pub status: ResultStatus,
+    pub record: GeoIpRecord,
+}
+
+pub struct Rdap {
+    pub status: ResultStatus,
+    pub network: IpNetwork,
+    pub record_index: usize,
+    // Whether the whois pane shows VirusTotal's raw whois text instead of the RDAP record
+    pub show_raw: bool,
+}
+
+impl Abusech {
+    /// Payloads URLhaus saw served, then the samples ThreatFox tied to the selected IOC
+    pub fn payloads(&self) -> Vec<Vec<String>> {
+        let urlhaus = self.urlhaus.payloads.iter().map(|payload| {
+            vec![
+                payload.firstseen.clone().unwrap_or_default(),
+                payload.signature.clone().unwrap_or_default(),
+                payload.response_sha256.clone().unwrap_or_default(),
+                String::from("URLhaus"),
+            ]
+        });
+
+        let threatfox = self.threatfox.data.get(self.ioc_index).map(|ioc| {
+            ioc.malware_samples
+                .iter()
+                .flatten()
+                .map(|sample| {
+                    vec![
+                        sample.time_stamp.chars().take(10).collect(),
+                        ioc.malware_printable.to_owned(),
+                        sample.sha256_hash.to_owned(),
+                        String::from("ThreatFox"),
+                    ]
+                })
+                .collect::<Vec<Vec<String>>>()
+        });
+
+        urlhaus.chain(threatfox.into_iter().flatten()).collect()
+    }
 }
 
 pub struct ScanTable {
@@ -90,11 +405,56 @@ pub enum ActiveBlock {
     Input,
     CensysMenu,
     CensysServices,
+    CensysHistory,
+    CensysCertificate,
+    CensysSearchResults,
+    DnsRecords,
+    DnsPassive,
+    GeoIp,
+    GeoIpUnloaded,
+    Rdap,
+    AbuseipdbReports,
+    AbuseipdbNotFound,
+    AbuseipdbNotQueried,
+    AbuseipdbUnloaded,
+    Greynoise,
+    GreynoiseNotFound,
+    GreynoiseNotQueried,
+    GreynoiseUnloaded,
+    OtxPulses,
+    OtxPulseIndicators,
+    OtxNotFound,
+    OtxNotQueried,
+    OtxUnloaded,
+    AbusechIocs,
+    AbusechPayloads,
+    AbusechNotFound,
+    AbusechNotQueried,
+    CrtshCertificates,
+    CrtshSubdomains,
+    CrtshNotFound,
+    CrtshNotQueried,
+    BulkResults,
+    SecurityTrailsHistory,
+    SecurityTrailsWhois,
+    SecurityTrailsNotFound,
+    SecurityTrailsNotQueried,
+    SecurityTrailsUnloaded,
+    UrlscanScans,
+    UrlscanNotQueried,
+    UrlscanUnloaded,
+    AsnPrefixes,
+    AsnPeers,
+    AsnNotQueried,
+    ExtractInput,
+    ExtractResults,
     CensysNotFound,
     CensysNotQueried,
     CensysUnloaded,
     ShodanMenu,
     ShodanServices,
+    ShodanSearchResults,
+    ShodanServiceDetail,
     ShodanNotFound,
     ShodanNotQueried,
     ShodanUnloaded,
@@ -103,13 +463,20 @@ pub enum ActiveBlock {
     VirustotalResults,
     VirustotalWhois,
     VirustotalComments,
+    VirustotalCommentDetail,
+    VirustotalCommentPivots,
+    VirustotalRelationships,
+    VirustotalRelationItems,
     VirustotalNotFound,
     VirustotalNotQueried,
     VirustotalUnloaded,
 }
 
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum ResultStatus {
     NotQueried,
+    // Dispatched for the current search and not answered yet
+    Loading,
     NotFound,
     Found,
 }
@@ -119,6 +486,7 @@ pub struct App {
     pub user_config: UserConfig,
     pub client_config: Config,
     pub home_scroll: u16,
+    pub search_result_index: usize,
     pub is_loading: bool,
     pub is_input_error: bool,
     pub api_error: String,
@@ -132,9 +500,31 @@ pub struct App {
     pub input: Vec<char>,
     pub input_idx: usize,
     pub input_cursor_position: u16,
+    // Indicator searched last as sent to the providers, results for any other are dropped
+    pub query: String,
     pub censys: Censys,
     pub shodan: Shodan,
     pub virustotal: Virustotal,
+    pub rdap: Rdap,
+    pub dns: Dns,
+    pub geoip: GeoIp,
+    // Tags from the local Tor, cloud provider and user lists matching the searched address
+    pub list_tags: Vec<String>,
+    pub watchlist: Watchlist,
+    // Watchlist entries covering the searched indicator
+    pub watchlist_matches: Vec<WatchlistEntry>,
+    // Where the report of the current indicator was exported to, or why it couldn't be
+    pub export_message: Option<String>,
+    pub abuseipdb: Abuseipdb,
+    pub greynoise: Greynoise,
+    pub otx: Otx,
+    pub abusech: Abusech,
+    pub crtsh: Crtsh,
+    pub bulk: Bulk,
+    pub securitytrails: SecurityTrails,
+    pub urlscan: Urlscan,
+    pub asn: Asn,
+    pub extract: Extract,
     io_tx: Option<Sender<IoEvent>>,
 }
 
@@ -142,12 +532,22 @@ impl Default for App {
     fn default() -> Self {
         App {
             api_error: String::new(),
+            search_result_index: 0,
             virustotal: Virustotal {
                 status: ResultStatus::NotQueried,
+                indicator: String::new(),
                 selected_index: 0,
                 analysis_result_index: 0,
                 whois_result_index: 0,
+                comment_index: 0,
                 comment_scroll: 0,
+                comment_pivot_index: 0,
+                comments_loading: false,
+                relationship_index: 0,
+                relation_item_index: 0,
+                relationships: HashMap::new(),
+                relationships_loading: HashSet::new(),
+                relationship_errors: HashMap::new(),
                 scan_table: ScanTable { selected_index: 0 },
                 ip_whois_items: IpAddress {
                     data: IpData {
@@ -169,6 +569,7 @@ impl Default for App {
                                 timeout: 0,
                                 undetected: 0,
                             },
+                            ..IpAttributes::default()
                         },
                         id: String::new(),
                     },
@@ -187,22 +588,22 @@ impl Default for App {
                             },
                         },
                     }],
+                    meta: None,
                 },
-                comment_authors: CommentAuthor {
-                    data: CommentAttributes {
-                        first_name: String::new(),
-                        last_name: String::new(),
-                        profile_phrase: String::new(),
-                        status: String::new(),
-                        user_since: 0,
-                    },
-                    id: String::new(),
-                },
+                comment_authors: HashMap::new(),
+                comment_authors_loading: HashSet::new(),
+                comment_author_errors: HashMap::new(),
             },
             shodan: Shodan {
                 status: ResultStatus::NotQueried,
                 service_index: 0,
                 menu_index: 0,
+                banner_scroll: 0,
+                search_index: 0,
+                search_page: 1,
+                search_query: String::new(),
+                search_items: ShodanHostSearch::default(),
+                search_loading: false,
                 search_ip_items: ShodanSearchIp {
                     ip_str: Some(String::new()),
                     org: String::new(),
@@ -225,6 +626,12 @@ impl Default for App {
                             longitude: Some(0.0),
                             region_code: Some(String::new()),
                         }),
+                        timestamp: None,
+                        cpe: None,
+                        cpe23: None,
+                        http: None,
+                        ssl: None,
+                        vulns: None,
                     }]),
                     ports: Some(vec![0]),
                     latitude: 0.00,
@@ -232,12 +639,126 @@ impl Default for App {
                     city: Some(String::new()),
                     country_code: Some(String::new()),
                     country_name: Some(String::new()),
+                    vulns: None,
+                    tags: None,
+                    last_update: None,
                 },
             },
+            dns: Dns {
+                status: ResultStatus::NotQueried,
+                records: vec![],
+                record_index: 0,
+                passive_index: 0,
+            },
+            abuseipdb: Abuseipdb {
+                status: ResultStatus::NotQueried,
+                check: AbuseIpCheck::default(),
+                reports: AbuseIpReports::default(),
+                report_index: 0,
+                reports_loading: false,
+            },
+            greynoise: Greynoise {
+                status: ResultStatus::NotQueried,
+                ip: GreyNoiseIp::default(),
+            },
+            otx: Otx {
+                status: ResultStatus::NotQueried,
+                indicator: OtxIndicator::default(),
+                pulse_index: 0,
+                pulse_indicators: PulseIndicators::default(),
+                pulse_indicator_index: 0,
+            },
+            abusech: Abusech {
+                status: ResultStatus::NotQueried,
+                urlhaus: UrlhausLookup::default(),
+                threatfox: ThreatFoxSearch::default(),
+                urlhaus_error: None,
+                threatfox_error: None,
+                ioc_index: 0,
+                payload_index: 0,
+            },
+            crtsh: Crtsh {
+                status: ResultStatus::NotQueried,
+                domain: String::new(),
+                certificates: vec![],
+                subdomains: vec![],
+                certificate_index: 0,
+                subdomain_index: 0,
+                error: None,
+            },
+            securitytrails: SecurityTrails {
+                status: ResultStatus::NotQueried,
+                entries: vec![],
+                whois: WhoisHistory::default(),
+                entry_index: 0,
+                whois_index: 0,
+            },
+            urlscan: Urlscan {
+                status: ResultStatus::NotQueried,
+                indicator: String::new(),
+                search: UrlscanSearch::default(),
+                scan_index: 0,
+                result: None,
+                confirm_submit: false,
+                message: None,
+                submitted: HashMap::new(),
+            },
+            asn: Asn {
+                status: ResultStatus::NotQueried,
+                address: None,
+                context: AsnContext::default(),
+                prefix_index: 0,
+                peer_index: 0,
+                shodan_hosts: None,
+                censys_hosts: None,
+            },
+            extract: Extract {
+                text: String::new(),
+                indicators: vec![],
+                selected: vec![],
+                skipped: 0,
+                index: 0,
+            },
+            bulk: Bulk {
+                source: String::new(),
+                items: vec![],
+                index: 0,
+                network: None,
+                verdicts_loading: false,
+            },
+            list_tags: vec![],
+            watchlist: Watchlist::default(),
+            watchlist_matches: vec![],
+            export_message: None,
+            geoip: GeoIp {
+                status: ResultStatus::NotQueried,
+                record: GeoIpRecord::default(),
+            },
+            rdap: Rdap {
+                status: ResultStatus::NotQueried,
+                network: IpNetwork::default(),
+                record_index: 0,
+                show_raw: false,
+            },
             censys: Censys {
                 status: ResultStatus::NotQueried,
                 service_index: 0,
                 menu_index: 0,
+                history_index: 0,
+                certificate_host_index: 0,
+                search_index: 0,
+                search_query: String::new(),
+                search_items: CensysHostSearch::default(),
+                search_loading: false,
+                history_host: String::new(),
+                history_status: ResultStatus::NotQueried,
+                history_error: None,
+                history_items: CensysHostEvents::default(),
+                certificate_fingerprint: String::new(),
+                certificate_status: ResultStatus::NotQueried,
+                certificate_error: None,
+                certificate_items: CensysCertificate::default(),
+                certificate_host_items: CensysCertificateHosts::default(),
                 search_ip_items: CensysSearchIp {
                     code: 0,
                     status: String::new(),
@@ -252,6 +773,7 @@ impl Default for App {
                             transport_protocol: Some(String::new()),
                             extended_service_name: Some(String::new()),
                             certificate: Some(String::new()),
+                            labels: None,
                         }],
                         location: CensysLocation {
                             continent: Some(String::new()),
@@ -280,6 +802,7 @@ impl Default for App {
                             edition: Some(String::new()),
                             uniform_resource_identifier: Some(String::new()),
                         }),
+                        labels: None,
                     },
                 },
             },
@@ -291,6 +814,7 @@ impl Default for App {
             io_tx: None,
             home_scroll: 0,
             input_cursor_position: 0,
+            query: String::new(),
             user_config: UserConfig::new(),
             client_config: Config::new(),
             size: Rect::default(),
@@ -321,6 +845,233 @@ impl App {
         }
     }
 
+    /// Replaces the bulk queue with `indicators` and starts looking them up
+    pub fn queue_bulk_lookup(&mut self, source: String, indicators: Vec<String>) {
+        self.bulk.source = source;
+        self.bulk.items = indicators
+            .into_iter()
+            .take(BULK_LIMIT)
+            .map(BulkItem::new)
+            .collect();
+        self.bulk.index = 0;
+        self.bulk.network = None;
+        self.bulk.verdicts_loading = false;
+
+        self.dispatch(IoEvent::BulkLookup);
+        self.push_navigation_stack(RouteId::Bulk, ActiveBlock::BulkResults);
+    }
+
+    /// Lists the indicators worth looking up in the pasted text
+    pub fn extract_indicators(&mut self) {
+        let watchlist = self.watchlist.clone();
+        let (indicators, skipped) =
+            indicator::triage(&self.extract.text, |indicator| watchlist.is_ours(indicator));
+
+        self.extract.selected = vec![false; indicators.len()];
+        self.extract.indicators = indicators;
+        self.extract.skipped = skipped;
+        self.extract.index = 0;
+        self.push_navigation_stack(RouteId::Extract, ActiveBlock::ExtractResults);
+    }
+
+    /// Queues the addresses of `network`, then looks up their open ports
+    pub fn queue_network_lookup(&mut self, network: IpNet) {
+        let hosts = network
+            .hosts()
+            .take(BULK_LIMIT + 1)
+            .map(|host| host.to_string())
+            .collect::<Vec<String>>();
+
+        let source = match hosts.len() > BULK_LIMIT {
+            true => format!("{}, first {} addresses", network, BULK_LIMIT),
+            false => network.to_string(),
+        };
+
+        self.queue_bulk_lookup(source, hosts);
+        self.bulk.network = Some(network);
+        self.dispatch(IoEvent::BulkNetwork(network));
+    }
+
+    /// Looks the hosts of the queued network up on VirusTotal, paced for the public API
+    pub fn request_bulk_verdicts(&mut self) {
+        let network = match self.bulk.network {
+            Some(network) => network,
+            None => return,
+        };
+        if self.client_config.keys.virustotal.is_empty() || self.bulk.verdicts_loading {
+            return;
+        }
+
+        self.bulk.verdicts_loading = true;
+        self.dispatch(IoEvent::BulkVerdicts(network));
+    }
+
+    /// Relationships of the indicator shown by VirusTotal, which depend on its type
+    pub fn virustotal_relationships(&self) -> &'static [&'static str] {
+        virustotal::relationships(&self.virustotal.indicator)
+    }
+
+    /// Name of the relationship selected in the Relations tab
+    pub fn virustotal_relationship(&self) -> &'static str {
+        self.virustotal_relationships()
+            .get(self.virustotal.relationship_index)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Forgets every provider's result for the previous search, so none of them is shown or
+    /// scored for the next indicator before its own lookup has answered
+    pub fn reset_results(&mut self) {
+        self.censys.status = ResultStatus::NotQueried;
+        self.censys.history_host.clear();
+        self.shodan.status = ResultStatus::NotQueried;
+        self.virustotal.status = ResultStatus::NotQueried;
+        self.rdap.status = ResultStatus::NotQueried;
+        self.dns.status = ResultStatus::NotQueried;
+        self.geoip.status = ResultStatus::NotQueried;
+        self.abuseipdb.status = ResultStatus::NotQueried;
+        self.abuseipdb.reports_loading = false;
+        self.greynoise.status = ResultStatus::NotQueried;
+        self.otx.status = ResultStatus::NotQueried;
+        self.abusech.status = ResultStatus::NotQueried;
+        self.crtsh.status = ResultStatus::NotQueried;
+        self.securitytrails.status = ResultStatus::NotQueried;
+        self.urlscan.status = ResultStatus::NotQueried;
+    }
+
+    /// Loads the Censys event history of the host shown, unless it is already loaded
+    pub fn open_censys_history(&mut self) {
+        let host = self.censys.search_ip_items.result.ip.to_owned();
+        if host.is_empty() || host == self.censys.history_host {
+            return;
+        }
+
+        self.censys.history_host = host.to_owned();
+        self.censys.history_status = ResultStatus::NotQueried;
+        self.censys.history_error = None;
+        self.censys.history_items = CensysHostEvents::default();
+        self.censys.history_index = 0;
+        self.dispatch(IoEvent::CensysHistory(host));
+    }
+
+    /// Names seen for the current indicator by providers, rather than resolved live
+    pub fn passive_dns(&self) -> Vec<PassiveDnsRecord> {
+        let mut records = Vec::new();
+
+        if let Some(resolutions) = self.virustotal.relationships.get("resolutions") {
+            records.extend(resolutions.data.iter().map(|resolution| {
+                PassiveDnsRecord {
+                    value: resolution.pivot(&self.virustotal.indicator),
+                    source: String::from("VirusTotal"),
+                    last_seen: resolution
+                        .attributes
+                        .date
+                        .and_then(|date| chrono::DateTime::from_timestamp(date, 0))
+                        .map_or(String::from("N/A"), |date| {
+                            date.format("%Y-%m-%d").to_string()
+                        }),
+                }
+            }));
+        }
+
+        if self.otx.status == ResultStatus::Found {
+            let indicator = &self.otx.indicator.general.indicator;
+            records.extend(
+                self.otx
+                    .indicator
+                    .passive_dns
+                    .passive_dns
+                    .iter()
+                    .map(|record| PassiveDnsRecord {
+                        // Records of an IP point at host names and the other way around
+                        value: match &record.hostname == indicator {
+                            true => record.address.to_owned(),
+                            false => record.hostname.to_owned(),
+                        },
+                        source: String::from("OTX"),
+                        last_seen: record.last.chars().take(10).collect(),
+                    }),
+            );
+        }
+
+        if self.shodan.status == ResultStatus::Found {
+            let host = &self.shodan.search_ip_items;
+            records.extend(
+                host.hostnames
+                    .iter()
+                    .flatten()
+                    .filter(|hostname| !hostname.is_empty())
+                    .map(|hostname| PassiveDnsRecord {
+                        value: hostname.to_owned(),
+                        source: String::from("Shodan"),
+                        last_seen: host
+                            .last_update
+                            .clone()
+                            .unwrap_or_else(|| String::from("N/A")),
+                    }),
+            );
+        }
+
+        records
+    }
+
+    /// Shows the context of `asn`, looking it up unless it is the AS already loaded
+    pub fn open_asn(&mut self, asn: u32) {
+        if self.asn.context.asn != asn || self.asn.status == ResultStatus::NotQueried {
+            // The searched address only tells Team Cymru about the peers of its own AS
+            let address = self.asn.address.filter(|_| self.current_asn() == Some(asn));
+
+            self.asn.status = ResultStatus::NotQueried;
+            self.asn.context = AsnContext {
+                asn,
+                ..AsnContext::default()
+            };
+            self.asn.prefix_index = 0;
+            self.asn.peer_index = 0;
+            self.asn.shodan_hosts = None;
+            self.asn.censys_hosts = None;
+            self.dispatch(IoEvent::Asn(asn, address));
+
+            if !self.client_config.keys.shodan.is_empty()
+                || !self.client_config.keys.censys_secret.is_empty()
+            {
+                self.dispatch(IoEvent::AsnHostCounts(asn));
+            }
+        }
+
+        self.push_navigation_stack(RouteId::Asn, ActiveBlock::AsnPrefixes);
+    }
+
+    /// AS announcing the current indicator, as reported by the first provider that knows it
+    pub fn current_asn(&self) -> Option<u32> {
+        let censys = self
+            .censys
+            .search_ip_items
+            .result
+            .autonomous_system
+            .asn
+            .filter(|_| self.censys.status == ResultStatus::Found)
+            .and_then(|asn| u32::try_from(asn).ok());
+        let virustotal = u32::try_from(self.virustotal.ip_whois_items.data.attributes.asn)
+            .ok()
+            .filter(|_| self.virustotal.status == ResultStatus::Found);
+        let geoip = self
+            .geoip
+            .record
+            .asn
+            .filter(|_| self.geoip.status == ResultStatus::Found);
+        let shodan = match self.shodan.status {
+            ResultStatus::Found => parse_asn(&self.shodan.search_ip_items.asn),
+            _ => None,
+        };
+
+        censys
+            .or(virustotal)
+            .or(geoip)
+            .or(shodan)
+            .filter(|asn| *asn > 0)
+    }
+
     pub fn handle_error(&mut self, e: anyhow::Error) {
         self.push_navigation_stack(RouteId::Error, ActiveBlock::Error);
         self.api_error = e.to_string();
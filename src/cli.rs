@@ -0,0 +1,119 @@
+use crossterm::style::{style, Attribute, Color};
+use osintui::{
+    clients::lists,
+    config::Config,
+    indicator::{self, refang, Indicator},
+    watchlist::{ListKind, Severity, Watchlist, WatchlistEntry},
+};
+use std::{
+    error::Error,
+    io::{self, IsTerminal, Read},
+};
+
+const USAGE: &str = "Usage: osintui [refresh-lists | check <indicator>... | extract < file]";
+
+/// Runs a command given on the command line instead of starting the TUI
+pub async fn run(
+    command: &str,
+    args: &[String],
+    config: &Config,
+    watchlist: &Watchlist,
+) -> Result<(), Box<dyn Error>> {
+    match command {
+        "refresh-lists" => refresh_lists(config).await,
+        "check" => {
+            check(args, watchlist);
+            Ok(())
+        }
+        "extract" => extract(&read_stdin()?, watchlist),
+        "-h" | "--help" | "help" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => {
+            eprintln!("Unknown command {}\n{}", command, USAGE);
+            std::process::exit(2);
+        }
+    }
+}
+
+async fn refresh_lists(config: &Config) -> Result<(), Box<dyn Error>> {
+    let dir = config.get_or_build_paths()?.lists_dir;
+    println!("Refreshing lists in {}", dir.display());
+
+    for (list, saved) in lists::refresh(&dir, &config.lists.azure_url).await? {
+        match saved {
+            Ok(ranges) => println!("{:<12}{} ranges", list, ranges),
+            Err(e) => println!("{:<12}{}", list, e),
+        }
+    }
+
+    Ok(())
+}
+
+// Prints every indicator with the banners of the watchlist entries covering it
+fn check(args: &[String], watchlist: &Watchlist) {
+    for arg in args {
+        match Indicator::parse(&refang(arg)) {
+            Some(indicator) => {
+                let banners = watchlist
+                    .matches(&indicator)
+                    .into_iter()
+                    .map(banner)
+                    .collect::<Vec<String>>();
+                let line = format!("{}  {}", indicator, banners.join(" "));
+                println!("{}", line.trim_end());
+            }
+            None => println!("{}  not a valid indicator", arg),
+        }
+    }
+}
+
+/// Text piped in to extract indicators from
+pub fn read_stdin() -> io::Result<String> {
+    let mut text = String::new();
+    io::stdin().read_to_string(&mut text)?;
+    Ok(text)
+}
+
+// Prints the indicators worth looking up in `text`, one per line so they can be piped along
+fn extract(text: &str, watchlist: &Watchlist) -> Result<(), Box<dyn Error>> {
+    let (indicators, skipped) = indicator::triage(text, |indicator| watchlist.is_ours(indicator));
+
+    for indicator in indicators {
+        let banners = watchlist
+            .matches(&indicator)
+            .into_iter()
+            .map(banner)
+            .collect::<Vec<String>>();
+        let line = format!(
+            "{:<8}{}  {}",
+            indicator.kind(),
+            indicator,
+            banners.join(" ")
+        );
+        println!("{}", line.trim_end());
+    }
+    eprintln!("{} reserved or our own indicators skipped", skipped);
+
+    Ok(())
+}
+
+/// Banner of a watchlist entry, coloured when printed to a terminal
+pub fn banner(entry: &WatchlistEntry) -> String {
+    let text = format!("[{}]", entry.banner());
+    if !io::stdout().is_terminal() {
+        return text;
+    }
+
+    let color = match (entry.kind, entry.severity) {
+        (ListKind::Ours, _) => Color::Green,
+        (ListKind::Watch, Severity::Critical | Severity::High) => Color::Red,
+        (ListKind::Watch, Severity::Medium) => Color::Yellow,
+        (ListKind::Watch, Severity::Low | Severity::Info) => Color::Blue,
+    };
+    style(text)
+        .with(color)
+        .attribute(Attribute::Bold)
+        .to_string()
+}
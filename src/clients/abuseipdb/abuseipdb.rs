@@ -0,0 +1,55 @@
+use crate::clients::{
+    abuseipdb::{AbuseIpCheck, AbuseIpReports},
+    base,
+};
+use reqwest::{self, Url};
+
+const BASE_URL: &str = "https://api.abuseipdb.com/api/v2";
+const MAX_AGE_IN_DAYS: &str = "90";
+
+#[derive(Clone)]
+pub struct Client {
+    api_key: String,
+}
+
+impl Client {
+    pub fn new(api_key: String) -> Client {
+        Client { api_key }
+    }
+
+    pub async fn check(&self, ip: &str) -> Result<AbuseIpCheck, reqwest::StatusCode> {
+        let url = Url::parse_with_params(
+            &format!("{}/check", BASE_URL),
+            &[("ipAddress", ip), ("maxAgeInDays", MAX_AGE_IN_DAYS)],
+        )
+        .map_err(|_| reqwest::StatusCode::BAD_REQUEST)?;
+
+        let res: Result<AbuseIpCheck, reqwest::StatusCode> =
+            base::get(url.to_string(), Some(("Key", &self.api_key)), None).await;
+
+        res
+    }
+
+    pub async fn reports(
+        &self,
+        ip: &str,
+        page: u32,
+    ) -> Result<AbuseIpReports, reqwest::StatusCode> {
+        let page = page.to_string();
+        let url = Url::parse_with_params(
+            &format!("{}/reports", BASE_URL),
+            &[
+                ("ipAddress", ip),
+                ("maxAgeInDays", MAX_AGE_IN_DAYS),
+                ("perPage", "25"),
+                ("page", page.as_str()),
+            ],
+        )
+        .map_err(|_| reqwest::StatusCode::BAD_REQUEST)?;
+
+        let res: Result<AbuseIpReports, reqwest::StatusCode> =
+            base::get(url.to_string(), Some(("Key", &self.api_key)), None).await;
+
+        res
+    }
+}
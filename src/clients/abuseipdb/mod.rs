@@ -0,0 +1,6 @@
+#![allow(clippy::module_inception)]
+mod abuseipdb;
+mod models;
+
+pub use abuseipdb::*;
+pub use models::*;
@@ -0,0 +1,218 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AbuseIpCheck {
+    pub data: CheckData,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CheckData {
+    pub ip_address: String,
+    pub is_public: bool,
+    pub is_whitelisted: Option<bool>,
+    pub abuse_confidence_score: u8,
+    pub country_code: Option<String>,
+    pub usage_type: Option<String>,
+    pub isp: Option<String>,
+    pub domain: Option<String>,
+    pub hostnames: Vec<String>,
+    pub is_tor: bool,
+    pub total_reports: u32,
+    pub num_distinct_users: u32,
+    pub last_reported_at: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AbuseIpReports {
+    pub data: ReportsData,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ReportsData {
+    pub total: u32,
+    pub page: u32,
+    pub count: u32,
+    pub per_page: u32,
+    pub last_page: u32,
+    pub results: Vec<Report>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Report {
+    pub reported_at: String,
+    pub comment: Option<String>,
+    pub categories: Vec<u8>,
+    pub reporter_id: u64,
+    pub reporter_country_code: Option<String>,
+    pub reporter_country_name: Option<String>,
+}
+
+/// Report category names, see https://www.abuseipdb.com/categories
+pub fn category_name(category: u8) -> &'static str {
+    match category {
+        1 => "DNS Compromise",
+        2 => "DNS Poisoning",
+        3 => "Fraud Orders",
+        4 => "DDoS Attack",
+        5 => "FTP Brute-Force",
+        6 => "Ping of Death",
+        7 => "Phishing",
+        8 => "Fraud VoIP",
+        9 => "Open Proxy",
+        10 => "Web Spam",
+        11 => "Email Spam",
+        12 => "Blog Spam",
+        13 => "VPN IP",
+        14 => "Port Scan",
+        15 => "Hacking",
+        16 => "SQL Injection",
+        17 => "Spoofing",
+        18 => "Brute-Force",
+        19 => "Bad Web Bot",
+        20 => "Exploited Host",
+        21 => "Web App Attack",
+        22 => "SSH",
+        23 => "IoT Targeted",
+        _ => "Unknown",
+    }
+}
+
+impl CheckData {
+    pub fn summary_to_vec(&self) -> Vec<Vec<String>> {
+        let or_na = |value: &Option<String>| {
+            value
+                .clone()
+                .filter(|value| !value.is_empty())
+                .unwrap_or_else(|| String::from("N/A"))
+        };
+
+        vec![
+            vec![String::from("IP Address"), self.ip_address.to_owned()],
+            vec![
+                String::from("Confidence"),
+                format!("{}%", self.abuse_confidence_score),
+            ],
+            vec![
+                String::from("Total Reports"),
+                format!(
+                    "{} from {} users",
+                    self.total_reports, self.num_distinct_users
+                ),
+            ],
+            vec![String::from("Last Reported"), or_na(&self.last_reported_at)],
+            vec![String::from("Usage Type"), or_na(&self.usage_type)],
+            vec![String::from("ISP"), or_na(&self.isp)],
+            vec![String::from("Domain"), or_na(&self.domain)],
+            vec![String::from("Country"), or_na(&self.country_code)],
+            vec![
+                String::from("Tor Exit"),
+                match self.is_tor {
+                    true => String::from("Yes"),
+                    false => String::from("No"),
+                },
+            ],
+            vec![
+                String::from("Allowlisted"),
+                match self.is_whitelisted {
+                    Some(true) => String::from("Yes"),
+                    _ => String::from("No"),
+                },
+            ],
+        ]
+    }
+}
+
+impl ReportsData {
+    pub fn has_more(&self) -> bool {
+        self.page < self.last_page
+    }
+}
+
+impl Report {
+    pub fn categories(&self) -> String {
+        self.categories
+            .iter()
+            .map(|category| category_name(*category))
+            .collect::<Vec<&str>>()
+            .join(", ")
+    }
+
+    pub fn summary_to_vec(&self) -> Vec<String> {
+        vec![
+            self.reported_at.get(..10).unwrap_or_default().to_owned(),
+            self.categories(),
+            self.reporter_country_code.clone().unwrap_or_default(),
+            self.comment
+                .as_deref()
+                .unwrap_or_default()
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_owned(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reports_page() {
+        let reports: AbuseIpReports = serde_json::from_str(
+            r#"{"data": {
+                "total": 120,
+                "page": 1,
+                "count": 1,
+                "perPage": 100,
+                "lastPage": 2,
+                "results": [{
+                    "reportedAt": "2024-03-01T12:00:00+00:00",
+                    "comment": "SSH brute force\nfrom honeypot",
+                    "categories": [18, 22, 99],
+                    "reporterId": 1,
+                    "reporterCountryCode": "DE"
+                }]
+            }}"#,
+        )
+        .unwrap();
+
+        assert!(reports.data.has_more());
+        assert_eq!(
+            reports.data.results[0].summary_to_vec(),
+            vec![
+                "2024-03-01",
+                "Brute-Force, SSH, Unknown",
+                "DE",
+                "SSH brute force"
+            ]
+        );
+    }
+
+    #[test]
+    fn check_summary() {
+        let check: AbuseIpCheck = serde_json::from_str(
+            r#"{"data": {
+                "ipAddress": "198.51.100.7",
+                "abuseConfidenceScore": 87,
+                "totalReports": 120,
+                "numDistinctUsers": 31,
+                "isp": "",
+                "isTor": true,
+                "isWhitelisted": null
+            }}"#,
+        )
+        .unwrap();
+
+        let summary = check.data.summary_to_vec();
+        assert_eq!(summary[1], vec!["Confidence", "87%"]);
+        assert_eq!(summary[2], vec!["Total Reports", "120 from 31 users"]);
+        // Empty strings are shown like missing values
+        assert_eq!(summary[5], vec!["ISP", "N/A"]);
+        assert_eq!(summary[8], vec!["Tor Exit", "Yes"]);
+        assert_eq!(summary[9], vec!["Allowlisted", "No"]);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::clients::{
+    asn::{cymru, ipinfo, ripestat, AsnContext},
+    dns, rdap,
+};
+use std::net::IpAddr;
+
+/// ASN context object, backed by IPinfo with a token and by Team Cymru's DNS interface and
+/// RIPEstat without
+#[derive(Clone)]
+pub struct Client {
+    ipinfo_token: String,
+    dns: dns::Client,
+    rdap: rdap::Client,
+}
+
+impl Client {
+    pub fn new(ipinfo_token: String, dns: dns::Client, rdap: rdap::Client) -> Client {
+        Client {
+            ipinfo_token,
+            dns,
+            rdap,
+        }
+    }
+
+    /// `ip` is an address announced by the AS, a fallback for the peers when RIPEstat has none
+    pub async fn lookup(
+        &self,
+        asn: u32,
+        ip: Option<IpAddr>,
+    ) -> Result<AsnContext, reqwest::StatusCode> {
+        let (context, autnum) = futures::join!(
+            async {
+                match self.ipinfo_token.is_empty() {
+                    true => self.keyless_lookup(asn, ip).await,
+                    false => ipinfo::lookup(&self.ipinfo_token, asn).await,
+                }
+            },
+            self.rdap.lookup_autnum(asn)
+        );
+
+        // The registry's abuse contact is a bonus, the AS is still worth showing without one
+        let mut context = context?;
+        context.abuse_contact = autnum.ok().map(|autnum| autnum.abuse_contact());
+
+        Ok(context)
+    }
+
+    // Team Cymru has no prefix list and only knows the peers of a given address, RIPEstat
+    // fills both in for the whole AS
+    async fn keyless_lookup(
+        &self,
+        asn: u32,
+        ip: Option<IpAddr>,
+    ) -> Result<AsnContext, reqwest::StatusCode> {
+        let (context, prefixes, upstreams) = futures::join!(
+            cymru::lookup(&self.dns, asn, ip),
+            ripestat::prefixes(asn),
+            ripestat::upstreams(asn)
+        );
+
+        let mut context = context?;
+        context.source = String::from("Team Cymru, RIPEstat");
+        context.prefixes = prefixes.unwrap_or_default();
+        match upstreams {
+            Ok(upstreams) if !upstreams.is_empty() => context.peers = upstreams,
+            _ => {}
+        }
+
+        Ok(context)
+    }
+}
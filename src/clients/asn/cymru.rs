@@ -0,0 +1,108 @@
+use crate::clients::{asn::AsnContext, dns};
+use std::net::IpAddr;
+
+const ASN_ZONE: &str = "asn.cymru.com";
+const PEER_ZONE: &str = "peer.asn.cymru.com";
+const PEER_ZONE_V6: &str = "peer.asn6.cymru.com";
+
+pub async fn lookup(
+    dns: &dns::Client,
+    asn: u32,
+    ip: Option<IpAddr>,
+) -> Result<AsnContext, reqwest::StatusCode> {
+    let records = dns
+        .txt(&format!("AS{}.{}", asn, ASN_ZONE))
+        .await
+        .map_err(|_| reqwest::StatusCode::BAD_GATEWAY)?;
+
+    let mut context = records
+        .first()
+        .and_then(|record| parse_asn(record))
+        .ok_or(reqwest::StatusCode::NOT_FOUND)?;
+
+    if let Some(ip) = ip {
+        let peers = dns.txt(&peer_name(ip)).await.unwrap_or_default();
+        context.peers = peers
+            .first()
+            .map(|record| parse_peers(record))
+            .unwrap_or_default();
+    }
+
+    Ok(context)
+}
+
+// `13335 | US | apnic | 2010-07-14 | CLOUDFLARENET - Cloudflare, Inc., US`
+fn parse_asn(record: &str) -> Option<AsnContext> {
+    let fields = record.split('|').map(str::trim).collect::<Vec<&str>>();
+    if fields.len() < 5 {
+        return None;
+    }
+
+    Some(AsnContext {
+        asn: fields[0].parse().ok()?,
+        name: fields[4].to_owned(),
+        country: fields[1].to_owned(),
+        registry: fields[2].to_owned(),
+        allocated: fields[3].to_owned(),
+        source: String::from("Team Cymru"),
+        ..AsnContext::default()
+    })
+}
+
+// `174 3356 6939 | 1.1.1.0/24 | AU | apnic | 2011-08-11`
+fn parse_peers(record: &str) -> Vec<String> {
+    record
+        .split('|')
+        .next()
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(|peer| format!("AS{}", peer))
+        .collect()
+}
+
+// Reversed octets for IPv4, reversed nibbles for IPv6
+fn peer_name(ip: IpAddr) -> String {
+    match ip {
+        IpAddr::V4(ip) => {
+            let octets = ip.octets();
+            format!(
+                "{}.{}.{}.{}.{}",
+                octets[3], octets[2], octets[1], octets[0], PEER_ZONE
+            )
+        }
+        IpAddr::V6(ip) => {
+            let nibbles = ip
+                .octets()
+                .iter()
+                .flat_map(|octet| [octet >> 4, octet & 0xf])
+                .rev()
+                .map(|nibble| format!("{:x}", nibble))
+                .collect::<Vec<String>>();
+            format!("{}.{}", nibbles.join("."), PEER_ZONE_V6)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_records() {
+        let context =
+            parse_asn("13335 | US | apnic | 2010-07-14 | CLOUDFLARENET - Cloudflare, Inc., US")
+                .unwrap();
+
+        assert_eq!(context.asn, 13335);
+        assert_eq!(context.registry, "apnic");
+        assert_eq!(context.name, "CLOUDFLARENET - Cloudflare, Inc., US");
+        assert_eq!(
+            parse_peers("174 3356 | 1.1.1.0/24 | AU | apnic | 2011-08-11"),
+            vec!["AS174", "AS3356"]
+        );
+        assert_eq!(
+            peer_name("1.2.3.4".parse().unwrap()),
+            "4.3.2.1.peer.asn.cymru.com"
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use crate::clients::{
+    asn::{AsnContext, AsnPrefix},
+    base,
+};
+use reqwest::Url;
+use serde::Deserialize;
+
+const BASE_URL: &str = "https://ipinfo.io";
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct IpinfoAsn {
+    asn: String,
+    name: String,
+    country: String,
+    allocated: String,
+    registry: String,
+    prefixes: Vec<IpinfoPrefix>,
+    prefixes6: Vec<IpinfoPrefix>,
+    upstreams: Vec<String>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct IpinfoPrefix {
+    netblock: String,
+    name: String,
+    country: String,
+}
+
+pub async fn lookup(token: &str, asn: u32) -> Result<AsnContext, reqwest::StatusCode> {
+    let url = Url::parse_with_params(&format!("{}/AS{}/json", BASE_URL, asn), &[("token", token)])
+        .map_err(|_| reqwest::StatusCode::BAD_REQUEST)?;
+
+    let resp: IpinfoAsn = base::get(url.to_string(), None, None).await?;
+
+    Ok(AsnContext {
+        asn,
+        name: resp.name,
+        country: resp.country,
+        registry: resp.registry,
+        allocated: resp.allocated,
+        prefixes: resp
+            .prefixes
+            .into_iter()
+            .chain(resp.prefixes6)
+            .map(|prefix| AsnPrefix {
+                netblock: prefix.netblock,
+                name: prefix.name,
+                country: prefix.country,
+            })
+            .collect(),
+        peers: resp.upstreams,
+        abuse_contact: None,
+        source: format!("IPinfo ({})", resp.asn),
+    })
+}
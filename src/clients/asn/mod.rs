@@ -0,0 +1,9 @@
+#![allow(clippy::module_inception)]
+mod asn;
+mod cymru;
+mod ipinfo;
+mod models;
+mod ripestat;
+
+pub use asn::*;
+pub use models::*;
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AsnContext {
+    pub asn: u32,
+    pub name: String,
+    pub country: String,
+    pub registry: String,
+    pub allocated: String,
+    pub prefixes: Vec<AsnPrefix>,
+    // Upstream ASes, formatted as `AS174`
+    pub peers: Vec<String>,
+    pub abuse_contact: Option<String>,
+    pub source: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AsnPrefix {
+    pub netblock: String,
+    pub name: String,
+    pub country: String,
+}
+
+/// Parses `AS13335`, `as13335` or `13335`
+pub fn parse_asn(value: &str) -> Option<u32> {
+    let value = value.trim();
+    let digits = match value.get(..2) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("as") => &value[2..],
+        _ => value,
+    };
+
+    digits.parse().ok().filter(|asn| *asn > 0)
+}
+
+impl AsnContext {
+    pub fn summary_to_vec(&self) -> Vec<Vec<String>> {
+        let or_na = |value: &str| match value {
+            "" => String::from("N/A"),
+            value => value.to_owned(),
+        };
+
+        vec![
+            vec![String::from("ASN"), format!("AS{}", self.asn)],
+            vec![String::from("Name"), or_na(&self.name)],
+            vec![String::from("Country"), or_na(&self.country)],
+            vec![String::from("Registry"), or_na(&self.registry)],
+            vec![String::from("Allocated"), or_na(&self.allocated)],
+            vec![
+                String::from("Abuse Contact"),
+                or_na(self.abuse_contact.as_deref().unwrap_or_default()),
+            ],
+            vec![String::from("Source"), or_na(&self.source)],
+        ]
+    }
+}
+
+impl AsnPrefix {
+    pub fn summary_to_vec(&self) -> Vec<String> {
+        vec![
+            self.netblock.to_owned(),
+            self.name.to_owned(),
+            self.country.to_owned(),
+        ]
+    }
+}
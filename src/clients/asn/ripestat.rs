@@ -0,0 +1,128 @@
+use crate::clients::{asn::AsnPrefix, base};
+use serde::Deserialize;
+use std::cmp::Reverse;
+
+const BASE_URL: &str = "https://stat.ripe.net/data";
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Response<T> {
+    data: T,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct AnnouncedPrefixes {
+    prefixes: Vec<AnnouncedPrefix>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct AnnouncedPrefix {
+    prefix: String,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct AsnNeighbours {
+    neighbours: Vec<AsnNeighbour>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct AsnNeighbour {
+    asn: u32,
+    // `left` neighbours sit between the AS and the route collectors, i.e. its upstreams
+    #[serde(rename = "type")]
+    position: String,
+    power: u32,
+}
+
+/// Prefixes the AS announces as seen by the RIPE route collectors, needs no key
+pub async fn prefixes(asn: u32) -> Result<Vec<AsnPrefix>, reqwest::StatusCode> {
+    let resp: Response<AnnouncedPrefixes> = base::get(
+        format!(
+            "{}/announced-prefixes/data.json?resource=AS{}",
+            BASE_URL, asn
+        ),
+        None,
+        None,
+    )
+    .await?;
+
+    Ok(parse_prefixes(resp.data))
+}
+
+/// Upstream ASes, the ones seen on the most paths first
+pub async fn upstreams(asn: u32) -> Result<Vec<String>, reqwest::StatusCode> {
+    let resp: Response<AsnNeighbours> = base::get(
+        format!("{}/asn-neighbours/data.json?resource=AS{}", BASE_URL, asn),
+        None,
+        None,
+    )
+    .await?;
+
+    Ok(parse_upstreams(resp.data))
+}
+
+fn parse_prefixes(prefixes: AnnouncedPrefixes) -> Vec<AsnPrefix> {
+    prefixes
+        .prefixes
+        .into_iter()
+        .map(|prefix| AsnPrefix {
+            netblock: prefix.prefix,
+            ..AsnPrefix::default()
+        })
+        .collect()
+}
+
+fn parse_upstreams(neighbours: AsnNeighbours) -> Vec<String> {
+    let mut upstreams = neighbours
+        .neighbours
+        .into_iter()
+        .filter(|neighbour| neighbour.position == "left")
+        .collect::<Vec<AsnNeighbour>>();
+    upstreams.sort_by_key(|neighbour| Reverse(neighbour.power));
+
+    upstreams
+        .into_iter()
+        .map(|neighbour| format!("AS{}", neighbour.asn))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_prefixes() {
+        let resp: Response<AnnouncedPrefixes> = serde_json::from_str(
+            r#"{"data": {"prefixes": [
+                {"prefix": "1.1.1.0/24", "timelines": []},
+                {"prefix": "2606:4700::/32", "timelines": []}
+            ]}, "status": "ok"}"#,
+        )
+        .unwrap();
+
+        let prefixes = parse_prefixes(resp.data);
+
+        assert_eq!(prefixes.len(), 2);
+        assert_eq!(prefixes[0].netblock, "1.1.1.0/24");
+        assert_eq!(prefixes[1].netblock, "2606:4700::/32");
+    }
+
+    #[test]
+    fn test_parse_upstreams() {
+        let resp: Response<AsnNeighbours> = serde_json::from_str(
+            r#"{"data": {"neighbours": [
+                {"asn": 6939, "type": "left", "power": 12},
+                {"asn": 64512, "type": "right", "power": 40},
+                {"asn": 174, "type": "left", "power": 30},
+                {"asn": 3356, "type": "uncertain", "power": 2}
+            ]}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(parse_upstreams(resp.data), vec!["AS174", "AS6939"]);
+    }
+}
@@ -1,5 +1,18 @@
 use reqwest::StatusCode;
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Serialize};
+use std::time::Duration;
+
+// A provider that stops answering would otherwise hold up every request queued behind it
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+fn client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .connect_timeout(CONNECT_TIMEOUT)
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .unwrap_or_default()
+}
 
 #[doc(hidden)]
 pub async fn get<T>(
@@ -10,7 +23,7 @@ pub async fn get<T>(
 where
     T: DeserializeOwned,
 {
-    let client = reqwest::Client::new();
+    let client = client();
     let mut call = client.get(&url);
 
     if let Some((key, value)) = header {
@@ -21,21 +34,61 @@ where
         call = call.basic_auth(user, Some(pass));
     }
 
-    let resp = call.send().await;
+    parse(call.send().await).await
+}
+
+#[doc(hidden)]
+pub async fn post_form<T, B>(
+    url: String,
+    header: Option<(&str, &str)>,
+    form: &B,
+) -> Result<T, StatusCode>
+where
+    T: DeserializeOwned,
+    B: Serialize + ?Sized,
+{
+    let client = client();
+    let mut call = client.post(&url).form(form);
+
+    if let Some((key, value)) = header {
+        call = call.header(key, value);
+    }
+
+    parse(call.send().await).await
+}
 
+#[doc(hidden)]
+pub async fn post_json<T, B>(
+    url: String,
+    header: Option<(&str, &str)>,
+    body: &B,
+) -> Result<T, StatusCode>
+where
+    T: DeserializeOwned,
+    B: Serialize + ?Sized,
+{
+    let client = client();
+    let mut call = client.post(&url).json(body);
+
+    if let Some((key, value)) = header {
+        call = call.header(key, value);
+    }
+
+    parse(call.send().await).await
+}
+
+async fn parse<T>(resp: Result<reqwest::Response, reqwest::Error>) -> Result<T, StatusCode>
+where
+    T: DeserializeOwned,
+{
     match resp {
         Ok(response) if !response.status().is_success() => Err(response.status()),
+        Err(error) if error.is_timeout() => Err(StatusCode::REQUEST_TIMEOUT),
         Err(error) => Err(error.status().unwrap_or(StatusCode::BAD_REQUEST)),
-        Ok(response) => {
-            let content = response.json::<T>().await;
-
-            match content {
-                Ok(data) => Ok(data),
-                Err(error) => {
-                    println!("{:?}", error);
-                    Err(StatusCode::BAD_REQUEST)
-                }
-            }
-        }
+        // Printing the decode error would draw over the TUI, the status is reported instead
+        Ok(response) => response
+            .json::<T>()
+            .await
+            .map_err(|_| StatusCode::BAD_REQUEST),
     }
 }
@@ -1,5 +1,11 @@
-use crate::clients::{base, censys::CensysSearchIp};
-use reqwest;
+use crate::clients::{
+    base,
+    censys::{
+        CensysCertificate, CensysCertificateHosts, CensysHostEvents, CensysHostSearch,
+        CensysSearchIp,
+    },
+};
+use reqwest::{self, Url};
 
 const BASE_URL: &str = "https://search.censys.io/api/v2";
 
@@ -21,4 +27,55 @@ impl Client {
 
         res
     }
+
+    pub async fn search_hosts(
+        &self,
+        query: &str,
+        cursor: Option<&str>,
+    ) -> Result<CensysHostSearch, reqwest::StatusCode> {
+        let mut params = vec![("q", query), ("per_page", "50")];
+        if let Some(cursor) = cursor {
+            params.push(("cursor", cursor));
+        }
+
+        let url = Url::parse_with_params(&format!("{}/hosts/search", BASE_URL), &params)
+            .map_err(|_| reqwest::StatusCode::BAD_REQUEST)?;
+        let res: Result<CensysHostSearch, reqwest::StatusCode> =
+            base::get(url.to_string(), None, Some((&self.api_id, &self.api_key))).await;
+
+        res
+    }
+
+    pub async fn host_events(&self, ip: &str) -> Result<CensysHostEvents, reqwest::StatusCode> {
+        let url = format!(
+            "{}/experimental/hosts/{}/events?per_page=50&reversed=true",
+            BASE_URL, ip
+        );
+        let res: Result<CensysHostEvents, reqwest::StatusCode> =
+            base::get(url, None, Some((&self.api_id, &self.api_key))).await;
+
+        res
+    }
+
+    pub async fn view_certificate(
+        &self,
+        fingerprint: &str,
+    ) -> Result<CensysCertificate, reqwest::StatusCode> {
+        let url = format!("{}/certificates/{}", BASE_URL, fingerprint);
+        let res: Result<CensysCertificate, reqwest::StatusCode> =
+            base::get(url, None, Some((&self.api_id, &self.api_key))).await;
+
+        res
+    }
+
+    pub async fn certificate_hosts(
+        &self,
+        fingerprint: &str,
+    ) -> Result<CensysCertificateHosts, reqwest::StatusCode> {
+        let url = format!("{}/certificates/{}/hosts", BASE_URL, fingerprint);
+        let res: Result<CensysCertificateHosts, reqwest::StatusCode> =
+            base::get(url, None, Some((&self.api_id, &self.api_key))).await;
+
+        res
+    }
 }
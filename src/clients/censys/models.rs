@@ -17,6 +17,7 @@ pub struct Result {
     pub location: Location,
     pub autonomous_system: AutonomousSystem,
     pub operating_sytem: Option<OperatingSystem>,
+    pub labels: Option<Vec<String>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -26,6 +27,7 @@ pub struct Services {
     pub transport_protocol: Option<String>,
     pub extended_service_name: Option<String>,
     pub certificate: Option<String>,
+    pub labels: Option<Vec<String>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -104,3 +106,233 @@ impl CensysSearchIp {
         ]
     }
 }
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CensysHostEvents {
+    pub code: i32,
+    pub status: String,
+    pub result: HostEventsResult,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostEventsResult {
+    pub ip: String,
+    #[serde(default)]
+    pub events: Vec<HostEvent>,
+    pub links: Option<Links>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostEvent {
+    pub timestamp: String,
+    #[serde(rename = "_event")]
+    pub event: Option<String>,
+    pub service_observed: Option<ServiceObserved>,
+    pub end_service: Option<ServiceObserved>,
+    pub location_updated: Option<LocationUpdated>,
+    pub route_updated: Option<RouteUpdated>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServiceObserved {
+    pub id: ServiceId,
+    pub observed_at: Option<String>,
+    pub changed_fields: Option<Vec<ChangedField>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServiceId {
+    pub port: Option<i32>,
+    pub service_name: Option<String>,
+    pub transport: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangedField {
+    pub field_name: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocationUpdated {
+    pub location: Option<Location>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RouteUpdated {
+    pub route: Option<AutonomousSystem>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Links {
+    pub prev: Option<String>,
+    pub next: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CensysCertificate {
+    pub code: i32,
+    pub status: String,
+    pub result: Certificate,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Certificate {
+    pub fingerprint_sha256: String,
+    pub names: Option<Vec<String>>,
+    pub parsed: ParsedCertificate,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParsedCertificate {
+    pub subject_dn: Option<String>,
+    pub issuer_dn: Option<String>,
+    pub validity: Option<Validity>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Validity {
+    pub start: Option<String>,
+    pub end: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CensysCertificateHosts {
+    pub code: i32,
+    pub status: String,
+    pub result: CertificateHosts,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificateHosts {
+    pub fingerprint: String,
+    #[serde(default)]
+    pub hosts: Vec<CertificateHost>,
+    pub links: Option<Links>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificateHost {
+    pub ip: String,
+    pub name: Option<String>,
+    pub observed_at: Option<String>,
+    pub first_observed_at: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CensysHostSearch {
+    pub code: i32,
+    pub status: String,
+    pub result: HostSearchResult,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostSearchResult {
+    pub query: String,
+    pub total: i64,
+    #[serde(default)]
+    pub hits: Vec<HostHit>,
+    pub links: Option<Links>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostHit {
+    pub ip: String,
+    #[serde(default)]
+    pub services: Vec<Services>,
+    pub location: Option<Location>,
+    pub autonomous_system: Option<AutonomousSystem>,
+    pub last_updated_at: Option<String>,
+}
+
+impl HostSearchResult {
+    /// Cursor for the next page of hits, if the query has more results
+    pub fn next_cursor(&self) -> Option<String> {
+        self.links
+            .as_ref()
+            .and_then(|links| links.next.clone())
+            .filter(|cursor| !cursor.is_empty())
+    }
+}
+
+impl HostEvent {
+    /// Short label describing what changed, e.g. `service_observed`
+    pub fn kind(&self) -> String {
+        match &self.event {
+            Some(event) => event.to_string(),
+            None if self.service_observed.is_some() => "service_observed".to_owned(),
+            None if self.end_service.is_some() => "end_service".to_owned(),
+            None if self.location_updated.is_some() => "location_updated".to_owned(),
+            None if self.route_updated.is_some() => "route_updated".to_owned(),
+            None => "N/A".to_owned(),
+        }
+    }
+
+    /// The service the event refers to, if any
+    pub fn service(&self) -> Option<&ServiceObserved> {
+        self.service_observed.as_ref().or(self.end_service.as_ref())
+    }
+
+    pub fn details(&self) -> String {
+        if let Some(service) = self.service() {
+            return service
+                .changed_fields
+                .as_ref()
+                .map(|fields| {
+                    fields
+                        .iter()
+                        .map(|f| f.field_name.as_str())
+                        .collect::<Vec<&str>>()
+                        .join(", ")
+                })
+                .unwrap_or_default();
+        }
+
+        if let Some(location) = self
+            .location_updated
+            .as_ref()
+            .and_then(|l| l.location.as_ref())
+        {
+            return location.country.as_deref().unwrap_or("N/A").to_owned();
+        }
+
+        if let Some(route) = self.route_updated.as_ref().and_then(|r| r.route.as_ref()) {
+            return format!(
+                "AS{} {}",
+                route.asn.map(|x| x.to_string()).unwrap_or_default(),
+                route.name.as_deref().unwrap_or_default()
+            );
+        }
+
+        String::new()
+    }
+}
+
+impl Certificate {
+    pub fn summary_to_vec(&self) -> Vec<Vec<String>> {
+        let validity = self.parsed.validity.clone().unwrap_or_default();
+
+        vec![
+            vec!["SHA-256".to_owned(), self.fingerprint_sha256.to_string()],
+            vec![
+                "Subject".to_owned(),
+                self.parsed
+                    .subject_dn
+                    .as_deref()
+                    .unwrap_or("N/A")
+                    .to_owned(),
+            ],
+            vec![
+                "Issuer".to_owned(),
+                self.parsed.issuer_dn.as_deref().unwrap_or("N/A").to_owned(),
+            ],
+            vec![
+                "Not Before".to_owned(),
+                validity.start.as_deref().unwrap_or("N/A").to_owned(),
+            ],
+            vec![
+                "Not After".to_owned(),
+                validity.end.as_deref().unwrap_or("N/A").to_owned(),
+            ],
+        ]
+    }
+}
@@ -0,0 +1,103 @@
+use crate::clients::{base, crtsh::CrtshCertificate};
+use reqwest::{self, Url};
+
+const BASE_URL: &str = "https://crt.sh";
+
+/// crt.sh certificate transparency search object
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Client::new()
+    }
+}
+
+impl Client {
+    pub fn new() -> Client {
+        Client::with_base_url(BASE_URL.to_string())
+    }
+
+    pub fn with_base_url(base_url: String) -> Client {
+        Client { base_url }
+    }
+
+    /// Certificates logged for `domain` and its subdomains, newest first
+    pub async fn certificates(
+        &self,
+        domain: &str,
+    ) -> Result<Vec<CrtshCertificate>, reqwest::StatusCode> {
+        let url = Url::parse_with_params(
+            &format!("{}/", self.base_url.trim_end_matches('/')),
+            &[("q", format!("%.{}", domain).as_str()), ("output", "json")],
+        )
+        .map_err(|_| reqwest::StatusCode::BAD_REQUEST)?;
+
+        let mut certificates: Vec<CrtshCertificate> =
+            base::get(url.to_string(), None, None).await?;
+
+        // Precertificates and their final certificates are logged as separate entries
+        certificates.sort_by(|a, b| {
+            b.not_before
+                .cmp(&a.not_before)
+                .then(a.serial_number.cmp(&b.serial_number))
+        });
+        certificates.dedup_by(|a, b| a.serial_number == b.serial_number);
+
+        Ok(certificates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::crtsh::subdomains;
+    use crate::clients::test_server::{serve_once, serve_status_once};
+
+    const CERTIFICATES: &str = include_str!("testdata/example.com.json");
+
+    #[tokio::test]
+    async fn test_certificates() {
+        let (base_url, request) = serve_once("application/json", CERTIFICATES).await;
+        let client = Client::with_base_url(base_url);
+
+        let certificates = client.certificates("example.com").await.unwrap();
+
+        assert_eq!(
+            request.await.unwrap(),
+            "GET /?q=%25.example.com&output=json HTTP/1.1"
+        );
+        assert_eq!(certificates.len(), 2);
+        assert_eq!(certificates[0].issuer(), "R3");
+        assert_eq!(certificates[1].issuer(), "DigiCert TLS RSA SHA256 2020 CA1");
+    }
+
+    #[tokio::test]
+    async fn test_certificates_overloaded() {
+        let (base_url, request) =
+            serve_status_once("502 Bad Gateway", "text/html", "<h1>502 Bad Gateway</h1>").await;
+        let client = Client::with_base_url(base_url);
+
+        let result = client.certificates("example.com").await;
+
+        request.await.unwrap();
+        assert_eq!(result.err(), Some(reqwest::StatusCode::BAD_GATEWAY));
+    }
+
+    #[test]
+    fn test_subdomains() {
+        let certificates: Vec<CrtshCertificate> = serde_json::from_str(CERTIFICATES).unwrap();
+
+        assert_eq!(
+            subdomains(&certificates, "example.com"),
+            vec![
+                "api.example.com",
+                "example.com",
+                "mail.example.com",
+                "www.example.com"
+            ]
+        );
+    }
+}
@@ -0,0 +1,6 @@
+#![allow(clippy::module_inception)]
+mod crtsh;
+mod models;
+
+pub use crtsh::*;
+pub use models::*;
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CrtshCertificate {
+    pub id: u64,
+    pub issuer_name: String,
+    pub common_name: String,
+    // Newline separated subject alternative names
+    pub name_value: String,
+    pub entry_timestamp: String,
+    pub not_before: String,
+    pub not_after: String,
+    pub serial_number: String,
+}
+
+impl CrtshCertificate {
+    pub fn names(&self) -> Vec<String> {
+        self.name_value
+            .lines()
+            .map(|name| name.trim().to_lowercase())
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+
+    /// Common name of the issuing CA, e.g. `R3` out of `C=US, O=Let's Encrypt, CN=R3`
+    pub fn issuer(&self) -> String {
+        self.issuer_name
+            .split(", ")
+            .find_map(|part| part.strip_prefix("CN="))
+            .unwrap_or(&self.issuer_name)
+            .to_owned()
+    }
+
+    pub fn summary_to_vec(&self) -> Vec<String> {
+        vec![
+            self.not_before.chars().take(10).collect(),
+            self.not_after.chars().take(10).collect(),
+            self.issuer(),
+            self.common_name.to_owned(),
+            self.names().len().to_string(),
+        ]
+    }
+}
+
+/// Distinct names under `domain` across every certificate, wildcards reduced to their base
+pub fn subdomains(certificates: &[CrtshCertificate], domain: &str) -> Vec<String> {
+    let suffix = format!(".{}", domain);
+
+    let mut names = certificates
+        .iter()
+        .flat_map(CrtshCertificate::names)
+        .map(|name| name.trim_start_matches("*.").to_owned())
+        .filter(|name| name == domain || name.ends_with(&suffix))
+        .collect::<Vec<String>>();
+    names.sort();
+    names.dedup();
+
+    names
+}
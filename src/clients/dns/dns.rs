@@ -0,0 +1,227 @@
+use crate::clients::dns::DnsRecord;
+use futures::future::join_all;
+use hickory_resolver::{
+    config::{NameServerConfig, NameServerConfigGroup, Protocol, ResolverConfig, ResolverOpts},
+    error::{ResolveError, ResolveErrorKind},
+    proto::rr::{RData, Record, RecordType},
+    TokioAsyncResolver,
+};
+use std::{net::IpAddr, net::SocketAddr, time::Duration};
+
+// Record types looked up for a domain, in display order
+const DOMAIN_RECORD_TYPES: [RecordType; 5] = [
+    RecordType::A,
+    RecordType::AAAA,
+    RecordType::MX,
+    RecordType::NS,
+    RecordType::TXT,
+];
+
+/// DNS resolver object
+#[derive(Clone)]
+pub struct Client {
+    resolver: TokioAsyncResolver,
+}
+
+impl Client {
+    /// Resolves through `servers` over UDP, falling back to TCP for truncated answers
+    pub fn new(servers: &[SocketAddr]) -> Client {
+        let mut group = NameServerConfigGroup::new();
+        for server in servers {
+            for protocol in [Protocol::Udp, Protocol::Tcp] {
+                group.push(NameServerConfig::new(*server, protocol));
+            }
+        }
+
+        let mut options = ResolverOpts::default();
+        options.timeout = Duration::from_secs(3);
+        options.attempts = 1;
+
+        Client {
+            resolver: TokioAsyncResolver::tokio(
+                ResolverConfig::from_parts(None, vec![], group),
+                options,
+            ),
+        }
+    }
+
+    pub async fn reverse_lookup(&self, ip: IpAddr) -> Result<Vec<DnsRecord>, ResolveError> {
+        match self.resolver.reverse_lookup(ip).await {
+            Ok(lookup) => Ok(lookup
+                .as_lookup()
+                .record_iter()
+                .filter_map(to_record)
+                .collect()),
+            Err(error) => empty_when_missing(error),
+        }
+    }
+
+    /// Addresses `domain` points to, following CNAMEs
+    pub async fn resolve(&self, domain: &str) -> Result<Vec<IpAddr>, ResolveError> {
+        let name = format!("{}.", domain.trim_end_matches('.'));
+
+        match self.resolver.lookup_ip(name).await {
+            Ok(lookup) => Ok(lookup.iter().collect()),
+            Err(error) => match error.kind() {
+                ResolveErrorKind::NoRecordsFound { .. } => Ok(vec![]),
+                _ => Err(error),
+            },
+        }
+    }
+
+    /// Text records of `name`, each one's strings joined together
+    pub async fn txt(&self, name: &str) -> Result<Vec<String>, ResolveError> {
+        let name = format!("{}.", name.trim_end_matches('.'));
+
+        match self.resolver.txt_lookup(name).await {
+            Ok(lookup) => Ok(lookup.iter().map(|txt| txt.to_string()).collect()),
+            Err(error) => match error.kind() {
+                ResolveErrorKind::NoRecordsFound { .. } => Ok(vec![]),
+                _ => Err(error),
+            },
+        }
+    }
+
+    pub async fn lookup_domain(&self, domain: &str) -> Result<Vec<DnsRecord>, ResolveError> {
+        let name = format!("{}.", domain.trim_end_matches('.'));
+
+        let lookups = DOMAIN_RECORD_TYPES
+            .iter()
+            .map(|record_type| self.resolver.lookup(name.as_str(), *record_type));
+
+        let mut records = Vec::new();
+        for lookup in join_all(lookups).await {
+            match lookup {
+                Ok(lookup) => records.extend(lookup.record_iter().filter_map(to_record)),
+                Err(error) => records.extend(empty_when_missing(error)?),
+            }
+        }
+
+        Ok(records)
+    }
+}
+
+// A name without records of some type is an answer, not a failure
+fn empty_when_missing(error: ResolveError) -> Result<Vec<DnsRecord>, ResolveError> {
+    match error.kind() {
+        ResolveErrorKind::NoRecordsFound { .. } => Ok(vec![]),
+        _ => Err(error),
+    }
+}
+
+fn to_record(record: &Record) -> Option<DnsRecord> {
+    let name = |name: String| name.trim_end_matches('.').to_owned();
+
+    let (value, detail) = match record.data()? {
+        RData::A(address) => (address.to_string(), String::new()),
+        RData::AAAA(address) => (address.to_string(), String::new()),
+        RData::MX(mx) => (
+            name(mx.exchange().to_string()),
+            format!("preference {}", mx.preference()),
+        ),
+        RData::NS(ns) => (name(ns.0.to_string()), String::new()),
+        RData::PTR(ptr) => (name(ptr.0.to_string()), String::new()),
+        RData::CNAME(cname) => (name(cname.0.to_string()), String::new()),
+        RData::TXT(txt) => (txt.to_string(), String::new()),
+        _ => return None,
+    };
+
+    Some(DnsRecord {
+        kind: record.record_type().to_string(),
+        value,
+        detail,
+        ttl: record.ttl(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hickory_resolver::proto::{
+        op::{Message, MessageType},
+        rr::{
+            rdata::{A, MX, PTR},
+            Name,
+        },
+    };
+    use tokio::net::UdpSocket;
+
+    // Answers every query from a fixed zone until the test ends
+    async fn stub_server() -> SocketAddr {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let address = socket.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let mut buffer = [0; 512];
+            loop {
+                let (read, peer) = socket.recv_from(&mut buffer).await.unwrap();
+                let request = Message::from_vec(&buffer[..read]).unwrap();
+                let query = request.queries()[0].clone();
+                let name = query.name().clone();
+
+                let answer = match (name.to_ascii().as_str(), query.query_type()) {
+                    ("1.2.0.192.in-addr.arpa.", RecordType::PTR) => Some(RData::PTR(PTR(
+                        Name::from_ascii("host.example.test.").unwrap(),
+                    ))),
+                    ("example.test.", RecordType::A) => Some(RData::A(A::new(192, 0, 2, 1))),
+                    ("example.test.", RecordType::MX) => Some(RData::MX(MX::new(
+                        10,
+                        Name::from_ascii("mail.example.test.").unwrap(),
+                    ))),
+                    _ => None,
+                };
+
+                let mut response = Message::new();
+                response
+                    .set_id(request.id())
+                    .set_message_type(MessageType::Response)
+                    .set_recursion_desired(true)
+                    .set_recursion_available(true)
+                    .add_query(query.clone());
+
+                // No answer is an empty NOERROR response, which the resolver reads as no records
+                if let Some(answer) = answer {
+                    response.add_answer(Record::from_rdata(name, 300, answer));
+                }
+
+                let bytes = response.to_vec().unwrap();
+                socket.send_to(&bytes, peer).await.unwrap();
+            }
+        });
+
+        address
+    }
+
+    #[tokio::test]
+    async fn test_reverse_lookup() {
+        let client = Client::new(&[stub_server().await]);
+
+        let records = client
+            .reverse_lookup("192.0.2.1".parse().unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].kind, "PTR");
+        assert_eq!(records[0].value, "host.example.test");
+        assert_eq!(records[0].ttl, 300);
+    }
+
+    #[tokio::test]
+    async fn test_lookup_domain() {
+        let client = Client::new(&[stub_server().await]);
+
+        let records = client.lookup_domain("example.test").await.unwrap();
+
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| record.summary_to_vec())
+                .collect::<Vec<Vec<String>>>(),
+            vec![
+                vec!["A", "192.0.2.1", "", "300"],
+                vec!["MX", "mail.example.test", "preference 10", "300"],
+            ]
+        );
+    }
+}
@@ -0,0 +1,6 @@
+#![allow(clippy::module_inception)]
+mod dns;
+mod models;
+
+pub use dns::*;
+pub use models::*;
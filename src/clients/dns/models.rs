@@ -0,0 +1,38 @@
+use serde::Serialize;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DnsRecord {
+    pub kind: String,
+    // Host name or address the record points to, without the root label
+    pub value: String,
+    pub detail: String,
+    pub ttl: u32,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct PassiveDnsRecord {
+    pub value: String,
+    pub source: String,
+    pub last_seen: String,
+}
+
+impl DnsRecord {
+    pub fn summary_to_vec(&self) -> Vec<String> {
+        vec![
+            self.kind.to_owned(),
+            self.value.to_owned(),
+            self.detail.to_owned(),
+            self.ttl.to_string(),
+        ]
+    }
+}
+
+impl PassiveDnsRecord {
+    pub fn summary_to_vec(&self) -> Vec<String> {
+        vec![
+            self.value.to_owned(),
+            self.source.to_owned(),
+            self.last_seen.to_owned(),
+        ]
+    }
+}
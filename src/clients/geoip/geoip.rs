@@ -0,0 +1,102 @@
+use crate::clients::geoip::GeoIpRecord;
+use crate::config::GeoIpConfig;
+use anyhow::{anyhow, Result};
+use maxminddb::{geoip2, Reader};
+use std::{net::IpAddr, sync::Arc};
+
+/// Local MaxMind database lookups, each database is optional
+#[derive(Clone, Default)]
+pub struct Client {
+    city: Option<Arc<Reader<Vec<u8>>>>,
+    asn: Option<Arc<Reader<Vec<u8>>>>,
+}
+
+impl Client {
+    pub fn new(config: &GeoIpConfig) -> Result<Client> {
+        Ok(Client {
+            city: open(&config.city_database)?,
+            asn: open(&config.asn_database)?,
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.city.is_some() || self.asn.is_some()
+    }
+
+    /// Returns None when neither database knows about `ip`
+    pub fn lookup(&self, ip: IpAddr) -> Option<GeoIpRecord> {
+        let mut record = GeoIpRecord::default();
+        let mut found = false;
+
+        if let Some(city) = self
+            .city
+            .as_ref()
+            .and_then(|reader| reader.lookup::<geoip2::City>(ip).ok())
+        {
+            found = true;
+            let english = |names: Option<std::collections::BTreeMap<&str, &str>>| {
+                names.and_then(|names| names.get("en").map(|name| name.to_string()))
+            };
+
+            if let Some(country) = city.country {
+                record.country = english(country.names);
+                record.country_code = country.iso_code.map(String::from);
+            }
+            record.city = city.city.and_then(|city| english(city.names));
+            if let Some(location) = city.location {
+                record.latitude = location.latitude;
+                record.longitude = location.longitude;
+                record.accuracy_radius = location.accuracy_radius;
+            }
+        }
+
+        if let Some(asn) = self
+            .asn
+            .as_ref()
+            .and_then(|reader| reader.lookup::<geoip2::Asn>(ip).ok())
+        {
+            found = true;
+            record.asn = asn.autonomous_system_number;
+            record.as_organization = asn.autonomous_system_organization.map(String::from);
+        }
+
+        found.then_some(record)
+    }
+}
+
+fn open(path: &str) -> Result<Option<Arc<Reader<Vec<u8>>>>> {
+    if path.is_empty() {
+        return Ok(None);
+    }
+
+    Reader::open_readfile(path)
+        .map(|reader| Some(Arc::new(reader)))
+        .map_err(|e| anyhow!("Could not open MaxMind database {}: {}", path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_without_databases() {
+        let client = Client::new(&GeoIpConfig::default()).unwrap();
+
+        assert!(!client.is_enabled());
+        assert_eq!(client.lookup("8.8.8.8".parse().unwrap()), None);
+    }
+
+    #[test]
+    fn missing_database_is_reported() {
+        let config = GeoIpConfig {
+            city_database: String::from("/nonexistent/GeoLite2-City.mmdb"),
+            ..Default::default()
+        };
+
+        let error = Client::new(&config).err().unwrap().to_string();
+
+        assert!(
+            error.starts_with("Could not open MaxMind database /nonexistent/GeoLite2-City.mmdb")
+        );
+    }
+}
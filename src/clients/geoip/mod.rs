@@ -0,0 +1,6 @@
+#![allow(clippy::module_inception)]
+mod geoip;
+mod models;
+
+pub use geoip::*;
+pub use models::*;
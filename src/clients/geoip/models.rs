@@ -0,0 +1,98 @@
+use serde::Serialize;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GeoIpRecord {
+    pub country: Option<String>,
+    pub country_code: Option<String>,
+    pub city: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub accuracy_radius: Option<u16>,
+    pub asn: Option<u32>,
+    pub as_organization: Option<String>,
+}
+
+impl GeoIpRecord {
+    pub fn coordinates(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+
+    pub fn summary_to_vec(&self) -> Vec<Vec<String>> {
+        let or_na = |value: &Option<String>| value.clone().unwrap_or_else(|| String::from("N/A"));
+
+        vec![
+            vec![String::from("Country"), or_na(&self.country)],
+            vec![String::from("Country Code"), or_na(&self.country_code)],
+            vec![String::from("City"), or_na(&self.city)],
+            vec![
+                String::from("Coordinates"),
+                self.coordinates()
+                    .map_or(String::from("N/A"), |(lat, long)| {
+                        format!("{:.4}, {:.4}", lat, long)
+                    }),
+            ],
+            vec![
+                String::from("Accuracy"),
+                self.accuracy_radius
+                    .map_or(String::from("N/A"), |radius| format!("{} km", radius)),
+            ],
+            vec![
+                String::from("ASN"),
+                self.asn
+                    .map_or(String::from("N/A"), |asn| format!("AS{}", asn)),
+            ],
+            vec![
+                String::from("AS Organization"),
+                or_na(&self.as_organization),
+            ],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_of_partial_record() {
+        // Only the ASN database is configured
+        let record = GeoIpRecord {
+            asn: Some(13335),
+            as_organization: Some(String::from("CLOUDFLARENET")),
+            ..Default::default()
+        };
+
+        assert_eq!(record.coordinates(), None);
+        assert_eq!(
+            record.summary_to_vec(),
+            vec![
+                vec!["Country", "N/A"],
+                vec!["Country Code", "N/A"],
+                vec!["City", "N/A"],
+                vec!["Coordinates", "N/A"],
+                vec!["Accuracy", "N/A"],
+                vec!["ASN", "AS13335"],
+                vec!["AS Organization", "CLOUDFLARENET"],
+            ]
+        );
+    }
+
+    #[test]
+    fn summary_of_city_record() {
+        let record = GeoIpRecord {
+            country: Some(String::from("Netherlands")),
+            city: Some(String::from("Amsterdam")),
+            latitude: Some(52.37403),
+            longitude: Some(4.88969),
+            accuracy_radius: Some(20),
+            ..Default::default()
+        };
+
+        assert_eq!(record.coordinates(), Some((52.37403, 4.88969)));
+        assert_eq!(
+            record.summary_to_vec()[3],
+            vec!["Coordinates", "52.3740, 4.8897"]
+        );
+        assert_eq!(record.summary_to_vec()[4], vec!["Accuracy", "20 km"]);
+    }
+}
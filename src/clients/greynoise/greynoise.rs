@@ -0,0 +1,53 @@
+use crate::clients::{
+    base,
+    greynoise::{GreyNoiseIp, NoiseContext, RiotContext},
+};
+use reqwest;
+
+const BASE_URL: &str = "https://api.greynoise.io";
+
+#[derive(Clone)]
+pub struct Client {
+    api_key: String,
+    enterprise: bool,
+}
+
+impl Client {
+    pub fn new(api_key: String, enterprise: bool) -> Client {
+        Client {
+            api_key,
+            enterprise,
+        }
+    }
+
+    pub async fn lookup_ip(&self, ip: &str) -> Result<GreyNoiseIp, reqwest::StatusCode> {
+        match self.enterprise {
+            true => self.enterprise_context(ip).await,
+            false => self.community(ip).await,
+        }
+    }
+
+    async fn community(&self, ip: &str) -> Result<GreyNoiseIp, reqwest::StatusCode> {
+        let url = format!("{}/v3/community/{}", BASE_URL, ip);
+        let res: Result<GreyNoiseIp, reqwest::StatusCode> =
+            base::get(url, Some(("key", &self.api_key)), None).await;
+
+        res
+    }
+
+    // The enterprise API splits scanner and business service data into two endpoints
+    async fn enterprise_context(&self, ip: &str) -> Result<GreyNoiseIp, reqwest::StatusCode> {
+        let noise_url = format!("{}/v2/noise/context/{}", BASE_URL, ip);
+        let riot_url = format!("{}/v2/riot/{}", BASE_URL, ip);
+
+        let (noise, riot) = futures::join!(
+            base::get::<NoiseContext>(noise_url, Some(("key", &self.api_key)), None),
+            base::get::<RiotContext>(riot_url, Some(("key", &self.api_key)), None)
+        );
+
+        Ok(GreyNoiseIp::from_enterprise(
+            noise?,
+            riot.unwrap_or_default(),
+        ))
+    }
+}
@@ -0,0 +1,6 @@
+#![allow(clippy::module_inception)]
+mod greynoise;
+mod models;
+
+pub use greynoise::*;
+pub use models::*;
@@ -0,0 +1,182 @@
+use serde::{Deserialize, Serialize};
+
+/// Response of the community endpoint, also the shape the enterprise responses are merged into
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GreyNoiseIp {
+    pub ip: String,
+    pub noise: bool,
+    pub riot: bool,
+    pub classification: String,
+    pub name: String,
+    pub link: String,
+    pub last_seen: String,
+    pub message: String,
+    // Only returned by the enterprise endpoints
+    pub first_seen: Option<String>,
+    pub tags: Vec<String>,
+    pub cve: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NoiseContext {
+    pub ip: String,
+    pub seen: bool,
+    pub classification: String,
+    pub first_seen: Option<String>,
+    pub last_seen: String,
+    pub actor: String,
+    pub tags: Vec<String>,
+    pub cve: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RiotContext {
+    pub ip: String,
+    pub riot: bool,
+    pub name: String,
+    pub category: String,
+    pub description: String,
+    pub last_updated: String,
+}
+
+impl GreyNoiseIp {
+    pub fn from_enterprise(noise: NoiseContext, riot: RiotContext) -> GreyNoiseIp {
+        let name = match noise.actor.as_str() {
+            "" | "unknown" => riot.name.to_owned(),
+            actor => actor.to_owned(),
+        };
+
+        GreyNoiseIp {
+            ip: noise.ip,
+            noise: noise.seen,
+            riot: riot.riot,
+            classification: match riot.riot && noise.classification.is_empty() {
+                true => String::from("benign"),
+                false => noise.classification,
+            },
+            name,
+            link: String::new(),
+            last_seen: noise.last_seen,
+            message: riot.description,
+            first_seen: noise.first_seen,
+            tags: noise.tags,
+            cve: noise.cve,
+        }
+    }
+
+    /// Mass scanners and common business services that are safe to close alerts on
+    pub fn is_benign(&self) -> bool {
+        self.riot || (self.noise && self.classification == "benign")
+    }
+
+    pub fn summary_to_vec(&self) -> Vec<Vec<String>> {
+        let or_na = |value: &str| match value {
+            "" => String::from("N/A"),
+            value => value.to_owned(),
+        };
+        let yes_no = |value: bool| match value {
+            true => String::from("Yes"),
+            false => String::from("No"),
+        };
+
+        vec![
+            vec![String::from("IP Address"), or_na(&self.ip)],
+            vec![String::from("Classification"), or_na(&self.classification)],
+            vec![String::from("Noise"), yes_no(self.noise)],
+            vec![String::from("RIOT"), yes_no(self.riot)],
+            vec![String::from("Actor"), or_na(&self.name)],
+            vec![
+                String::from("First Seen"),
+                or_na(self.first_seen.as_deref().unwrap_or_default()),
+            ],
+            vec![String::from("Last Seen"), or_na(&self.last_seen)],
+            vec![String::from("Link"), or_na(&self.link)],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn community_scanner() {
+        let ip: GreyNoiseIp = serde_json::from_str(
+            r#"{
+                "ip": "71.6.135.131",
+                "noise": true,
+                "riot": false,
+                "classification": "benign",
+                "name": "Shodan.io",
+                "link": "https://viz.greynoise.io/ip/71.6.135.131",
+                "last_seen": "2024-03-01",
+                "message": "Success"
+            }"#,
+        )
+        .unwrap();
+
+        assert!(ip.is_benign());
+        assert_eq!(ip.summary_to_vec()[4], vec!["Actor", "Shodan.io"]);
+        // The community API has no first seen date
+        assert_eq!(ip.summary_to_vec()[5], vec!["First Seen", "N/A"]);
+    }
+
+    #[test]
+    fn malicious_scanner_is_not_benign() {
+        let ip = GreyNoiseIp {
+            noise: true,
+            classification: String::from("malicious"),
+            ..Default::default()
+        };
+
+        assert!(!ip.is_benign());
+    }
+
+    #[test]
+    fn enterprise_business_service() {
+        let noise = NoiseContext {
+            ip: String::from("8.8.8.8"),
+            seen: false,
+            actor: String::from("unknown"),
+            ..Default::default()
+        };
+        let riot = RiotContext {
+            ip: String::from("8.8.8.8"),
+            riot: true,
+            name: String::from("Google Public DNS"),
+            description: String::from("Google's global domain name system resolution service"),
+            ..Default::default()
+        };
+
+        let ip = GreyNoiseIp::from_enterprise(noise, riot);
+
+        assert!(ip.is_benign());
+        assert!(!ip.noise);
+        assert_eq!(ip.classification, "benign");
+        assert_eq!(ip.name, "Google Public DNS");
+    }
+
+    #[test]
+    fn enterprise_scanner_keeps_its_actor() {
+        let noise = NoiseContext {
+            ip: String::from("198.51.100.7"),
+            seen: true,
+            classification: String::from("malicious"),
+            first_seen: Some(String::from("2023-01-01")),
+            actor: String::from("Mirai"),
+            tags: vec![String::from("Mirai")],
+            cve: vec![String::from("CVE-2017-17215")],
+            ..Default::default()
+        };
+
+        let ip = GreyNoiseIp::from_enterprise(noise, RiotContext::default());
+
+        assert!(!ip.is_benign());
+        assert_eq!(ip.name, "Mirai");
+        assert_eq!(ip.cve, vec!["CVE-2017-17215"]);
+        assert_eq!(ip.summary_to_vec()[5], vec!["First Seen", "2023-01-01"]);
+    }
+}
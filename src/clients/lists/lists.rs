@@ -0,0 +1,186 @@
+use crate::clients::lists::{AwsRanges, AzureServiceTags, GcpRanges, TaggedRange};
+use anyhow::{anyhow, Result};
+use std::{fs, net::IpAddr, path::Path, sync::Arc};
+
+const TOR_EXITS_URL: &str = "https://check.torproject.org/torbulkexitlist";
+const AWS_URL: &str = "https://ip-ranges.amazonaws.com/ip-ranges.json";
+const GCP_URL: &str = "https://www.gstatic.com/ipranges/cloud.json";
+const CLOUDFLARE_URLS: [&str; 2] = [
+    "https://www.cloudflare.com/ips-v4",
+    "https://www.cloudflare.com/ips-v6",
+];
+
+/// Lists written by `refresh`, any other `.txt` file in the directory is maintained by the user
+pub const DOWNLOADED_LISTS: [&str; 5] = ["tor", "aws", "gcp", "azure", "cloudflare"];
+
+/// Offline lookups of addresses in the lists kept under the config dir
+#[derive(Clone, Default)]
+pub struct Client {
+    ranges: Arc<Vec<TaggedRange>>,
+}
+
+impl Client {
+    /// Reads every `.txt` list in `dir`, which does not exist before the first refresh
+    pub fn new(dir: &Path) -> Result<Client> {
+        let mut ranges = vec![];
+
+        if dir.is_dir() {
+            for entry in fs::read_dir(dir)? {
+                let path = entry?.path();
+                if path.extension().is_none_or(|extension| extension != "txt") {
+                    continue;
+                }
+
+                let list = path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                let contents = fs::read_to_string(&path)
+                    .map_err(|e| anyhow!("Could not read list {}: {}", path.display(), e))?;
+                ranges.extend(
+                    contents
+                        .lines()
+                        .filter_map(|line| TaggedRange::parse(&list, line)),
+                );
+            }
+        }
+
+        Ok(Client {
+            ranges: Arc::new(ranges),
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.ranges.is_empty()
+    }
+
+    /// Tags of the most specific ranges holding `ip` in each list
+    pub fn tags(&self, ip: IpAddr) -> Vec<String> {
+        let matches = self
+            .ranges
+            .iter()
+            .filter(|range| range.network.contains(&ip))
+            .collect::<Vec<&TaggedRange>>();
+
+        let mut tags: Vec<String> = vec![];
+        for range in &matches {
+            let most_specific = matches
+                .iter()
+                .filter(|other| other.list == range.list)
+                .all(|other| other.network.prefix_len() <= range.network.prefix_len());
+
+            if most_specific && !tags.contains(&range.tag) {
+                tags.push(range.tag.to_owned());
+            }
+        }
+
+        tags
+    }
+}
+
+/// Downloads the published lists into `dir`, along with how many ranges each one saved
+pub async fn refresh(dir: &Path, azure_url: &str) -> Result<Vec<(&'static str, Result<usize>)>> {
+    fs::create_dir_all(dir)?;
+
+    let mut results = vec![];
+    for list in DOWNLOADED_LISTS {
+        let saved = match download(list, azure_url).await {
+            Ok(ranges) => save(dir, list, &ranges).map(|_| ranges.len()),
+            Err(e) => Err(e),
+        };
+        results.push((list, saved));
+    }
+
+    Ok(results)
+}
+
+async fn download(list: &str, azure_url: &str) -> Result<Vec<TaggedRange>> {
+    let ranges = match list {
+        "tor" => text_list(list, TOR_EXITS_URL, "Tor exit").await?,
+        "aws" => get::<AwsRanges>(AWS_URL).await?.to_ranges(),
+        "gcp" => get::<GcpRanges>(GCP_URL).await?.to_ranges(),
+        // Microsoft moves the service tags file to a new URL every week
+        "azure" if azure_url.is_empty() => {
+            return Err(anyhow!("skipped, no azure_url in the [lists] table"))
+        }
+        "azure" => get::<AzureServiceTags>(azure_url).await?.to_ranges(),
+        "cloudflare" => {
+            let mut ranges = vec![];
+            for url in CLOUDFLARE_URLS {
+                ranges.extend(text_list(list, url, "Cloudflare").await?);
+            }
+            ranges
+        }
+        _ => return Err(anyhow!("unknown list {}", list)),
+    };
+
+    match ranges.is_empty() {
+        true => Err(anyhow!("no ranges found")),
+        false => Ok(ranges),
+    }
+}
+
+async fn get<T>(url: &str) -> Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    Ok(reqwest::get(url).await?.error_for_status()?.json().await?)
+}
+
+// One address or network per line, every range getting the same tag
+async fn text_list(list: &str, url: &str, tag: &str) -> Result<Vec<TaggedRange>> {
+    let body = reqwest::get(url).await?.error_for_status()?.text().await?;
+
+    Ok(body
+        .lines()
+        .filter_map(|line| TaggedRange::parse(list, line))
+        .map(|range| TaggedRange {
+            tag: tag.to_owned(),
+            ..range
+        })
+        .collect())
+}
+
+fn save(dir: &Path, list: &str, ranges: &[TaggedRange]) -> Result<()> {
+    let mut contents = format!(
+        "# Downloaded by osintui on {}, overwritten on every refresh\n",
+        chrono::Utc::now().format("%Y-%m-%d %H:%M UTC")
+    );
+    for range in ranges {
+        contents.push_str(&range.to_line());
+        contents.push('\n');
+    }
+
+    fs::write(dir.join(format!("{}.txt", list)), contents)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn most_specific_tags() {
+        let dir = std::env::temp_dir().join(format!("osintui-lists-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("aws.txt"),
+            "# comment\n3.0.0.0/9 AWS us-east-1 AMAZON\n3.5.0.0/19 AWS us-east-1 S3\n",
+        )
+        .unwrap();
+        fs::write(dir.join("egress.txt"), "3.5.1.1\n").unwrap();
+        fs::write(dir.join("notes.md"), "3.5.1.1 ignored\n").unwrap();
+
+        let client = Client::new(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let mut tags = client.tags("3.5.1.1".parse().unwrap());
+        tags.sort();
+        assert_eq!(tags, vec!["AWS us-east-1 S3", "egress"]);
+        assert_eq!(
+            client.tags("3.100.0.1".parse().unwrap()),
+            vec!["AWS us-east-1 AMAZON"]
+        );
+        assert!(client.tags("8.8.8.8".parse().unwrap()).is_empty());
+    }
+}
@@ -0,0 +1,6 @@
+#![allow(clippy::module_inception)]
+mod lists;
+mod models;
+
+pub use lists::*;
+pub use models::*;
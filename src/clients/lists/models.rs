@@ -0,0 +1,181 @@
+use ipnet::IpNet;
+use serde::{Deserialize, Serialize};
+use std::{net::IpAddr, str::FromStr};
+
+/// A network from one of the local lists, tagged with what it belongs to
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaggedRange {
+    pub network: IpNet,
+    pub tag: String,
+    // Name of the file the range was read from
+    pub list: String,
+}
+
+impl TaggedRange {
+    /// Parses a `<network or address> [tag]` line, the tag defaulting to the list name
+    pub fn parse(list: &str, line: &str) -> Option<TaggedRange> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        let (value, tag) = match line.split_once(char::is_whitespace) {
+            Some((value, tag)) => (value, tag.trim()),
+            None => (line, list),
+        };
+
+        let network = IpNet::from_str(value)
+            .or_else(|_| value.parse::<IpAddr>().map(IpNet::from))
+            .ok()?;
+
+        Some(TaggedRange {
+            network: network.trunc(),
+            tag: tag.to_owned(),
+            list: list.to_owned(),
+        })
+    }
+
+    pub fn to_line(&self) -> String {
+        format!("{} {}", self.network, self.tag)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AwsRanges {
+    pub prefixes: Vec<AwsPrefix>,
+    pub ipv6_prefixes: Vec<AwsPrefix>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AwsPrefix {
+    #[serde(alias = "ipv6_prefix")]
+    pub ip_prefix: String,
+    pub region: String,
+    pub service: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GcpRanges {
+    pub prefixes: Vec<GcpPrefix>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GcpPrefix {
+    #[serde(alias = "ipv6Prefix")]
+    pub ipv4_prefix: String,
+    pub scope: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AzureServiceTags {
+    pub values: Vec<AzureServiceTag>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AzureServiceTag {
+    pub name: String,
+    pub properties: AzureServiceTagProperties,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AzureServiceTagProperties {
+    pub address_prefixes: Vec<String>,
+}
+
+impl AwsRanges {
+    /// Tagged `AWS <region> <service>`, skipping the catch-all AMAZON entry of ranges that
+    /// a specific service also lists
+    pub fn to_ranges(&self) -> Vec<TaggedRange> {
+        let prefixes = self.prefixes.iter().chain(self.ipv6_prefixes.iter());
+        let specific = prefixes
+            .clone()
+            .filter(|prefix| prefix.service != "AMAZON")
+            .map(|prefix| prefix.ip_prefix.as_str())
+            .collect::<Vec<&str>>();
+
+        prefixes
+            .filter(|prefix| {
+                prefix.service != "AMAZON" || !specific.contains(&prefix.ip_prefix.as_str())
+            })
+            .filter_map(|prefix| {
+                TaggedRange::parse(
+                    "aws",
+                    &format!(
+                        "{} AWS {} {}",
+                        prefix.ip_prefix, prefix.region, prefix.service
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+impl GcpRanges {
+    pub fn to_ranges(&self) -> Vec<TaggedRange> {
+        self.prefixes
+            .iter()
+            .filter_map(|prefix| {
+                TaggedRange::parse(
+                    "gcp",
+                    &format!("{} GCP {}", prefix.ipv4_prefix, prefix.scope),
+                )
+            })
+            .collect()
+    }
+}
+
+impl AzureServiceTags {
+    pub fn to_ranges(&self) -> Vec<TaggedRange> {
+        self.values
+            .iter()
+            .flat_map(|tag| {
+                tag.properties.address_prefixes.iter().filter_map(|prefix| {
+                    TaggedRange::parse("azure", &format!("{} Azure {}", prefix, tag.name))
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_aws_ranges() {
+        let ranges: AwsRanges = serde_json::from_str(
+            r#"{
+                "prefixes": [
+                    {"ip_prefix": "3.5.140.0/22", "region": "ap-northeast-2", "service": "AMAZON"},
+                    {"ip_prefix": "3.5.140.0/22", "region": "ap-northeast-2", "service": "S3"},
+                    {"ip_prefix": "13.34.37.64/27", "region": "ap-southeast-4", "service": "AMAZON"}
+                ],
+                "ipv6_prefixes": [
+                    {"ipv6_prefix": "2600:1f14::/35", "region": "us-west-2", "service": "EC2"}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let tags = ranges
+            .to_ranges()
+            .iter()
+            .map(TaggedRange::to_line)
+            .collect::<Vec<String>>();
+        assert_eq!(
+            tags,
+            vec![
+                "3.5.140.0/22 AWS ap-northeast-2 S3",
+                "13.34.37.64/27 AWS ap-southeast-4 AMAZON",
+                "2600:1f14::/35 AWS us-west-2 EC2",
+            ]
+        );
+    }
+}
@@ -1,4 +1,77 @@
+pub mod abuseipdb;
+pub mod asn;
 pub mod base;
 pub mod censys;
+pub mod crtsh;
+pub mod dns;
+pub mod geoip;
+pub mod greynoise;
+pub mod lists;
+pub mod otx;
+pub mod rdap;
+pub mod securitytrails;
 pub mod shodan;
+#[cfg(test)]
+pub(crate) mod test_server;
+pub mod threatfox;
+pub mod urlhaus;
+pub mod urlscan;
 pub mod virustotal;
+
+use crate::config::Config;
+use anyhow::Result;
+
+/// Every provider client, built once from the config at startup
+#[derive(Clone)]
+pub struct Clients {
+    pub censys: censys::Client,
+    pub shodan: shodan::Client,
+    pub virustotal: virustotal::Client,
+    pub rdap: rdap::Client,
+    pub crtsh: crtsh::Client,
+    pub securitytrails: securitytrails::Client,
+    pub urlscan: urlscan::Client,
+    pub dns: dns::Client,
+    pub asn: asn::Client,
+    pub geoip: geoip::Client,
+    pub lists: lists::Client,
+    pub abuseipdb: abuseipdb::Client,
+    pub greynoise: greynoise::Client,
+    pub otx: otx::Client,
+    pub urlhaus: urlhaus::Client,
+    pub threatfox: threatfox::Client,
+}
+
+impl Clients {
+    pub fn new(config: &Config) -> Result<Clients> {
+        let dns = dns::Client::new(&config.dns.socket_addrs());
+
+        Ok(Clients {
+            censys: censys::Client::new(
+                config.keys.censys_id.clone(),
+                config.keys.censys_secret.clone(),
+            ),
+            shodan: shodan::Client::new(config.keys.shodan.clone()),
+            virustotal: virustotal::Client::new(config.keys.virustotal.clone()),
+            rdap: rdap::Client::new(),
+            crtsh: crtsh::Client::new(),
+            urlscan: urlscan::Client::new(config.keys.urlscan.clone()),
+            securitytrails: securitytrails::Client::new(
+                config.keys.securitytrails.clone(),
+                config.securitytrails.base_url.clone(),
+            ),
+            asn: asn::Client::new(config.keys.ipinfo.clone(), dns.clone(), rdap::Client::new()),
+            dns,
+            geoip: geoip::Client::new(&config.geoip)?,
+            lists: lists::Client::new(&config.get_or_build_paths()?.lists_dir)?,
+            abuseipdb: abuseipdb::Client::new(config.keys.abuseipdb.clone()),
+            greynoise: greynoise::Client::new(
+                config.keys.greynoise.clone(),
+                config.greynoise.enterprise,
+            ),
+            otx: otx::Client::new(config.keys.otx.clone()),
+            urlhaus: urlhaus::Client::new(config.keys.abusech.clone()),
+            threatfox: threatfox::Client::new(config.keys.abusech.clone()),
+        })
+    }
+}
@@ -0,0 +1,6 @@
+#![allow(clippy::module_inception)]
+mod models;
+mod otx;
+
+pub use models::*;
+pub use otx::*;
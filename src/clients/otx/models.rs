@@ -0,0 +1,240 @@
+use crate::indicator::Indicator;
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OtxGeneral {
+    pub indicator: String,
+    pub pulse_info: PulseInfo,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PulseInfo {
+    pub count: u64,
+    pub pulses: Vec<Pulse>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Pulse {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub author: PulseAuthor,
+    pub tags: Vec<String>,
+    pub adversary: String,
+    #[serde(alias = "TLP")]
+    pub tlp: String,
+    pub created: String,
+    pub references: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PulseAuthor {
+    pub username: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OtxReputation {
+    pub reputation: Option<ReputationData>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReputationData {
+    pub threat_score: i64,
+    pub activities: Vec<ReputationActivity>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReputationActivity {
+    pub name: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OtxPassiveDns {
+    pub count: u64,
+    pub passive_dns: Vec<OtxPassiveDnsRecord>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OtxPassiveDnsRecord {
+    pub address: String,
+    pub hostname: String,
+    pub record_type: String,
+    pub first: String,
+    pub last: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OtxMalware {
+    pub count: u64,
+    pub data: Vec<OtxMalwareSample>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OtxMalwareSample {
+    pub hash: String,
+    pub datetime_int: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OtxUrlList {
+    pub full_size: u64,
+    pub url_list: Vec<OtxUrl>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OtxUrl {
+    pub url: String,
+    pub date: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PulseIndicators {
+    pub count: u64,
+    pub results: Vec<PulseIndicator>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PulseIndicator {
+    pub indicator: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub created: String,
+}
+
+/// Everything OTX knows about an indicator, the sections besides `general` are best effort
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OtxIndicator {
+    pub general: OtxGeneral,
+    pub reputation: OtxReputation,
+    pub passive_dns: OtxPassiveDns,
+    pub malware: OtxMalware,
+    pub url_list: OtxUrlList,
+}
+
+impl OtxIndicator {
+    pub fn summary_to_vec(&self) -> Vec<Vec<String>> {
+        let threat_score = self
+            .reputation
+            .reputation
+            .as_ref()
+            .map_or(String::from("N/A"), |reputation| {
+                reputation.threat_score.to_string()
+            });
+
+        vec![
+            vec![String::from("Indicator"), self.general.indicator.to_owned()],
+            vec![
+                String::from("Pulses"),
+                self.general.pulse_info.count.to_string(),
+            ],
+            vec![String::from("Threat Score"), threat_score],
+            vec![
+                String::from("Passive DNS"),
+                self.passive_dns.count.to_string(),
+            ],
+            vec![String::from("Malware"), self.malware.count.to_string()],
+            vec![String::from("URLs"), self.url_list.full_size.to_string()],
+        ]
+    }
+}
+
+impl Pulse {
+    pub fn summary_to_vec(&self) -> Vec<String> {
+        let or_na = |value: &str| match value {
+            "" => String::from("N/A"),
+            value => value.to_owned(),
+        };
+
+        vec![
+            self.name.to_owned(),
+            or_na(&self.author.username),
+            or_na(&self.adversary),
+            or_na(&self.tlp),
+            self.created.chars().take(10).collect(),
+            self.tags.join(", "),
+        ]
+    }
+}
+
+impl PulseIndicator {
+    /// The IOC as a lookup, CVEs, emails and other types osintui can't search are `None`
+    pub fn pivot(&self) -> Option<Indicator> {
+        Indicator::parse(&self.indicator)
+    }
+
+    pub fn summary_to_vec(&self) -> Vec<String> {
+        vec![
+            self.kind.to_owned(),
+            self.indicator.to_owned(),
+            self.created.chars().take(10).collect(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_general_pulses() {
+        let general: OtxGeneral = serde_json::from_str(
+            r#"{
+                "indicator": "198.51.100.7",
+                "pulse_info": {
+                    "count": 1,
+                    "pulses": [{
+                        "id": "5f1e",
+                        "name": "Cobalt Strike servers",
+                        "author": {"username": "analyst"},
+                        "tags": ["c2"],
+                        "adversary": "",
+                        "TLP": "white",
+                        "created": "2023-04-01T10:00:00.000000"
+                    }]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let pulse = &general.pulse_info.pulses[0];
+        assert_eq!(pulse.tlp, "white");
+        assert_eq!(
+            pulse.summary_to_vec(),
+            vec![
+                "Cobalt Strike servers",
+                "analyst",
+                "N/A",
+                "white",
+                "2023-04-01",
+                "c2"
+            ]
+        );
+    }
+
+    #[test]
+    fn pivot_pulse_indicators() {
+        let indicator = |kind: &str, value: &str| PulseIndicator {
+            indicator: value.to_owned(),
+            kind: kind.to_owned(),
+            created: String::new(),
+        };
+
+        assert!(indicator("hostname", "c2.example.com").pivot().is_some());
+        assert!(indicator("CVE", "CVE-2021-44228").pivot().is_none());
+    }
+}
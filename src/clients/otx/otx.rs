@@ -0,0 +1,104 @@
+use crate::clients::{
+    base,
+    otx::{OtxGeneral, OtxMalware, OtxPassiveDns, OtxReputation, OtxUrlList, PulseIndicators},
+};
+use crate::indicator::Indicator;
+use reqwest::{self, Url};
+use serde::de::DeserializeOwned;
+
+const BASE_URL: &str = "https://otx.alienvault.com/api/v1";
+
+#[derive(Clone)]
+pub struct Client {
+    api_key: String,
+}
+
+impl Client {
+    pub fn new(api_key: String) -> Client {
+        Client { api_key }
+    }
+
+    pub async fn general(&self, indicator: &Indicator) -> Result<OtxGeneral, reqwest::StatusCode> {
+        self.section(indicator, "general").await
+    }
+
+    pub async fn reputation(
+        &self,
+        indicator: &Indicator,
+    ) -> Result<OtxReputation, reqwest::StatusCode> {
+        self.section(indicator, "reputation").await
+    }
+
+    pub async fn passive_dns(
+        &self,
+        indicator: &Indicator,
+    ) -> Result<OtxPassiveDns, reqwest::StatusCode> {
+        self.section(indicator, "passive_dns").await
+    }
+
+    pub async fn malware(&self, indicator: &Indicator) -> Result<OtxMalware, reqwest::StatusCode> {
+        self.section(indicator, "malware").await
+    }
+
+    pub async fn url_list(&self, indicator: &Indicator) -> Result<OtxUrlList, reqwest::StatusCode> {
+        self.section(indicator, "url_list").await
+    }
+
+    pub async fn pulse_indicators(
+        &self,
+        pulse_id: &str,
+    ) -> Result<PulseIndicators, reqwest::StatusCode> {
+        let url = Url::parse_with_params(
+            &format!("{}/pulses/{}/indicators", BASE_URL, pulse_id),
+            &[("limit", "100")],
+        )
+        .map_err(|_| reqwest::StatusCode::BAD_REQUEST)?;
+
+        let res: Result<PulseIndicators, reqwest::StatusCode> = base::get(
+            url.to_string(),
+            Some(("X-OTX-API-KEY", &self.api_key)),
+            None,
+        )
+        .await;
+
+        res
+    }
+
+    async fn section<T>(
+        &self,
+        indicator: &Indicator,
+        section: &str,
+    ) -> Result<T, reqwest::StatusCode>
+    where
+        T: DeserializeOwned,
+    {
+        let kind = match indicator {
+            Indicator::Ip(ip) if ip.is_ipv4() => "IPv4",
+            Indicator::Ip(_) => "IPv6",
+            Indicator::Domain(_) => "domain",
+            Indicator::Url(_) => "url",
+            Indicator::Hash(_) => "file",
+            Indicator::Email(_) | Indicator::Asn(_) | Indicator::Cidr(_) => {
+                return Err(reqwest::StatusCode::NOT_FOUND)
+            }
+        };
+
+        // URLs are a single path segment, so they have to be escaped
+        let mut url = Url::parse(&format!("{}/indicators/{}/", BASE_URL, kind))
+            .map_err(|_| reqwest::StatusCode::BAD_REQUEST)?;
+        url.path_segments_mut()
+            .map_err(|_| reqwest::StatusCode::BAD_REQUEST)?
+            .pop_if_empty()
+            .push(&indicator.to_string())
+            .push(section);
+
+        let res: Result<T, reqwest::StatusCode> = base::get(
+            url.to_string(),
+            Some(("X-OTX-API-KEY", &self.api_key)),
+            None,
+        )
+        .await;
+
+        res
+    }
+}
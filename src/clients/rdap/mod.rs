@@ -0,0 +1,6 @@
+#![allow(clippy::module_inception)]
+mod models;
+mod rdap;
+
+pub use models::*;
+pub use rdap::*;
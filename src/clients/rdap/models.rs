@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct IpNetwork {
+    pub handle: String,
+    pub start_address: String,
+    pub end_address: String,
+    pub ip_version: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub country: String,
+    pub parent_handle: String,
+    pub port43: String,
+    pub entities: Vec<Entity>,
+    pub events: Vec<Event>,
+    #[serde(rename = "cidr0_cidrs")]
+    pub cidrs: Vec<Cidr>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Entity {
+    pub handle: String,
+    pub roles: Vec<String>,
+    // jCard (RFC 7095): ["vcard", [[name, params, type, value], ...]]
+    pub vcard_array: Vec<serde_json::Value>,
+    pub entities: Vec<Entity>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Event {
+    pub event_action: String,
+    pub event_date: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Cidr {
+    pub v4prefix: Option<String>,
+    pub v6prefix: Option<String>,
+    pub length: u8,
+}
+
+impl Entity {
+    // Returns the first value of a jCard property, e.g. "fn" or "email"
+    pub fn vcard(&self, property: &str) -> Option<String> {
+        self.vcard_array
+            .get(1)?
+            .as_array()?
+            .iter()
+            .filter_map(|field| field.as_array())
+            .find(|field| field.first().and_then(|name| name.as_str()) == Some(property))
+            .and_then(|field| field.get(3))
+            .and_then(|value| match value {
+                serde_json::Value::String(value) => Some(value.to_owned()),
+                serde_json::Value::Array(lines) => Some(
+                    lines
+                        .iter()
+                        .filter_map(|line| line.as_str())
+                        .collect::<Vec<&str>>()
+                        .join(", "),
+                ),
+                _ => None,
+            })
+    }
+
+    fn find_role(&self, role: &str) -> Option<&Entity> {
+        if self.roles.iter().any(|r| r == role) {
+            return Some(self);
+        }
+
+        self.entities
+            .iter()
+            .find_map(|entity| entity.find_role(role))
+    }
+}
+
+impl IpNetwork {
+    fn entity(&self, role: &str) -> Option<&Entity> {
+        self.entities
+            .iter()
+            .find_map(|entity| entity.find_role(role))
+    }
+
+    fn event(&self, action: &str) -> String {
+        self.events
+            .iter()
+            .find(|event| event.event_action == action)
+            .map_or(String::from("N/A"), |event| event.event_date.to_owned())
+    }
+
+    pub fn organization(&self) -> String {
+        self.entity("registrant")
+            .and_then(|entity| {
+                entity
+                    .vcard("fn")
+                    .or_else(|| Some(entity.handle.to_owned()))
+            })
+            .unwrap_or_else(|| String::from("N/A"))
+    }
+
+    pub fn abuse_contact(&self) -> String {
+        self.entity("abuse")
+            .and_then(|entity| entity.vcard("email").or_else(|| entity.vcard("fn")))
+            .unwrap_or_else(|| String::from("N/A"))
+    }
+
+    pub fn cidr(&self) -> String {
+        let cidrs = self
+            .cidrs
+            .iter()
+            .filter_map(|cidr| {
+                cidr.v4prefix
+                    .as_ref()
+                    .or(cidr.v6prefix.as_ref())
+                    .map(|prefix| format!("{}/{}", prefix, cidr.length))
+            })
+            .collect::<Vec<String>>();
+
+        match cidrs.is_empty() {
+            true => String::from("N/A"),
+            false => cidrs.join(", "),
+        }
+    }
+
+    pub fn summary_to_vec(&self) -> Vec<Vec<String>> {
+        let or_na = |value: &str| match value {
+            "" => String::from("N/A"),
+            value => value.to_owned(),
+        };
+
+        vec![
+            vec![
+                String::from("Network Range"),
+                format!("{} - {}", self.start_address, self.end_address),
+            ],
+            vec![String::from("CIDR"), self.cidr()],
+            vec![String::from("Name"), or_na(&self.name)],
+            vec![String::from("Handle"), or_na(&self.handle)],
+            vec![String::from("Type"), or_na(&self.kind)],
+            vec![String::from("Parent"), or_na(&self.parent_handle)],
+            vec![String::from("Organization"), self.organization()],
+            vec![String::from("Abuse Contact"), self.abuse_contact()],
+            vec![String::from("Country"), or_na(&self.country)],
+            vec![String::from("Registered"), self.event("registration")],
+            vec![String::from("Last Changed"), self.event("last changed")],
+            vec![String::from("Whois Server"), or_na(&self.port43)],
+        ]
+    }
+}
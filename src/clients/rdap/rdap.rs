@@ -0,0 +1,164 @@
+use crate::clients::base;
+use crate::clients::rdap::IpNetwork;
+use ipnet::IpNet;
+use serde::Deserialize;
+use std::{net::IpAddr, sync::OnceLock};
+
+// IANA RDAP bootstrap registries (RFC 9224), bundled so lookups need no extra round trip
+const IPV4_BOOTSTRAP: &str = include_str!("bootstrap/ipv4.json");
+const IPV6_BOOTSTRAP: &str = include_str!("bootstrap/ipv6.json");
+
+// ARIN redirects queries for space it does not manage to the right registry
+const FALLBACK_URL: &str = "https://rdap.arin.net/registry/";
+
+#[derive(Deserialize)]
+struct Bootstrap {
+    services: Vec<(Vec<String>, Vec<String>)>,
+}
+
+/// RDAP API object
+#[derive(Debug, Clone, Default)]
+pub struct Client {
+    base_url: Option<String>,
+}
+
+impl Client {
+    pub fn new() -> Client {
+        Client { base_url: None }
+    }
+
+    /// Sends every query to `base_url` instead of the registry from the bootstrap data
+    pub fn with_base_url(base_url: String) -> Client {
+        Client {
+            base_url: Some(base_url),
+        }
+    }
+
+    pub async fn lookup_ip(&self, ip: &str) -> Result<IpNetwork, reqwest::StatusCode> {
+        let address = ip
+            .parse::<IpAddr>()
+            .map_err(|_| reqwest::StatusCode::BAD_REQUEST)?;
+
+        let base_url = match &self.base_url {
+            Some(base_url) => base_url.to_owned(),
+            None => registry_for(address),
+        };
+        let url = format!("{}/ip/{}", base_url.trim_end_matches('/'), address);
+
+        let res: Result<IpNetwork, reqwest::StatusCode> =
+            base::get(url, Some(("Accept", "application/rdap+json")), None).await;
+
+        res
+    }
+
+    /// Autonomous system records share the entity and event layout of IP networks
+    pub async fn lookup_autnum(&self, asn: u32) -> Result<IpNetwork, reqwest::StatusCode> {
+        let base_url = self
+            .base_url
+            .clone()
+            .unwrap_or_else(|| FALLBACK_URL.to_string());
+        let url = format!("{}/autnum/{}", base_url.trim_end_matches('/'), asn);
+
+        let res: Result<IpNetwork, reqwest::StatusCode> =
+            base::get(url, Some(("Accept", "application/rdap+json")), None).await;
+
+        res
+    }
+}
+
+fn bootstrap() -> &'static Vec<(IpNet, String)> {
+    static REGISTRIES: OnceLock<Vec<(IpNet, String)>> = OnceLock::new();
+
+    REGISTRIES.get_or_init(|| {
+        [IPV4_BOOTSTRAP, IPV6_BOOTSTRAP]
+            .iter()
+            .filter_map(|data| serde_json::from_str::<Bootstrap>(data).ok())
+            .flat_map(|bootstrap| bootstrap.services)
+            .filter_map(|(prefixes, urls)| {
+                let url = urls.into_iter().find(|url| url.starts_with("https"))?;
+                Some(
+                    prefixes
+                        .iter()
+                        .filter_map(|prefix| prefix.parse::<IpNet>().ok())
+                        .map(|prefix| (prefix, url.to_owned()))
+                        .collect::<Vec<(IpNet, String)>>(),
+                )
+            })
+            .flatten()
+            .collect()
+    })
+}
+
+/// Finds the RDAP service of the registry managing `address`, using the most specific prefix
+pub fn registry_for(address: IpAddr) -> String {
+    bootstrap()
+        .iter()
+        .filter(|(prefix, _)| prefix.contains(&address))
+        .max_by_key(|(prefix, _)| prefix.prefix_len())
+        .map_or(String::from(FALLBACK_URL), |(_, url)| url.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::test_server::serve_once;
+
+    const NETWORK: &str = r#"{
+        "objectClassName": "ip network",
+        "handle": "NET-192-0-2-0-1",
+        "startAddress": "192.0.2.0",
+        "endAddress": "192.0.2.255",
+        "ipVersion": "v4",
+        "name": "TEST-NET-1",
+        "type": "IANA Special Use",
+        "country": "US",
+        "cidr0_cidrs": [{ "v4prefix": "192.0.2.0", "length": 24 }],
+        "events": [
+            { "eventAction": "registration", "eventDate": "2010-01-01T00:00:00Z" },
+            { "eventAction": "last changed", "eventDate": "2020-06-01T00:00:00Z" }
+        ],
+        "entities": [{
+            "handle": "IANA",
+            "roles": ["registrant"],
+            "vcardArray": ["vcard", [["version", {}, "text", "4.0"], ["fn", {}, "text", "Internet Assigned Numbers Authority"]]],
+            "entities": [{
+                "handle": "IANA-IP-ARIN",
+                "roles": ["abuse"],
+                "vcardArray": ["vcard", [["fn", {}, "text", "ICANN"], ["email", {}, "text", "abuse@iana.org"]]]
+            }]
+        }]
+    }"#;
+
+    #[test]
+    fn test_registry_for() {
+        let registry = |ip: &str| registry_for(ip.parse().unwrap());
+
+        assert_eq!(registry("1.1.1.1"), "https://rdap.apnic.net/");
+        assert_eq!(registry("8.8.8.8"), "https://rdap.arin.net/registry/");
+        assert_eq!(registry("185.1.1.1"), "https://rdap.db.ripe.net/");
+        assert_eq!(registry("200.1.1.1"), "https://rdap.lacnic.net/rdap/");
+        assert_eq!(registry("41.1.1.1"), "https://rdap.afrinic.net/rdap/");
+        assert_eq!(registry("2a00:1450::1"), "https://rdap.db.ripe.net/");
+        assert_eq!(registry("10.0.0.1"), FALLBACK_URL);
+    }
+
+    #[tokio::test]
+    async fn test_lookup_ip() {
+        let (base_url, request) = serve_once("application/rdap+json", NETWORK).await;
+        let client = Client::with_base_url(base_url);
+
+        let network = client.lookup_ip("192.0.2.1").await.unwrap();
+
+        assert_eq!(request.await.unwrap(), "GET /ip/192.0.2.1 HTTP/1.1");
+        assert_eq!(network.cidr(), "192.0.2.0/24");
+        assert_eq!(
+            network.organization(),
+            "Internet Assigned Numbers Authority"
+        );
+        assert_eq!(network.abuse_contact(), "abuse@iana.org");
+        assert_eq!(
+            network.summary_to_vec()[0],
+            vec!["Network Range", "192.0.2.0 - 192.0.2.255"]
+        );
+    }
+}
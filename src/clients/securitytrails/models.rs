@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DnsHistory {
+    pub records: Vec<DnsHistoryRecord>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DnsHistoryRecord {
+    pub values: Vec<DnsHistoryValue>,
+    pub first_seen: Option<String>,
+    pub last_seen: Option<String>,
+    pub organizations: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DnsHistoryValue {
+    pub ip: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AssociatedDomains {
+    pub record_count: u64,
+    pub records: Vec<AssociatedDomain>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AssociatedDomain {
+    pub hostname: String,
+    pub host_provider: Vec<String>,
+    pub whois: AssociatedWhois,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AssociatedWhois {
+    pub registrar: Option<String>,
+    pub created_date: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WhoisHistory {
+    pub result: WhoisHistoryResult,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WhoisHistoryResult {
+    pub count: u64,
+    pub items: Vec<WhoisRecord>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct WhoisRecord {
+    // Milliseconds since the epoch
+    pub started: Option<i64>,
+    pub ended: Option<i64>,
+    pub registrar_name: Option<String>,
+    pub contact: Vec<WhoisContact>,
+    pub name_servers: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WhoisContact {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub organization: Option<String>,
+    pub email: Option<String>,
+}
+
+/// One row of the history table, an address of a domain or a domain of an address
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub value: String,
+    pub first_seen: String,
+    pub last_seen: String,
+    pub detail: String,
+}
+
+fn format_millis(millis: Option<i64>) -> String {
+    millis
+        .and_then(chrono::DateTime::from_timestamp_millis)
+        .map_or(String::from("N/A"), |date| {
+            date.format("%Y-%m-%d").to_string()
+        })
+}
+
+impl HistoryEntry {
+    pub fn summary_to_vec(&self) -> Vec<String> {
+        vec![
+            self.value.to_owned(),
+            self.first_seen.to_owned(),
+            self.last_seen.to_owned(),
+            self.detail.to_owned(),
+        ]
+    }
+}
+
+impl DnsHistory {
+    pub fn entries(&self) -> Vec<HistoryEntry> {
+        self.records
+            .iter()
+            .flat_map(|record| {
+                record.values.iter().map(move |value| HistoryEntry {
+                    value: value.ip.to_owned(),
+                    first_seen: record.first_seen.clone().unwrap_or_default(),
+                    last_seen: record.last_seen.clone().unwrap_or_default(),
+                    detail: record.organizations.join(", "),
+                })
+            })
+            .collect()
+    }
+}
+
+impl AssociatedDomains {
+    pub fn entries(&self) -> Vec<HistoryEntry> {
+        self.records
+            .iter()
+            .map(|domain| HistoryEntry {
+                value: domain.hostname.to_owned(),
+                first_seen: format_millis(domain.whois.created_date),
+                last_seen: String::new(),
+                detail: domain
+                    .whois
+                    .registrar
+                    .clone()
+                    .unwrap_or_else(|| domain.host_provider.join(", ")),
+            })
+            .collect()
+    }
+}
+
+impl WhoisRecord {
+    pub fn registrant(&self) -> String {
+        self.contact
+            .iter()
+            .find(|contact| contact.kind == "registrant")
+            .and_then(|contact| {
+                contact
+                    .organization
+                    .clone()
+                    .or_else(|| contact.email.clone())
+            })
+            .unwrap_or_else(|| String::from("N/A"))
+    }
+
+    pub fn summary_to_vec(&self) -> Vec<String> {
+        vec![
+            format_millis(self.started),
+            match self.ended {
+                Some(_) => format_millis(self.ended),
+                None => String::from("Current"),
+            },
+            self.registrar_name
+                .clone()
+                .unwrap_or_else(|| String::from("N/A")),
+            self.registrant(),
+            self.name_servers.join(", "),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flatten_a_history() {
+        let history: DnsHistory = serde_json::from_str(
+            r#"{
+                "records": [{
+                    "values": [{"ip": "198.51.100.7", "ip_count": 2}, {"ip": "198.51.100.8", "ip_count": 2}],
+                    "first_seen": "2021-03-01",
+                    "last_seen": "2023-11-20",
+                    "organizations": ["Example Hosting"],
+                    "type": "a"
+                }],
+                "pages": 1
+            }"#,
+        )
+        .unwrap();
+
+        let entries = history.entries();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries[1].summary_to_vec(),
+            vec![
+                "198.51.100.8",
+                "2021-03-01",
+                "2023-11-20",
+                "Example Hosting"
+            ]
+        );
+    }
+}
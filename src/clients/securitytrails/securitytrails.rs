@@ -0,0 +1,52 @@
+use crate::clients::{
+    base,
+    securitytrails::{AssociatedDomains, DnsHistory, WhoisHistory},
+};
+use reqwest;
+use serde_json::json;
+
+/// SecurityTrails API object, any service implementing the same endpoints works
+#[derive(Clone)]
+pub struct Client {
+    api_key: String,
+    base_url: String,
+}
+
+impl Client {
+    pub fn new(api_key: String, base_url: String) -> Client {
+        Client {
+            api_key,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+        }
+    }
+
+    pub async fn a_history(&self, domain: &str) -> Result<DnsHistory, reqwest::StatusCode> {
+        let url = format!("{}/history/{}/dns/a", self.base_url, domain);
+        let res: Result<DnsHistory, reqwest::StatusCode> =
+            base::get(url, Some(("APIKEY", &self.api_key)), None).await;
+
+        res
+    }
+
+    pub async fn whois_history(&self, domain: &str) -> Result<WhoisHistory, reqwest::StatusCode> {
+        let url = format!("{}/history/{}/whois", self.base_url, domain);
+        let res: Result<WhoisHistory, reqwest::StatusCode> =
+            base::get(url, Some(("APIKEY", &self.api_key)), None).await;
+
+        res
+    }
+
+    /// Domains whose A records point, or pointed, at `ip`
+    pub async fn associated_domains(
+        &self,
+        ip: &str,
+    ) -> Result<AssociatedDomains, reqwest::StatusCode> {
+        let url = format!("{}/domains/list", self.base_url);
+        let body = json!({ "filter": { "ipv4": ip } });
+
+        let res: Result<AssociatedDomains, reqwest::StatusCode> =
+            base::post_json(url, Some(("APIKEY", &self.api_key)), &body).await;
+
+        res
+    }
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ShodanSearchIp {
@@ -17,6 +18,9 @@ pub struct ShodanSearchIp {
     pub city: Option<String>,
     pub country_code: Option<String>,
     pub country_name: Option<String>,
+    pub vulns: Option<Vec<String>>,
+    pub tags: Option<Vec<String>>,
+    pub last_update: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -27,6 +31,58 @@ pub struct ServiceData {
     pub port: i32,
     pub transport: Option<String>,
     pub location: Option<Location>,
+    pub timestamp: Option<String>,
+    pub cpe: Option<Vec<String>>,
+    pub cpe23: Option<Vec<String>>,
+    pub http: Option<Http>,
+    pub ssl: Option<Ssl>,
+    pub vulns: Option<HashMap<String, Vulnerability>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Http {
+    pub title: Option<String>,
+    pub server: Option<String>,
+    pub favicon: Option<Favicon>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Favicon {
+    pub hash: i64,
+    pub location: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ssl {
+    pub cert: Option<SslCertificate>,
+    pub jarm: Option<String>,
+    pub cipher: Option<Cipher>,
+    pub versions: Option<Vec<String>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SslCertificate {
+    pub subject: Option<HashMap<String, String>>,
+    pub issuer: Option<HashMap<String, String>>,
+    pub issued: Option<String>,
+    pub expires: Option<String>,
+    pub fingerprint: Option<HashMap<String, String>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cipher {
+    pub name: Option<String>,
+    pub bits: Option<i32>,
+    pub version: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Vulnerability {
+    // Usually a number, but older banners report it as a string
+    pub cvss: Option<serde_json::Value>,
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub verified: bool,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -40,6 +96,49 @@ pub struct Location {
     pub region_code: Option<String>,
 }
 
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShodanHostSearch {
+    #[serde(default)]
+    pub matches: Vec<ShodanMatch>,
+    pub facets: Option<Facets>,
+    pub total: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShodanMatch {
+    pub ip_str: String,
+    pub port: i32,
+    pub transport: Option<String>,
+    pub product: Option<String>,
+    pub org: Option<String>,
+    pub hostnames: Option<Vec<String>>,
+    pub location: Option<Location>,
+    pub timestamp: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Facets {
+    pub port: Option<Vec<FacetBucket>>,
+    pub country: Option<Vec<FacetBucket>>,
+    pub org: Option<Vec<FacetBucket>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FacetBucket {
+    pub count: u64,
+    // Ports come back as numbers while countries and orgs are strings
+    pub value: serde_json::Value,
+}
+
+impl FacetBucket {
+    pub fn label(&self) -> String {
+        match &self.value {
+            serde_json::Value::String(value) => value.to_string(),
+            value => value.to_string(),
+        }
+    }
+}
+
 impl ShodanSearchIp {
     pub fn summary_to_vec(&self) -> Vec<Vec<String>> {
         vec![
@@ -64,6 +163,209 @@ impl ShodanSearchIp {
                 String::from("Operating System"),
                 self.os.as_deref().unwrap_or("N/A").to_string(),
             ],
+            vec![
+                String::from("Tags"),
+                self.tags
+                    .as_ref()
+                    .filter(|tags| !tags.is_empty())
+                    .map_or(String::from("N/A"), |x| x.join(", ")),
+            ],
+            vec![
+                String::from("Vulnerabilities"),
+                self.vulns
+                    .as_ref()
+                    .map_or(0, |vulns| vulns.len())
+                    .to_string(),
+            ],
+            vec![
+                String::from("Last Update"),
+                self.last_update.as_deref().unwrap_or("N/A").to_string(),
+            ],
         ]
     }
 }
+
+impl Vulnerability {
+    pub fn cvss(&self) -> Option<f64> {
+        match &self.cvss {
+            Some(serde_json::Value::Number(cvss)) => cvss.as_f64(),
+            Some(serde_json::Value::String(cvss)) => cvss.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl ServiceData {
+    pub fn details_to_vec(&self) -> Vec<Vec<String>> {
+        let http = self.http.clone().unwrap_or_default();
+        let ssl = self.ssl.clone().unwrap_or_default();
+        let cert = ssl.cert.clone().unwrap_or_default();
+        let common_name = |name: &Option<HashMap<String, String>>| {
+            name.as_ref()
+                .and_then(|name| name.get("CN").cloned())
+                .unwrap_or_else(|| String::from("N/A"))
+        };
+
+        vec![
+            vec![
+                String::from("Timestamp"),
+                self.timestamp.as_deref().unwrap_or("N/A").to_string(),
+            ],
+            vec![
+                String::from("CPE"),
+                self.cpe23
+                    .as_ref()
+                    .or(self.cpe.as_ref())
+                    .filter(|cpe| !cpe.is_empty())
+                    .map_or(String::from("N/A"), |x| x.join(", ")),
+            ],
+            vec![
+                String::from("HTTP Title"),
+                http.title.as_deref().unwrap_or("N/A").to_string(),
+            ],
+            vec![
+                String::from("HTTP Server"),
+                http.server.as_deref().unwrap_or("N/A").to_string(),
+            ],
+            vec![
+                String::from("Favicon Hash"),
+                http.favicon
+                    .map_or(String::from("N/A"), |favicon| favicon.hash.to_string()),
+            ],
+            vec![String::from("SSL Subject"), common_name(&cert.subject)],
+            vec![String::from("SSL Issuer"), common_name(&cert.issuer)],
+            vec![
+                String::from("SSL Expires"),
+                cert.expires.as_deref().unwrap_or("N/A").to_string(),
+            ],
+            vec![
+                String::from("SSL SHA-256"),
+                cert.fingerprint
+                    .as_ref()
+                    .and_then(|fingerprint| fingerprint.get("sha256").cloned())
+                    .unwrap_or_else(|| String::from("N/A")),
+            ],
+            vec![
+                String::from("JARM"),
+                ssl.jarm.as_deref().unwrap_or("N/A").to_string(),
+            ],
+            vec![
+                String::from("Cipher"),
+                ssl.cipher.map_or(String::from("N/A"), |cipher| {
+                    format!(
+                        "{} {}",
+                        cipher.version.as_deref().unwrap_or_default(),
+                        cipher.name.as_deref().unwrap_or_default()
+                    )
+                }),
+            ],
+        ]
+    }
+
+    /// Vulnerabilities reported for this service, highest CVSS score first
+    pub fn sorted_vulns(&self) -> Vec<(String, Vulnerability)> {
+        let mut vulns = self
+            .vulns
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .collect::<Vec<(String, Vulnerability)>>();
+
+        vulns.sort_by(|(a_id, a), (b_id, b)| {
+            b.cvss()
+                .unwrap_or(0.0)
+                .total_cmp(&a.cvss().unwrap_or(0.0))
+                .then_with(|| a_id.cmp(b_id))
+        });
+
+        vulns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BANNER: &str = r#"{
+        "port": 443,
+        "transport": "tcp",
+        "product": "nginx",
+        "timestamp": "2024-03-01T12:00:00.000000",
+        "cpe23": ["cpe:2.3:a:f5:nginx:1.18.0"],
+        "http": {"title": "Login", "server": "nginx/1.18.0", "favicon": {"hash": -1234}},
+        "ssl": {
+            "cert": {
+                "subject": {"CN": "login.example.com"},
+                "issuer": {"CN": "R3"},
+                "expires": "20240601000000Z",
+                "fingerprint": {"sha256": "ab12"}
+            },
+            "jarm": "2ad2ad",
+            "cipher": {"name": "TLS_AES_256_GCM_SHA384", "bits": 256, "version": "TLSv1.3"}
+        },
+        "vulns": {
+            "CVE-2021-23017": {"cvss": 7.7, "summary": "Off-by-one in the resolver", "verified": false},
+            "CVE-2019-20372": {"cvss": "4.3", "summary": "HTTP request smuggling"},
+            "CVE-2023-44487": {"cvss": 7.7, "summary": "HTTP/2 rapid reset", "verified": true}
+        }
+    }"#;
+
+    #[test]
+    fn vulns_sorted_by_cvss() {
+        let banner: ServiceData = serde_json::from_str(BANNER).unwrap();
+
+        let vulns = banner.sorted_vulns();
+        let ids = vulns
+            .iter()
+            .map(|(id, _)| id.as_str())
+            .collect::<Vec<&str>>();
+
+        // Ties on the score keep a stable order by CVE id
+        assert_eq!(
+            ids,
+            vec!["CVE-2021-23017", "CVE-2023-44487", "CVE-2019-20372"]
+        );
+        assert_eq!(vulns[2].1.cvss(), Some(4.3));
+        assert!(vulns[1].1.verified);
+    }
+
+    #[test]
+    fn banner_details() {
+        let banner: ServiceData = serde_json::from_str(BANNER).unwrap();
+
+        let details = banner.details_to_vec();
+        let value = |name: &str| {
+            details
+                .iter()
+                .find(|row| row[0] == name)
+                .map(|row| row[1].to_owned())
+                .unwrap()
+        };
+
+        assert_eq!(value("CPE"), "cpe:2.3:a:f5:nginx:1.18.0");
+        assert_eq!(value("Favicon Hash"), "-1234");
+        assert_eq!(value("SSL Subject"), "login.example.com");
+        assert_eq!(value("SSL SHA-256"), "ab12");
+        assert_eq!(value("Cipher"), "TLSv1.3 TLS_AES_256_GCM_SHA384");
+
+        let empty = ServiceData::default().details_to_vec();
+        assert!(empty.iter().all(|row| row[1] == "N/A"));
+    }
+
+    #[test]
+    fn host_summary_lists_tags_and_vuln_count() {
+        let host = ShodanSearchIp {
+            tags: Some(vec![String::from("cloud"), String::from("self-signed")]),
+            vulns: Some(vec![String::from("CVE-2021-23017")]),
+            ..Default::default()
+        };
+
+        let summary = host.summary_to_vec();
+
+        assert!(summary.contains(&vec![
+            String::from("Tags"),
+            String::from("cloud, self-signed")
+        ]));
+        assert!(summary.contains(&vec![String::from("Vulnerabilities"), String::from("1")]));
+    }
+}
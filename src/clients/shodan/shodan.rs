@@ -1,5 +1,8 @@
-use crate::clients::{base, shodan::ShodanSearchIp};
-use reqwest;
+use crate::clients::{
+    base,
+    shodan::{ShodanHostSearch, ShodanSearchIp},
+};
+use reqwest::{self, Url};
 
 const BASE_URL: &str = "https://api.shodan.io";
 
@@ -19,4 +22,39 @@ impl Client {
 
         res
     }
+
+    pub async fn search_hosts(
+        &self,
+        query: &str,
+        page: u32,
+    ) -> Result<ShodanHostSearch, reqwest::StatusCode> {
+        let page = page.to_string();
+        let url = Url::parse_with_params(
+            &format!("{}/shodan/host/search", BASE_URL),
+            &[
+                ("key", self.api_key.as_str()),
+                ("query", query),
+                ("facets", "port,country,org"),
+                ("page", page.as_str()),
+            ],
+        )
+        .map_err(|_| reqwest::StatusCode::BAD_REQUEST)?;
+        let res: Result<ShodanHostSearch, reqwest::StatusCode> =
+            base::get(url.to_string(), None, None).await;
+
+        res
+    }
+
+    /// Number of hosts matching `query`, which unlike a search costs no query credits
+    pub async fn count_hosts(&self, query: &str) -> Result<ShodanHostSearch, reqwest::StatusCode> {
+        let url = Url::parse_with_params(
+            &format!("{}/shodan/host/count", BASE_URL),
+            &[("key", self.api_key.as_str()), ("query", query)],
+        )
+        .map_err(|_| reqwest::StatusCode::BAD_REQUEST)?;
+        let res: Result<ShodanHostSearch, reqwest::StatusCode> =
+            base::get(url.to_string(), None, None).await;
+
+        res
+    }
 }
@@ -0,0 +1,43 @@
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    task::JoinHandle,
+};
+
+/// Serves a single `200 OK` response with `body` and hands back the request line it received
+pub async fn serve_once(
+    content_type: &'static str,
+    body: &'static str,
+) -> (String, JoinHandle<String>) {
+    serve_status_once("200 OK", content_type, body).await
+}
+
+/// Same as `serve_once`, answering with `status`, e.g. `404 Not Found`
+pub async fn serve_status_once(
+    status: &'static str,
+    content_type: &'static str,
+    body: &'static str,
+) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buffer = vec![0; 4096];
+        let read = socket.read(&mut buffer).await.unwrap();
+        let request = String::from_utf8_lossy(&buffer[..read]).to_string();
+
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            content_type,
+            body.len(),
+            body
+        );
+        socket.write_all(response.as_bytes()).await.unwrap();
+
+        request.lines().next().unwrap_or_default().to_owned()
+    });
+
+    (base_url, handle)
+}
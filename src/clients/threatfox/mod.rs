@@ -0,0 +1,6 @@
+#![allow(clippy::module_inception)]
+mod models;
+mod threatfox;
+
+pub use models::*;
+pub use threatfox::*;
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThreatFoxSearch {
+    pub query_status: String,
+    // A message replaces the list when nothing matched
+    #[serde(deserialize_with = "list_or_message")]
+    pub data: Vec<ThreatFoxIoc>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThreatFoxIoc {
+    pub id: String,
+    pub ioc: String,
+    pub threat_type: String,
+    pub ioc_type: String,
+    pub malware_printable: String,
+    pub confidence_level: u8,
+    pub first_seen: Option<String>,
+    pub last_seen: Option<String>,
+    pub reporter: String,
+    pub tags: Option<Vec<String>>,
+    pub malware_samples: Option<Vec<MalwareSample>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MalwareSample {
+    pub time_stamp: String,
+    pub md5_hash: String,
+    pub sha256_hash: String,
+}
+
+fn list_or_message<'de, D>(deserializer: D) -> Result<Vec<ThreatFoxIoc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    Ok(serde_json::from_value(value).unwrap_or_default())
+}
+
+impl ThreatFoxSearch {
+    /// The most confident report, which is what the verdict is based on
+    pub fn top_ioc(&self) -> Option<&ThreatFoxIoc> {
+        self.data.iter().max_by_key(|ioc| ioc.confidence_level)
+    }
+}
+
+impl ThreatFoxIoc {
+    pub fn summary_to_vec(&self) -> Vec<String> {
+        vec![
+            self.first_seen
+                .as_deref()
+                .unwrap_or_default()
+                .chars()
+                .take(10)
+                .collect(),
+            self.threat_type.to_owned(),
+            self.malware_printable.to_owned(),
+            format!("{}%", self.confidence_level),
+            self.reporter.to_owned(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_no_result() {
+        let search: ThreatFoxSearch = serde_json::from_str(
+            r#"{"query_status": "no_result", "data": "Your search did not yield any results"}"#,
+        )
+        .unwrap();
+
+        assert!(search.data.is_empty());
+        assert_eq!(search.top_ioc(), None);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::clients::{base, threatfox::ThreatFoxSearch};
+use crate::indicator::Indicator;
+use reqwest;
+use serde_json::json;
+
+const BASE_URL: &str = "https://threatfox-api.abuse.ch/api/v1/";
+
+#[derive(Clone)]
+pub struct Client {
+    auth_key: String,
+}
+
+impl Client {
+    /// abuse.ch refuses lookups that don't carry an Auth-Key
+    pub fn new(auth_key: String) -> Client {
+        Client { auth_key }
+    }
+
+    pub async fn search(
+        &self,
+        indicator: &Indicator,
+    ) -> Result<ThreatFoxSearch, reqwest::StatusCode> {
+        let body = match indicator {
+            Indicator::Hash(hash) => json!({ "query": "search_hash", "hash": hash }),
+            indicator => json!({ "query": "search_ioc", "search_term": indicator.to_string() }),
+        };
+
+        let header = match self.auth_key.is_empty() {
+            true => None,
+            false => Some(("Auth-Key", self.auth_key.as_str())),
+        };
+
+        let res: Result<ThreatFoxSearch, reqwest::StatusCode> =
+            base::post_json(BASE_URL.to_string(), header, &body).await;
+
+        res
+    }
+}
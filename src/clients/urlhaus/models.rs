@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+
+/// Response of the host, URL and payload endpoints, each one only fills in its own fields
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UrlhausLookup {
+    pub query_status: String,
+    pub urlhaus_reference: String,
+    pub host: Option<String>,
+    pub url: Option<String>,
+    pub url_status: Option<String>,
+    pub threat: Option<String>,
+    pub reporter: Option<String>,
+    pub tags: Option<Vec<String>>,
+    #[serde(alias = "date_added")]
+    pub firstseen: Option<String>,
+    pub lastseen: Option<String>,
+    pub url_count: Option<String>,
+    pub signature: Option<String>,
+    pub file_type: Option<String>,
+    pub urls: Vec<UrlhausUrl>,
+    pub payloads: Vec<UrlhausPayload>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UrlhausUrl {
+    pub url: String,
+    pub url_status: Option<String>,
+    #[serde(alias = "firstseen")]
+    pub date_added: Option<String>,
+    pub threat: Option<String>,
+    pub reporter: Option<String>,
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UrlhausPayload {
+    pub firstseen: Option<String>,
+    pub filename: Option<String>,
+    pub file_type: Option<String>,
+    pub response_sha256: Option<String>,
+    pub signature: Option<String>,
+}
+
+impl UrlhausLookup {
+    pub fn is_listed(&self) -> bool {
+        self.query_status == "ok"
+    }
+
+    /// Listed URLs still serving malware, for a URL lookup that is the URL itself
+    pub fn online_urls(&self) -> usize {
+        match &self.url_status {
+            Some(status) => usize::from(status == "online"),
+            None => self
+                .urls
+                .iter()
+                .filter(|url| url.url_status.as_deref() == Some("online"))
+                .count(),
+        }
+    }
+
+    pub fn summary_to_vec(&self) -> Vec<Vec<String>> {
+        let or_na = |value: &Option<String>| value.clone().unwrap_or_else(|| String::from("N/A"));
+        let tags = self
+            .tags
+            .clone()
+            .or_else(|| self.urls.first().and_then(|url| url.tags.clone()))
+            .unwrap_or_default();
+        let threat = self
+            .threat
+            .clone()
+            .or_else(|| self.urls.first().and_then(|url| url.threat.clone()));
+
+        vec![
+            vec![String::from("Status"), self.query_status.to_owned()],
+            vec![String::from("Threat"), or_na(&threat)],
+            vec![String::from("Malware"), or_na(&self.signature)],
+            vec![String::from("First Seen"), or_na(&self.firstseen)],
+            vec![String::from("Reporter"), or_na(&self.reporter)],
+            vec![
+                String::from("URLs"),
+                format!("{} ({} online)", self.urls.len(), self.online_urls()),
+            ],
+            vec![
+                String::from("Tags"),
+                match tags.is_empty() {
+                    true => String::from("N/A"),
+                    false => tags.join(", "),
+                },
+            ],
+        ]
+    }
+}
+
+impl UrlhausUrl {
+    pub fn summary_to_vec(&self) -> Vec<String> {
+        vec![
+            self.date_added
+                .as_deref()
+                .unwrap_or_default()
+                .chars()
+                .take(10)
+                .collect(),
+            self.url_status.clone().unwrap_or_default(),
+            self.threat.clone().unwrap_or_default(),
+            self.url.to_owned(),
+        ]
+    }
+}
@@ -0,0 +1,65 @@
+use crate::clients::{base, urlhaus::UrlhausLookup};
+use crate::indicator::Indicator;
+use reqwest;
+
+const BASE_URL: &str = "https://urlhaus-api.abuse.ch/v1";
+
+#[derive(Clone)]
+pub struct Client {
+    auth_key: String,
+}
+
+impl Client {
+    /// abuse.ch refuses lookups that don't carry an Auth-Key
+    pub fn new(auth_key: String) -> Client {
+        Client { auth_key }
+    }
+
+    pub async fn lookup(
+        &self,
+        indicator: &Indicator,
+    ) -> Result<UrlhausLookup, reqwest::StatusCode> {
+        let value = indicator.to_string();
+        let (endpoint, field) = match indicator {
+            Indicator::Ip(_) | Indicator::Domain(_) => ("host", "host"),
+            Indicator::Url(_) => ("url", "url"),
+            // Payloads are only indexed by MD5 and SHA-256, SHA-1 hashes can't be listed
+            Indicator::Hash(hash) if hash.len() == 32 => ("payload", "md5_hash"),
+            Indicator::Hash(hash) if hash.len() == 64 => ("payload", "sha256_hash"),
+            Indicator::Hash(_) | Indicator::Email(_) | Indicator::Asn(_) | Indicator::Cidr(_) => {
+                return Err(reqwest::StatusCode::NOT_FOUND)
+            }
+        };
+
+        let header = match self.auth_key.is_empty() {
+            true => None,
+            false => Some(("Auth-Key", self.auth_key.as_str())),
+        };
+
+        let res: Result<UrlhausLookup, reqwest::StatusCode> = base::post_form(
+            format!("{}/{}/", BASE_URL, endpoint),
+            header,
+            &[(field, value.as_str())],
+        )
+        .await;
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sha1_hashes_are_not_looked_up() {
+        let client = Client::new(String::from("auth_key"));
+        let sha1 = Indicator::parse("da39a3ee5e6b4b0d3255bfef95601890afd80709").unwrap();
+
+        // Sent as a SHA-256 it would only ever come back as not listed
+        assert_eq!(
+            client.lookup(&sha1).await.unwrap_err(),
+            reqwest::StatusCode::NOT_FOUND
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UrlscanSearch {
+    pub total: u64,
+    pub results: Vec<UrlscanScan>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UrlscanScan {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub task: ScanTask,
+    pub page: ScanPage,
+    pub screenshot: Option<String>,
+    pub verdicts: Option<ScanVerdict>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScanTask {
+    pub uuid: String,
+    pub time: String,
+    pub url: String,
+    pub visibility: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScanPage {
+    pub url: String,
+    pub domain: String,
+    pub ip: Option<String>,
+    pub asn: Option<String>,
+    pub asnname: Option<String>,
+    pub country: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScanVerdict {
+    pub score: i64,
+    pub malicious: bool,
+    pub categories: Vec<String>,
+    pub brands: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UrlscanResult {
+    pub task: ScanTask,
+    pub page: ScanPage,
+    pub lists: ResultLists,
+    pub data: ResultData,
+    pub verdicts: ResultVerdicts,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResultLists {
+    pub ips: Vec<String>,
+    pub domains: Vec<String>,
+    pub certificates: Vec<ResultCertificate>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ResultCertificate {
+    pub subject_name: String,
+    pub issuer: String,
+    // Seconds since the epoch
+    pub valid_from: i64,
+    pub valid_to: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResultData {
+    pub console: Vec<ConsoleEntry>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConsoleEntry {
+    pub message: ConsoleMessage,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConsoleMessage {
+    pub level: String,
+    pub text: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResultVerdicts {
+    pub overall: ScanVerdict,
+    pub urlscan: ScanVerdict,
+    pub community: ScanVerdict,
+    pub engines: ScanVerdict,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UrlscanSubmission {
+    pub uuid: String,
+    pub url: String,
+    pub visibility: String,
+    pub message: String,
+}
+
+impl ScanVerdict {
+    pub fn label(&self) -> String {
+        match self.malicious {
+            true => format!("Malicious ({})", self.score),
+            false => format!("Clean ({})", self.score),
+        }
+    }
+}
+
+impl UrlscanScan {
+    pub fn summary_to_vec(&self) -> Vec<String> {
+        let asn = match (&self.page.asn, &self.page.asnname) {
+            (Some(asn), Some(name)) => format!("{} {}", asn, name),
+            (Some(asn), None) => asn.to_owned(),
+            _ => String::from("N/A"),
+        };
+
+        vec![
+            self.task.time.replace('T', " ").chars().take(16).collect(),
+            self.task.url.to_owned(),
+            self.verdicts
+                .as_ref()
+                .map_or(String::from("N/A"), ScanVerdict::label),
+            match self.screenshot.is_some() {
+                true => String::from("Yes"),
+                false => String::from("No"),
+            },
+            asn,
+        ]
+    }
+}
+
+impl UrlscanResult {
+    pub fn verdicts_to_vec(&self) -> Vec<Vec<String>> {
+        let verdict = |name: &str, verdict: &ScanVerdict| {
+            let mut labels = verdict.categories.clone();
+            labels.extend(verdict.brands.clone());
+            vec![name.to_owned(), verdict.label(), labels.join(", ")]
+        };
+
+        vec![
+            verdict("Overall", &self.verdicts.overall),
+            verdict("urlscan", &self.verdicts.urlscan),
+            verdict("Engines", &self.verdicts.engines),
+            verdict("Community", &self.verdicts.community),
+        ]
+    }
+}
+
+impl ResultCertificate {
+    pub fn summary_to_vec(&self) -> Vec<String> {
+        let date = |seconds: i64| {
+            chrono::DateTime::from_timestamp(seconds, 0).map_or(String::from("N/A"), |date| {
+                date.format("%Y-%m-%d").to_string()
+            })
+        };
+
+        vec![
+            self.subject_name.to_owned(),
+            self.issuer.to_owned(),
+            date(self.valid_from),
+            date(self.valid_to),
+        ]
+    }
+}
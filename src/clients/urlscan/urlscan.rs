@@ -0,0 +1,128 @@
+use crate::clients::{
+    base,
+    urlscan::{UrlscanResult, UrlscanSearch, UrlscanSubmission},
+};
+use crate::indicator::Indicator;
+use reqwest::{self, Url};
+use serde_json::json;
+
+const BASE_URL: &str = "https://urlscan.io/api/v1";
+
+#[derive(Clone)]
+pub struct Client {
+    api_key: String,
+    base_url: String,
+}
+
+impl Client {
+    pub fn new(api_key: String) -> Client {
+        Client::with_base_url(api_key, BASE_URL.to_string())
+    }
+
+    pub fn with_base_url(api_key: String, base_url: String) -> Client {
+        Client { api_key, base_url }
+    }
+
+    /// Existing scans of `indicator`, newest first
+    pub async fn search(
+        &self,
+        indicator: &Indicator,
+    ) -> Result<UrlscanSearch, reqwest::StatusCode> {
+        let query = match indicator {
+            Indicator::Ip(ip) => format!("ip:\"{}\"", ip),
+            Indicator::Domain(domain) => format!("domain:{}", domain),
+            Indicator::Url(url) => format!("page.url:\"{}\"", url.replace('"', "\\\"")),
+            Indicator::Hash(hash) => format!("hash:{}", hash),
+            Indicator::Email(_) => return Err(reqwest::StatusCode::NOT_FOUND),
+            Indicator::Asn(asn) => format!("asn:AS{}", asn),
+            Indicator::Cidr(network) => format!("ip:\"{}\"", network),
+        };
+
+        let url = Url::parse_with_params(
+            &format!("{}/search/", self.base_url),
+            &[("q", query.as_str()), ("size", "100")],
+        )
+        .map_err(|_| reqwest::StatusCode::BAD_REQUEST)?;
+
+        let res: Result<UrlscanSearch, reqwest::StatusCode> =
+            base::get(url.to_string(), Some(("API-Key", &self.api_key)), None).await;
+
+        res
+    }
+
+    /// Full result of a scan, urlscan.io answers 404 until the scan has finished
+    pub async fn result(&self, uuid: &str) -> Result<UrlscanResult, reqwest::StatusCode> {
+        let url = format!("{}/result/{}/", self.base_url, uuid);
+        let res: Result<UrlscanResult, reqwest::StatusCode> =
+            base::get(url, Some(("API-Key", &self.api_key)), None).await;
+
+        res
+    }
+
+    pub async fn submit(
+        &self,
+        url: &str,
+        visibility: &str,
+    ) -> Result<UrlscanSubmission, reqwest::StatusCode> {
+        let body = json!({ "url": url, "visibility": visibility });
+        let res: Result<UrlscanSubmission, reqwest::StatusCode> = base::post_json(
+            format!("{}/scan/", self.base_url),
+            Some(("API-Key", &self.api_key)),
+            &body,
+        )
+        .await;
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::test_server::{serve_once, serve_status_once};
+
+    #[tokio::test]
+    async fn test_submit() {
+        let (base_url, request) = serve_once(
+            "application/json",
+            r#"{
+                "message": "Submission successful",
+                "uuid": "0e37e828-a9d9-45c0-ac50-1ca579b86c72",
+                "result": "https://urlscan.io/result/0e37e828-a9d9-45c0-ac50-1ca579b86c72/",
+                "api": "https://urlscan.io/api/v1/result/0e37e828-a9d9-45c0-ac50-1ca579b86c72/",
+                "visibility": "private",
+                "url": "https://evil.example/"
+            }"#,
+        )
+        .await;
+        let client = Client::with_base_url(String::from("key"), base_url);
+
+        let submission = client
+            .submit("https://evil.example/", "private")
+            .await
+            .unwrap();
+
+        assert_eq!(request.await.unwrap(), "POST /scan/ HTTP/1.1");
+        assert_eq!(submission.uuid, "0e37e828-a9d9-45c0-ac50-1ca579b86c72");
+        assert_eq!(submission.visibility, "private");
+    }
+
+    #[tokio::test]
+    async fn test_result_of_running_scan() {
+        let (base_url, request) = serve_status_once(
+            "404 Not Found",
+            "application/json",
+            r#"{"message": "Scan is not finished yet", "status": 404}"#,
+        )
+        .await;
+        let client = Client::with_base_url(String::from("key"), base_url);
+
+        let result = client.result("0e37e828-a9d9-45c0-ac50-1ca579b86c72").await;
+
+        assert_eq!(
+            request.await.unwrap(),
+            "GET /result/0e37e828-a9d9-45c0-ac50-1ca579b86c72/ HTTP/1.1"
+        );
+        assert_eq!(result.err(), Some(reqwest::StatusCode::NOT_FOUND));
+    }
+}
@@ -1,6 +1,16 @@
-use serde::{Deserialize, Serialize};
+use crate::indicator::{self, Indicator};
+use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
 
+// VirusTotal sends explicit `null`s for some fields depending on the object type
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IpAddress {
     pub data: IpData,
@@ -12,7 +22,9 @@ pub struct IpData {
     pub id: String,
 }
 
+// Domains, files and URLs share most of these attributes, so missing ones fall back to defaults
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct IpAttributes {
     pub as_owner: String,
     pub whois: Option<String>,
@@ -22,12 +34,39 @@ pub struct IpAttributes {
     pub total_votes: Votes,
     pub last_analysis_results: HashMap<String, AnalysisResult>,
     pub last_analysis_stats: AnalysisStats,
+    pub country: String,
+    pub regional_internet_registry: String,
+    pub reputation: i64,
+    pub tags: Vec<String>,
+    pub jarm: String,
+    pub last_https_certificate: Option<HttpsCertificate>,
+    pub last_https_certificate_date: Option<i64>,
+    pub last_analysis_date: Option<i64>,
+    pub whois_date: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HttpsCertificate {
+    pub subject: HashMap<String, String>,
+    pub issuer: HashMap<String, String>,
+    pub validity: CertificateValidity,
+    pub serial_number: String,
+    pub thumbprint_sha256: String,
+    pub extensions: CertificateExtensions,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CertificateExtensions {
+    pub subject_alternative_name: Vec<String>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AnalysisResult {
     #[serde(rename = "engine_name")]
     pub engine_name: String,
+    #[serde(deserialize_with = "null_as_default")]
     pub result: String,
 }
 
@@ -49,6 +88,7 @@ pub struct AnalysisStats {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IpComments {
     pub data: Vec<IpCommentData>,
+    pub meta: Option<RelationshipMeta>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -74,15 +114,334 @@ pub struct CommentVotes {
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CommentAuthor {
-    pub data: CommentAttributes,
+    pub data: CommentAuthorData,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommentAuthorData {
+    #[serde(default)]
+    pub attributes: CommentAttributes,
     pub id: String,
 }
 
+// Profiles of other users only expose some of these, depending on their privacy settings
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct CommentAttributes {
     pub first_name: String,
     pub last_name: String,
     pub profile_phrase: String,
     pub status: String,
+    pub reputation: i64,
     pub user_since: usize,
 }
+
+impl AnalysisStats {
+    /// Worst category any engine put the indicator in
+    pub fn verdict(&self) -> &'static str {
+        match self {
+            stats if stats.malicious > 0 => "Malicious",
+            stats if stats.suspicious > 0 => "Suspicious",
+            stats if stats.harmless > 0 => "Harmless",
+            _ => "Undetected",
+        }
+    }
+}
+
+impl IpComments {
+    pub fn next_cursor(&self) -> Option<String> {
+        self.meta
+            .as_ref()
+            .and_then(|meta| meta.cursor.clone())
+            .filter(|cursor| !cursor.is_empty())
+    }
+}
+
+impl IpCommentData {
+    /// Indicators mentioned in the comment that can be pivoted to
+    pub fn indicators(&self) -> Vec<Indicator> {
+        indicator::extract(&self.attributes.text)
+    }
+}
+
+impl CommentAuthor {
+    pub fn display_name(&self) -> String {
+        let name = format!(
+            "{} {}",
+            self.data.attributes.first_name, self.data.attributes.last_name
+        );
+        match name.trim() {
+            "" => self.data.id.to_string(),
+            name => format!("{} ({})", self.data.id, name),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Relationship {
+    #[serde(default)]
+    pub data: Vec<RelatedObject>,
+    pub meta: Option<RelationshipMeta>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelationshipMeta {
+    pub cursor: Option<String>,
+    pub count: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelatedObject {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub attributes: RelatedAttributes,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RelatedAttributes {
+    pub host_name: Option<String>,
+    pub ip_address: Option<String>,
+    pub date: Option<i64>,
+    pub url: Option<String>,
+    pub meaningful_name: Option<String>,
+    pub type_description: Option<String>,
+    pub last_analysis_stats: Option<AnalysisStats>,
+    pub thumbprint_sha256: Option<String>,
+    pub subject: Option<HashMap<String, String>>,
+    pub validity: Option<CertificateValidity>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificateValidity {
+    pub not_before: Option<String>,
+    pub not_after: Option<String>,
+}
+
+impl Relationship {
+    pub fn next_cursor(&self) -> Option<String> {
+        self.meta
+            .as_ref()
+            .and_then(|meta| meta.cursor.clone())
+            .filter(|cursor| !cursor.is_empty())
+    }
+}
+
+impl RelatedObject {
+    /// The indicator to look up when pivoting from `source`, the object the relationship was
+    /// fetched for. Resolutions point both ways so pick whichever side isn't the source.
+    pub fn pivot(&self, source: &str) -> String {
+        match self.kind.as_str() {
+            "resolution" => {
+                let host_name = self.attributes.host_name.clone().unwrap_or_default();
+                let ip_address = self.attributes.ip_address.clone().unwrap_or_default();
+                if host_name == source {
+                    ip_address
+                } else {
+                    host_name
+                }
+            }
+            "url" => self
+                .attributes
+                .url
+                .clone()
+                .unwrap_or_else(|| self.id.clone()),
+            "ssl_cert" => self
+                .attributes
+                .thumbprint_sha256
+                .clone()
+                .unwrap_or_else(|| self.id.clone()),
+            _ => self.id.clone(),
+        }
+    }
+
+    pub fn summary_to_vec(&self, source: &str) -> Vec<String> {
+        let detections = |stats: &Option<AnalysisStats>| {
+            stats.as_ref().map_or(String::from("N/A"), |stats| {
+                format!(
+                    "{}/{}",
+                    stats.malicious + stats.suspicious,
+                    stats.harmless + stats.malicious + stats.suspicious + stats.undetected
+                )
+            })
+        };
+
+        let detail = match self.kind.as_str() {
+            "resolution" => self
+                .attributes
+                .date
+                .and_then(|date| chrono::DateTime::from_timestamp(date, 0))
+                .map_or(String::from("N/A"), |date| {
+                    date.format("%Y-%m-%d").to_string()
+                }),
+            "file" => self
+                .attributes
+                .meaningful_name
+                .clone()
+                .or_else(|| self.attributes.type_description.clone())
+                .unwrap_or_else(|| String::from("N/A")),
+            "ssl_cert" => self
+                .attributes
+                .subject
+                .as_ref()
+                .and_then(|subject| subject.get("CN").cloned())
+                .unwrap_or_else(|| String::from("N/A")),
+            _ => String::new(),
+        };
+
+        let extra = match self.kind.as_str() {
+            "ssl_cert" => self
+                .attributes
+                .validity
+                .as_ref()
+                .and_then(|validity| validity.not_after.clone())
+                .unwrap_or_else(|| String::from("N/A")),
+            "resolution" => String::new(),
+            _ => detections(&self.attributes.last_analysis_stats),
+        };
+
+        vec![self.pivot(source), detail, extra]
+    }
+}
+
+// Convert from Epoch time to 1900-01-01 00:00:00 format
+fn format_timestamp(timestamp: Option<i64>) -> String {
+    timestamp
+        .and_then(|timestamp| chrono::DateTime::from_timestamp(timestamp, 0))
+        .map_or(String::from("N/A"), |date| {
+            date.format("%Y-%m-%d %H:%M:%S").to_string()
+        })
+}
+
+fn or_na(value: &str) -> String {
+    match value {
+        "" => String::from("N/A"),
+        value => value.to_string(),
+    }
+}
+
+impl IpAttributes {
+    pub fn details_to_vec(&self) -> Vec<Vec<String>> {
+        vec![
+            vec![String::from("Network"), or_na(&self.network)],
+            vec![String::from("ASN"), self.asn.to_string()],
+            vec![String::from("AS Owner"), or_na(&self.as_owner)],
+            vec![String::from("Country"), or_na(&self.country)],
+            vec![String::from("Continent"), or_na(&self.continent)],
+            vec![
+                String::from("Registry"),
+                or_na(&self.regional_internet_registry),
+            ],
+            vec![String::from("Reputation"), self.reputation.to_string()],
+            vec![String::from("Tags"), or_na(&self.tags.join(", "))],
+            vec![String::from("JARM"), or_na(&self.jarm)],
+            vec![
+                String::from("Last Analysis"),
+                format_timestamp(self.last_analysis_date),
+            ],
+            vec![
+                String::from("Whois Date"),
+                format_timestamp(self.whois_date),
+            ],
+        ]
+    }
+}
+
+impl HttpsCertificate {
+    pub fn summary_to_vec(&self, retrieved: Option<i64>) -> Vec<Vec<String>> {
+        let name = |name: &HashMap<String, String>| {
+            name.get("CN")
+                .or_else(|| name.get("O"))
+                .cloned()
+                .unwrap_or_else(|| String::from("N/A"))
+        };
+
+        vec![
+            vec![String::from("Subject"), name(&self.subject)],
+            vec![String::from("Issuer"), name(&self.issuer)],
+            vec![
+                String::from("Not Before"),
+                or_na(self.validity.not_before.as_deref().unwrap_or_default()),
+            ],
+            vec![
+                String::from("Not After"),
+                or_na(self.validity.not_after.as_deref().unwrap_or_default()),
+            ],
+            vec![String::from("Serial"), or_na(&self.serial_number)],
+            vec![String::from("SHA-256"), or_na(&self.thumbprint_sha256)],
+            vec![
+                String::from("SANs"),
+                or_na(&self.extensions.subject_alternative_name.join(", ")),
+            ],
+            vec![String::from("Retrieved"), format_timestamp(retrieved)],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comment_author_display_name() {
+        let author: CommentAuthor = serde_json::from_str(
+            r#"{"data": {"id": "analyst", "attributes": {"first_name": "Ada", "last_name": "Lovelace", "reputation": 42}}}"#,
+        )
+        .unwrap();
+        assert_eq!(author.display_name(), "analyst (Ada Lovelace)");
+        assert_eq!(author.data.attributes.reputation, 42);
+
+        // Private profiles come back without attributes, only the id is shown
+        let private: CommentAuthor =
+            serde_json::from_str(r#"{"data": {"id": "someone"}}"#).unwrap();
+        assert_eq!(private.display_name(), "someone");
+    }
+
+    #[test]
+    fn ip_details_with_certificate() {
+        let ip: IpAddress = serde_json::from_str(
+            r#"{"data": {"id": "203.0.113.7", "attributes": {
+                "network": "203.0.113.0/24",
+                "asn": 64500,
+                "as_owner": "Example Hosting",
+                "country": "NL",
+                "tags": ["suspicious-udp"],
+                "reputation": -12,
+                "whois_date": 1700000000,
+                "last_https_certificate_date": 1700000000,
+                "last_https_certificate": {
+                    "subject": {"CN": "login.example.com"},
+                    "issuer": {"O": "Let's Encrypt"},
+                    "validity": {"not_before": "2023-11-01 00:00:00", "not_after": "2024-01-30 00:00:00"},
+                    "thumbprint_sha256": "ab12",
+                    "extensions": {"subject_alternative_name": ["login.example.com", "www.example.com"]}
+                }
+            }}}"#,
+        )
+        .unwrap();
+        let attributes = &ip.data.attributes;
+
+        let details = attributes.details_to_vec();
+        assert!(details.contains(&vec![String::from("ASN"), String::from("64500")]));
+        assert!(details.contains(&vec![String::from("Reputation"), String::from("-12")]));
+        assert!(details.contains(&vec![
+            String::from("Whois Date"),
+            String::from("2023-11-14 22:13:20")
+        ]));
+        // Fields missing from the response are shown as N/A
+        assert!(details.contains(&vec![String::from("JARM"), String::from("N/A")]));
+
+        let certificate = attributes.last_https_certificate.as_ref().unwrap();
+        let summary = certificate.summary_to_vec(attributes.last_https_certificate_date);
+        assert_eq!(summary[0], vec!["Subject", "login.example.com"]);
+        // Issuers without a common name fall back to their organization
+        assert_eq!(summary[1], vec!["Issuer", "Let's Encrypt"]);
+        assert_eq!(
+            summary[6],
+            vec!["SANs", "login.example.com, www.example.com"]
+        );
+        assert_eq!(summary[4], vec!["Serial", "N/A"]);
+    }
+}
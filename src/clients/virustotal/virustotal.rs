@@ -1,9 +1,45 @@
 use crate::clients::base;
-use crate::clients::virustotal::{CommentAuthor, IpAddress, IpComments};
-use reqwest;
+use crate::clients::virustotal::{CommentAuthor, IpAddress, IpComments, Relationship};
+use crate::indicator::Indicator;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use reqwest::{self, Url};
 
 const BASE_URL: &str = "https://www.virustotal.com/api/v3";
 
+// Relationships shown in the Relations tab, every collection supports a different set of them
+const IP_RELATIONSHIPS: [&str; 6] = [
+    "resolutions",
+    "communicating_files",
+    "downloaded_files",
+    "urls",
+    "referrer_files",
+    "historical_ssl_certificates",
+];
+const DOMAIN_RELATIONSHIPS: [&str; 7] = [
+    "resolutions",
+    "subdomains",
+    "communicating_files",
+    "downloaded_files",
+    "urls",
+    "referrer_files",
+    "historical_ssl_certificates",
+];
+const FILE_RELATIONSHIPS: [&str; 6] = [
+    "contacted_ips",
+    "contacted_domains",
+    "contacted_urls",
+    "itw_urls",
+    "execution_parents",
+    "dropped_files",
+];
+const URL_RELATIONSHIPS: [&str; 5] = [
+    "contacted_ips",
+    "contacted_domains",
+    "downloaded_files",
+    "communicating_files",
+    "redirects_to",
+];
+
 /// VirusTotal API object
 #[derive(Debug, Clone)]
 pub struct Client {
@@ -15,8 +51,8 @@ impl Client {
         Client { api_key }
     }
 
-    pub async fn get_ip_whois(&self, ip: &str) -> Result<IpAddress, reqwest::StatusCode> {
-        let url = format!("{}/ip_addresses/{}", BASE_URL, ip);
+    pub async fn get_ip_whois(&self, indicator: &str) -> Result<IpAddress, reqwest::StatusCode> {
+        let url = format!("{}/{}", BASE_URL, object_path(indicator));
 
         let res: Result<IpAddress, reqwest::StatusCode> =
             base::get(url, Some(("x-apikey", &self.api_key)), None).await;
@@ -24,11 +60,45 @@ impl Client {
         res
     }
 
-    pub async fn get_ip_comments(&self, ip: &str) -> Result<IpComments, reqwest::StatusCode> {
-        let url = format!("{}/ip_addresses/{}/comments", BASE_URL, ip);
+    pub async fn get_ip_comments(
+        &self,
+        indicator: &str,
+        cursor: Option<&str>,
+    ) -> Result<IpComments, reqwest::StatusCode> {
+        let mut params = vec![("limit", "20")];
+        if let Some(cursor) = cursor {
+            params.push(("cursor", cursor));
+        }
 
+        let url = Url::parse_with_params(
+            &format!("{}/{}/comments", BASE_URL, object_path(indicator)),
+            &params,
+        )
+        .map_err(|_| reqwest::StatusCode::BAD_REQUEST)?;
         let res: Result<IpComments, reqwest::StatusCode> =
-            base::get(url, Some(("x-apikey", &self.api_key)), None).await;
+            base::get(url.to_string(), Some(("x-apikey", &self.api_key)), None).await;
+
+        res
+    }
+
+    pub async fn get_relationship(
+        &self,
+        indicator: &str,
+        relationship: &str,
+        cursor: Option<&str>,
+    ) -> Result<Relationship, reqwest::StatusCode> {
+        let mut params = vec![("limit", "40")];
+        if let Some(cursor) = cursor {
+            params.push(("cursor", cursor));
+        }
+
+        let url = Url::parse_with_params(
+            &format!("{}/{}/{}", BASE_URL, object_path(indicator), relationship),
+            &params,
+        )
+        .map_err(|_| reqwest::StatusCode::BAD_REQUEST)?;
+        let res: Result<Relationship, reqwest::StatusCode> =
+            base::get(url.to_string(), Some(("x-apikey", &self.api_key)), None).await;
 
         res
     }
@@ -45,3 +115,41 @@ impl Client {
         res
     }
 }
+
+// VirusTotal keeps each indicator type in its own collection, URLs are identified by their
+// unpadded base64 encoding
+fn object_path(indicator: &str) -> String {
+    match Indicator::parse(indicator) {
+        Some(Indicator::Domain(domain)) => format!("domains/{}", domain),
+        Some(Indicator::Hash(hash)) => format!("files/{}", hash),
+        Some(Indicator::Url(url)) => format!("urls/{}", URL_SAFE_NO_PAD.encode(url)),
+        _ => format!("ip_addresses/{}", indicator),
+    }
+}
+
+/// Relationships VirusTotal accepts for the collection `indicator` belongs to
+pub fn relationships(indicator: &str) -> &'static [&'static str] {
+    match Indicator::parse(indicator) {
+        Some(Indicator::Domain(_)) => &DOMAIN_RELATIONSHIPS,
+        Some(Indicator::Hash(_)) => &FILE_RELATIONSHIPS,
+        Some(Indicator::Url(_)) => &URL_RELATIONSHIPS,
+        _ => &IP_RELATIONSHIPS,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relationships_follow_the_collection() {
+        assert_eq!(object_path("8.8.8.8"), "ip_addresses/8.8.8.8");
+        assert!(relationships("8.8.8.8").contains(&"resolutions"));
+        assert!(relationships("example.com").contains(&"subdomains"));
+
+        let hash = "44d88612fea8a8f36de82e1278abb02f";
+        assert_eq!(object_path(hash), format!("files/{}", hash));
+        assert!(!relationships(hash).contains(&"resolutions"));
+        assert!(!relationships("https://example.com/a").contains(&"resolutions"));
+    }
+}
@@ -1,13 +1,34 @@
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
-use std::{fs, path::PathBuf};
+use std::{
+    fs,
+    net::{IpAddr, SocketAddr},
+    path::PathBuf,
+};
 
 const CONFIG_FILE: &str = "config.toml";
 const APP_CONFIG_DIR: &str = "osintui";
+const LISTS_DIR: &str = "lists";
+const WATCHLISTS_DIR: &str = "watchlists";
+const EXPORTS_DIR: &str = "exports";
 
 #[derive(Deserialize, Serialize, Clone)]
 pub struct Config {
     pub keys: Keys,
+    #[serde(default)]
+    pub scoring: ScoringWeights,
+    #[serde(default)]
+    pub dns: DnsConfig,
+    #[serde(default)]
+    pub geoip: GeoIpConfig,
+    #[serde(default)]
+    pub greynoise: GreyNoiseConfig,
+    #[serde(default)]
+    pub securitytrails: SecurityTrailsConfig,
+    #[serde(default)]
+    pub urlscan: UrlscanConfig,
+    #[serde(default)]
+    pub lists: ListsConfig,
 }
 
 #[derive(Deserialize, Serialize, Clone)]
@@ -16,10 +37,146 @@ pub struct Keys {
     pub shodan: String,
     pub censys_id: String,
     pub censys_secret: String,
+    #[serde(default)]
+    pub abuseipdb: String,
+    #[serde(default)]
+    pub greynoise: String,
+    #[serde(default)]
+    pub otx: String,
+    #[serde(default)]
+    pub abusech: String,
+    #[serde(default)]
+    pub securitytrails: String,
+    #[serde(default)]
+    pub urlscan: String,
+    #[serde(default)]
+    pub ipinfo: String,
+}
+
+/// Maximum points each signal adds to the risk score, which is capped at 100
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct ScoringWeights {
+    pub vt_malicious: f64,
+    pub vt_suspicious: f64,
+    pub vt_reputation: f64,
+    pub shodan_vulns: f64,
+    pub shodan_tags: f64,
+    pub censys_labels: f64,
+    pub urlhaus: f64,
+    pub threatfox: f64,
+}
+
+impl Default for ScoringWeights {
+    fn default() -> Self {
+        ScoringWeights {
+            vt_malicious: 40.0,
+            vt_suspicious: 10.0,
+            vt_reputation: 15.0,
+            shodan_vulns: 20.0,
+            shodan_tags: 10.0,
+            censys_labels: 5.0,
+            urlhaus: 30.0,
+            threatfox: 40.0,
+        }
+    }
+}
+
+/// Upstream resolvers as `ip` or `ip:port`, queried in order
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct DnsConfig {
+    pub servers: Vec<String>,
+}
+
+impl Default for DnsConfig {
+    fn default() -> Self {
+        DnsConfig {
+            servers: vec!["1.1.1.1:53".to_string(), "8.8.8.8:53".to_string()],
+        }
+    }
+}
+
+impl DnsConfig {
+    pub fn socket_addrs(&self) -> Vec<SocketAddr> {
+        self.servers
+            .iter()
+            .filter_map(|server| {
+                server
+                    .parse::<SocketAddr>()
+                    .or_else(|_| server.parse::<IpAddr>().map(|ip| SocketAddr::new(ip, 53)))
+                    .ok()
+            })
+            .collect()
+    }
+}
+
+/// Paths to MaxMind GeoLite2/GeoIP2 `.mmdb` files, left empty to skip a database
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct GeoIpConfig {
+    pub city_database: String,
+    pub asn_database: String,
+}
+
+impl GeoIpConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.city_database.is_empty() || !self.asn_database.is_empty()
+    }
+}
+
+/// The community API is used unless the key belongs to an enterprise account
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct GreyNoiseConfig {
+    pub enterprise: bool,
+}
+
+/// Historical DNS API, self-hosted or alternative services only need the same endpoints
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct SecurityTrailsConfig {
+    pub base_url: String,
+}
+
+impl Default for SecurityTrailsConfig {
+    fn default() -> Self {
+        SecurityTrailsConfig {
+            base_url: String::from("https://api.securitytrails.com/v1"),
+        }
+    }
+}
+
+/// Visibility of scans submitted to urlscan.io, `public`, `unlisted` or `private`
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct UrlscanConfig {
+    pub visibility: String,
+}
+
+impl Default for UrlscanConfig {
+    fn default() -> Self {
+        UrlscanConfig {
+            visibility: String::from("private"),
+        }
+    }
+}
+
+/// Where to download the lists Microsoft only publishes at a changing address
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct ListsConfig {
+    pub azure_url: String,
 }
 
 pub struct ConfigPaths {
     pub config_file_path: PathBuf,
+    // Tor, cloud provider and user supplied range lists
+    pub lists_dir: PathBuf,
+    // User maintained watchlists of our own and known bad infrastructure
+    pub watchlists_dir: PathBuf,
+    // JSON reports of searched indicators
+    pub exports_dir: PathBuf,
 }
 
 impl Default for Config {
@@ -36,7 +193,21 @@ impl Config {
                 shodan: "".to_string(),
                 censys_id: "".to_string(),
                 censys_secret: "".to_string(),
+                abuseipdb: "".to_string(),
+                greynoise: "".to_string(),
+                otx: "".to_string(),
+                abusech: "".to_string(),
+                securitytrails: "".to_string(),
+                urlscan: "".to_string(),
+                ipinfo: "".to_string(),
             },
+            scoring: ScoringWeights::default(),
+            dns: DnsConfig::default(),
+            geoip: GeoIpConfig::default(),
+            greynoise: GreyNoiseConfig::default(),
+            securitytrails: SecurityTrailsConfig::default(),
+            urlscan: UrlscanConfig::default(),
+            lists: ListsConfig::default(),
         }
     }
 
@@ -50,8 +221,16 @@ impl Config {
                 }
 
                 let config_file_path = app_config_dir.join(CONFIG_FILE);
+                let lists_dir = app_config_dir.join(LISTS_DIR);
+                let watchlists_dir = app_config_dir.join(WATCHLISTS_DIR);
+                let exports_dir = app_config_dir.join(EXPORTS_DIR);
 
-                let paths = ConfigPaths { config_file_path };
+                let paths = ConfigPaths {
+                    config_file_path,
+                    lists_dir,
+                    watchlists_dir,
+                    exports_dir,
+                };
 
                 Ok(paths)
             }
@@ -71,6 +250,20 @@ impl Config {
             self.keys.virustotal = config_toml.keys.virustotal;
             self.keys.censys_id = config_toml.keys.censys_id;
             self.keys.censys_secret = config_toml.keys.censys_secret;
+            self.keys.abuseipdb = config_toml.keys.abuseipdb;
+            self.keys.greynoise = config_toml.keys.greynoise;
+            self.keys.otx = config_toml.keys.otx;
+            self.keys.abusech = config_toml.keys.abusech;
+            self.keys.securitytrails = config_toml.keys.securitytrails;
+            self.keys.urlscan = config_toml.keys.urlscan;
+            self.keys.ipinfo = config_toml.keys.ipinfo;
+            self.scoring = config_toml.scoring;
+            self.dns = config_toml.dns;
+            self.geoip = config_toml.geoip;
+            self.greynoise = config_toml.greynoise;
+            self.securitytrails = config_toml.securitytrails;
+            self.urlscan = config_toml.urlscan;
+            self.lists = config_toml.lists;
 
             Ok(())
         } else {
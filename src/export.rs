@@ -0,0 +1,155 @@
+use crate::app::{App, ResultStatus};
+use crate::clients::{
+    abuseipdb::CheckData, censys, geoip::GeoIpRecord, greynoise::GreyNoiseIp, rdap::IpNetwork,
+    shodan::ShodanSearchIp, threatfox::ThreatFoxSearch, urlhaus::UrlhausLookup,
+    virustotal::IpAttributes,
+};
+use crate::scoring::{self, Verdict};
+use crate::watchlist::WatchlistEntry;
+use anyhow::Result;
+use serde::Serialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Everything known about the indicator searched last, written out as JSON
+#[derive(Serialize)]
+pub struct Report<'a> {
+    pub indicator: &'a str,
+    pub exported_at: String,
+    pub verdict: Verdict,
+    // Watchlist entries covering the indicator
+    pub watchlist: &'a [WatchlistEntry],
+    // Tags from the local Tor, cloud provider and user lists
+    pub tags: &'a [String],
+    pub providers: Providers<'a>,
+}
+
+/// Results of the providers that know the indicator, the others are left out
+#[derive(Serialize, Default)]
+pub struct Providers<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub virustotal: Option<&'a IpAttributes>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shodan: Option<&'a ShodanSearchIp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub censys: Option<&'a censys::Result>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abuseipdb: Option<&'a CheckData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub greynoise: Option<&'a GreyNoiseIp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub urlhaus: Option<&'a UrlhausLookup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threatfox: Option<&'a ThreatFoxSearch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rdap: Option<&'a IpNetwork>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geoip: Option<&'a GeoIpRecord>,
+}
+
+fn found<T>(status: ResultStatus, result: &T) -> Option<&T> {
+    match status {
+        ResultStatus::Found => Some(result),
+        _ => None,
+    }
+}
+
+impl<'a> Report<'a> {
+    pub fn new(app: &'a App) -> Report<'a> {
+        Report {
+            indicator: &app.query,
+            exported_at: chrono::Utc::now().to_rfc3339(),
+            verdict: scoring::verdict(app),
+            watchlist: &app.watchlist_matches,
+            tags: &app.list_tags,
+            providers: Providers {
+                virustotal: found(
+                    app.virustotal.status,
+                    &app.virustotal.ip_whois_items.data.attributes,
+                ),
+                shodan: found(app.shodan.status, &app.shodan.search_ip_items),
+                censys: found(app.censys.status, &app.censys.search_ip_items.result),
+                abuseipdb: found(app.abuseipdb.status, &app.abuseipdb.check.data),
+                greynoise: found(app.greynoise.status, &app.greynoise.ip),
+                urlhaus: found(app.abusech.status, &app.abusech.urlhaus)
+                    .filter(|urlhaus| urlhaus.is_listed()),
+                threatfox: found(app.abusech.status, &app.abusech.threatfox)
+                    .filter(|threatfox| !threatfox.data.is_empty()),
+                rdap: found(app.rdap.status, &app.rdap.network),
+                geoip: found(app.geoip.status, &app.geoip.record),
+            },
+        }
+    }
+
+    /// Writes the report to `dir` as `<indicator>-<time>.json` and returns its path
+    pub fn write(&self, dir: &Path) -> Result<PathBuf> {
+        fs::create_dir_all(dir)?;
+
+        // URLs and IPv6 addresses contain characters file names can't
+        let name = self
+            .indicator
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>();
+        let path = dir.join(format!(
+            "{}-{}.json",
+            name,
+            chrono::Utc::now().format("%Y%m%dT%H%M%SZ")
+        ));
+
+        fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::watchlist::{ListKind, Severity};
+
+    #[test]
+    fn report_covers_found_providers() {
+        let mut app = App::default();
+        app.query = String::from("203.0.113.7");
+        app.virustotal.status = ResultStatus::Found;
+        app.virustotal
+            .ip_whois_items
+            .data
+            .attributes
+            .last_analysis_stats
+            .malicious = 10;
+
+        let report = serde_json::to_value(Report::new(&app)).unwrap();
+
+        assert_eq!(report["indicator"], "203.0.113.7");
+        assert_eq!(report["verdict"]["score"], scoring::verdict(&app).score);
+        assert!(report["providers"]["virustotal"].is_object());
+        assert!(report["providers"].get("shodan").is_none());
+        assert_eq!(report["watchlist"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn report_includes_watchlist_hits() {
+        let mut app = App::default();
+        app.query = String::from("203.0.113.7");
+        app.watchlist_matches = vec![WatchlistEntry {
+            value: String::from("203.0.113.0/24"),
+            label: String::from("corp VPN"),
+            kind: ListKind::Ours,
+            severity: Severity::Info,
+        }];
+
+        let report = serde_json::to_value(Report::new(&app)).unwrap();
+
+        assert_eq!(report["watchlist"][0]["label"], "corp VPN");
+        assert_eq!(report["watchlist"][0]["kind"], "ours");
+    }
+}
@@ -0,0 +1,28 @@
+use super::common_key_events;
+use crate::{app::App, event::Key, ActiveBlock};
+
+pub fn handler(key: Key, app: &mut App) {
+    let iocs = &app.abusech.threatfox.data;
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index =
+                common_key_events::on_down_press_handler(iocs, Some(app.abusech.ioc_index));
+            app.abusech.ioc_index = next_index;
+            app.abusech.payload_index = 0;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index =
+                common_key_events::on_up_press_handler(iocs, Some(app.abusech.ioc_index));
+            app.abusech.ioc_index = next_index;
+            app.abusech.payload_index = 0;
+        }
+        k if common_key_events::right_event(k) || k == Key::Enter => {
+            app.set_current_route_state(
+                Some(ActiveBlock::AbusechPayloads),
+                Some(ActiveBlock::AbusechPayloads),
+            );
+        }
+        _ => {}
+    };
+}
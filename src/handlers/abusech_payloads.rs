@@ -0,0 +1,34 @@
+use super::{common_key_events, input};
+use crate::{app::App, event::Key, ActiveBlock};
+
+pub fn handler(key: Key, app: &mut App) {
+    let payloads = app.abusech.payloads();
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index = common_key_events::on_down_press_handler(
+                &payloads,
+                Some(app.abusech.payload_index),
+            );
+            app.abusech.payload_index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index =
+                common_key_events::on_up_press_handler(&payloads, Some(app.abusech.payload_index));
+            app.abusech.payload_index = next_index;
+        }
+        k if common_key_events::left_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::AbusechIocs),
+                Some(ActiveBlock::AbusechIocs),
+            );
+        }
+        Key::Enter => {
+            if let Some(payload) = payloads.get(app.abusech.payload_index) {
+                let hash = payload[2].to_owned();
+                input::search_indicator(app, hash);
+            }
+        }
+        _ => {}
+    };
+}
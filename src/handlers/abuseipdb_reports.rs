@@ -0,0 +1,67 @@
+use super::{super::app::App, common_key_events};
+use crate::{event::Key, network::IoEvent};
+
+pub fn handler(key: Key, app: &mut App) {
+    let reports = &app.abuseipdb.reports.data;
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index = common_key_events::on_down_press_handler(
+                &reports.results,
+                Some(app.abuseipdb.report_index),
+            );
+            app.abuseipdb.report_index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index = common_key_events::on_up_press_handler(
+                &reports.results,
+                Some(app.abuseipdb.report_index),
+            );
+            app.abuseipdb.report_index = next_index;
+        }
+        k if k == app.user_config.keys.load_more
+            && reports.has_more()
+            && !app.abuseipdb.reports_loading =>
+        {
+            // The searched address, AbuseIPDB may write it differently in the check result
+            let next_page = reports.page + 1;
+            let ip = app.query.to_owned();
+            app.abuseipdb.reports_loading = true;
+            app.dispatch(IoEvent::AbuseipdbReports(ip, next_page));
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_more_requests_the_next_page_once() {
+        let mut app = App::default();
+        app.abuseipdb.reports.data.page = 1;
+        app.abuseipdb.reports.data.last_page = 3;
+        let load_more = app.user_config.keys.load_more;
+
+        handler(load_more, &mut app);
+        assert!(app.is_loading);
+        assert!(app.abuseipdb.reports_loading);
+
+        // A second press before the page arrives must not request page 2 again
+        app.is_loading = false;
+        handler(load_more, &mut app);
+        assert!(!app.is_loading);
+    }
+
+    #[test]
+    fn load_more_stops_on_the_last_page() {
+        let mut app = App::default();
+        app.abuseipdb.reports.data.page = 3;
+        app.abuseipdb.reports.data.last_page = 3;
+
+        handler(app.user_config.keys.load_more, &mut app);
+
+        assert!(!app.is_loading);
+    }
+}
@@ -0,0 +1,34 @@
+use super::common_key_events;
+use crate::{app::App, clients::asn::parse_asn, event::Key, ActiveBlock};
+
+pub fn handler(key: Key, app: &mut App) {
+    let peers = &app.asn.context.peers;
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index =
+                common_key_events::on_down_press_handler(peers, Some(app.asn.peer_index));
+            app.asn.peer_index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index =
+                common_key_events::on_up_press_handler(peers, Some(app.asn.peer_index));
+            app.asn.peer_index = next_index;
+        }
+        k if common_key_events::left_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::AsnPrefixes),
+                Some(ActiveBlock::AsnPrefixes),
+            );
+        }
+        Key::Enter => {
+            if let Some(asn) = peers
+                .get(app.asn.peer_index)
+                .and_then(|peer| parse_asn(peer))
+            {
+                app.open_asn(asn);
+            }
+        }
+        _ => {}
+    };
+}
@@ -0,0 +1,31 @@
+use super::common_key_events;
+use crate::{app::App, event::Key, ActiveBlock};
+
+pub fn handler(key: Key, app: &mut App) {
+    let prefixes = &app.asn.context.prefixes;
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index =
+                common_key_events::on_down_press_handler(prefixes, Some(app.asn.prefix_index));
+            app.asn.prefix_index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index =
+                common_key_events::on_up_press_handler(prefixes, Some(app.asn.prefix_index));
+            app.asn.prefix_index = next_index;
+        }
+        k if common_key_events::right_event(k) => {
+            app.set_current_route_state(Some(ActiveBlock::AsnPeers), Some(ActiveBlock::AsnPeers));
+        }
+        Key::Enter => {
+            if let Some(network) = prefixes
+                .get(app.asn.prefix_index)
+                .and_then(|prefix| prefix.netblock.parse().ok())
+            {
+                app.queue_network_lookup(network);
+            }
+        }
+        _ => {}
+    };
+}
@@ -0,0 +1,52 @@
+use super::{common_key_events, input};
+use crate::{app::App, event::Key};
+
+pub fn handler(key: Key, app: &mut App) {
+    let items = &app.bulk.items;
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index = common_key_events::on_down_press_handler(items, Some(app.bulk.index));
+            app.bulk.index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index = common_key_events::on_up_press_handler(items, Some(app.bulk.index));
+            app.bulk.index = next_index;
+        }
+        k if k == app.user_config.keys.bulk_verdicts => {
+            app.request_bulk_verdicts();
+        }
+        Key::Enter => {
+            if let Some(item) = items.get(app.bulk.index) {
+                let indicator = item.indicator.to_owned();
+                input::search_indicator(app, indicator);
+            }
+        }
+        _ => {}
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verdicts_are_looked_up_once_for_networks() {
+        let mut app = App::default();
+        app.client_config.keys.virustotal = String::from("key");
+        let bulk_verdicts = app.user_config.keys.bulk_verdicts;
+
+        // A plain bulk queue has no network to look up
+        handler(bulk_verdicts, &mut app);
+        assert!(!app.bulk.verdicts_loading);
+
+        app.queue_network_lookup("192.0.2.0/30".parse().unwrap());
+        app.is_loading = false;
+        handler(bulk_verdicts, &mut app);
+        assert!(app.bulk.verdicts_loading);
+
+        app.is_loading = false;
+        handler(bulk_verdicts, &mut app);
+        assert!(!app.is_loading);
+    }
+}
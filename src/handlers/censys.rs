@@ -3,6 +3,21 @@ use crate::{app::CENSYS_MENU, event::Key};
 
 pub fn handler(key: Key, app: &mut App) {
     match key {
+        k if common_key_events::right_event(k) => match app.get_current_route().id {
+            RouteId::Censys => {
+                app.set_current_route_state(
+                    Some(ActiveBlock::CensysServices),
+                    Some(ActiveBlock::CensysServices),
+                );
+            }
+            RouteId::CensysHistory => {
+                app.set_current_route_state(
+                    Some(ActiveBlock::CensysHistory),
+                    Some(ActiveBlock::CensysHistory),
+                );
+            }
+            _ => {}
+        },
         k if common_key_events::down_event(k) => {
             let next_index =
                 common_key_events::on_down_press_handler(&CENSYS_MENU, Some(app.censys.menu_index));
@@ -23,6 +38,10 @@ fn switch_view(app: &mut App) {
     match app.censys.menu_index {
         0 => app.push_navigation_stack(RouteId::Censys, ActiveBlock::CensysMenu),
         1 => app.push_navigation_stack(RouteId::CensysGeoLookup, ActiveBlock::CensysMenu),
+        2 => {
+            app.open_censys_history();
+            app.push_navigation_stack(RouteId::CensysHistory, ActiveBlock::CensysMenu);
+        }
         _ => {}
     }
 }
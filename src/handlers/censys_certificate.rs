@@ -0,0 +1,53 @@
+use super::{
+    super::app::{ActiveBlock, App, ResultStatus, RouteId},
+    common_key_events, input,
+};
+use crate::{
+    clients::censys::{CensysCertificate, CensysCertificateHosts},
+    event::Key,
+    network::IoEvent,
+};
+
+pub fn handler(key: Key, app: &mut App) {
+    let hosts = app.censys.certificate_host_items.result.hosts.clone();
+
+    match key {
+        k if common_key_events::up_event(k) => {
+            let next_index = common_key_events::on_up_press_handler(
+                &hosts,
+                Some(app.censys.certificate_host_index),
+            );
+            app.censys.certificate_host_index = next_index;
+        }
+        k if common_key_events::down_event(k) => {
+            let next_index = common_key_events::on_down_press_handler(
+                &hosts,
+                Some(app.censys.certificate_host_index),
+            );
+            app.censys.certificate_host_index = next_index;
+        }
+        // Pivot to another host presenting the same certificate
+        Key::Enter => {
+            if let Some(host) = hosts.get(app.censys.certificate_host_index) {
+                input::search_indicator(app, host.ip.to_owned());
+            }
+        }
+        _ => {}
+    }
+}
+
+// Look up a certificate by its SHA-256 fingerprint and show the hosts presenting it
+pub fn open_certificate(app: &mut App, fingerprint: String) {
+    if app.client_config.keys.censys_secret.is_empty() {
+        app.push_navigation_stack(RouteId::Unloaded, ActiveBlock::CensysUnloaded);
+        return;
+    }
+
+    app.censys.certificate_fingerprint = fingerprint.to_owned();
+    app.censys.certificate_status = ResultStatus::Loading;
+    app.censys.certificate_error = None;
+    app.censys.certificate_items = CensysCertificate::default();
+    app.censys.certificate_host_items = CensysCertificateHosts::default();
+    app.dispatch(IoEvent::CensysCertificate(fingerprint));
+    app.push_navigation_stack(RouteId::CensysCertificate, ActiveBlock::CensysCertificate);
+}
@@ -0,0 +1,29 @@
+use super::{
+    super::app::{ActiveBlock, App},
+    common_key_events,
+};
+use crate::event::Key;
+
+pub fn handler(key: Key, app: &mut App) {
+    let events = app.censys.history_items.result.events.clone();
+
+    match key {
+        k if common_key_events::left_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::CensysMenu),
+                Some(ActiveBlock::CensysMenu),
+            );
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index =
+                common_key_events::on_up_press_handler(&events, Some(app.censys.history_index));
+            app.censys.history_index = next_index;
+        }
+        k if common_key_events::down_event(k) => {
+            let next_index =
+                common_key_events::on_down_press_handler(&events, Some(app.censys.history_index));
+            app.censys.history_index = next_index;
+        }
+        _ => {}
+    }
+}
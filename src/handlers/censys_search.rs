@@ -0,0 +1,97 @@
+use super::{
+    super::app::{ActiveBlock, App, RouteId},
+    common_key_events, input,
+};
+use crate::{clients::censys::CensysSearchIp, event::Key, network::IoEvent};
+
+pub fn handler(key: Key, app: &mut App) {
+    let hits = app.censys.search_items.result.hits.clone();
+
+    match key {
+        k if common_key_events::up_event(k) => {
+            let next_index =
+                common_key_events::on_up_press_handler(&hits, Some(app.censys.search_index));
+            app.censys.search_index = next_index;
+        }
+        k if common_key_events::down_event(k) => {
+            let next_index =
+                common_key_events::on_down_press_handler(&hits, Some(app.censys.search_index));
+            app.censys.search_index = next_index;
+        }
+        k if k == app.user_config.keys.load_more && !app.censys.search_loading => {
+            if let Some(cursor) = app.censys.search_items.result.next_cursor() {
+                let query = app.censys.search_query.to_owned();
+                app.censys.search_loading = true;
+                app.dispatch(IoEvent::CensysSearch(query, Some(cursor)));
+            }
+        }
+        // Open the regular Censys host view for the selected hit
+        Key::Enter => {
+            if let Some(hit) = hits.get(app.censys.search_index) {
+                input::search_indicator(app, hit.ip.to_owned());
+                // The view fills in once the lookup answers, the previous host mustn't show meanwhile
+                app.censys.search_ip_items = CensysSearchIp::default();
+                app.censys.menu_index = 0;
+                app.push_navigation_stack(RouteId::Censys, ActiveBlock::CensysMenu);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::censys::{HostHit, Links};
+
+    #[test]
+    fn load_more_waits_for_the_pending_page() {
+        let mut app = App::default();
+        app.censys.search_query = String::from("services.port: 22");
+        app.censys.search_items.result.hits = vec![HostHit::default(); 2];
+        app.censys.search_items.result.links = Some(Links {
+            prev: None,
+            next: Some(String::from("next")),
+        });
+        let load_more = app.user_config.keys.load_more;
+
+        handler(load_more, &mut app);
+        assert!(app.is_loading);
+        assert!(app.censys.search_loading);
+
+        // A second press before the page arrives must not request the same cursor again
+        app.is_loading = false;
+        handler(load_more, &mut app);
+        assert!(!app.is_loading);
+    }
+
+    #[test]
+    fn load_more_stops_on_the_last_page() {
+        let mut app = App::default();
+        app.censys.search_items.result.links = Some(Links {
+            prev: Some(String::from("prev")),
+            next: Some(String::new()),
+        });
+
+        handler(app.user_config.keys.load_more, &mut app);
+
+        assert!(!app.is_loading);
+        assert!(!app.censys.search_loading);
+    }
+
+    #[test]
+    fn opening_a_hit_drops_the_previous_host() {
+        let mut app = App::default();
+        app.censys.search_ip_items.result.ip = String::from("192.0.2.1");
+        app.censys.search_items.result.hits = vec![HostHit {
+            ip: String::from("198.51.100.7"),
+            ..HostHit::default()
+        }];
+
+        handler(Key::Enter, &mut app);
+
+        assert_eq!(app.query, "198.51.100.7");
+        assert!(app.censys.search_ip_items.result.ip.is_empty());
+        assert_eq!(app.get_current_route().id, RouteId::Censys);
+    }
+}
@@ -0,0 +1,40 @@
+use super::{
+    super::app::{ActiveBlock, App},
+    censys_certificate, common_key_events,
+};
+use crate::event::Key;
+
+pub fn handler(key: Key, app: &mut App) {
+    let services = app.censys.search_ip_items.result.services.clone();
+
+    match key {
+        k if common_key_events::left_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::CensysMenu),
+                Some(ActiveBlock::CensysMenu),
+            );
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index =
+                common_key_events::on_up_press_handler(&services, Some(app.censys.service_index));
+            app.censys.service_index = next_index;
+        }
+        k if common_key_events::down_event(k) => {
+            let next_index =
+                common_key_events::on_down_press_handler(&services, Some(app.censys.service_index));
+            app.censys.service_index = next_index;
+        }
+        Key::Enter => {
+            // Only services presenting a certificate have anything to pivot on
+            let fingerprint = services
+                .get(app.censys.service_index)
+                .and_then(|service| service.certificate.clone())
+                .filter(|fingerprint| !fingerprint.is_empty());
+
+            if let Some(fingerprint) = fingerprint {
+                censys_certificate::open_certificate(app, fingerprint);
+            }
+        }
+        _ => {}
+    }
+}
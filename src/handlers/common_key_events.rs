@@ -61,12 +61,31 @@ pub fn handle_right_event(app: &mut App) {
             }
             RouteId::Censys => {}
             RouteId::CensysGeoLookup => {}
+            RouteId::CensysHistory => {}
+            RouteId::CensysCertificate => {}
+            RouteId::CensysSearch => {}
+            RouteId::Dns => {}
+            RouteId::GeoIp => {}
+            RouteId::Rdap => {}
+            RouteId::Abuseipdb => {}
+            RouteId::Greynoise => {}
+            RouteId::Otx => {}
+            RouteId::Abusech => {}
+            RouteId::Crtsh => {}
+            RouteId::Bulk => {}
+            RouteId::SecurityTrails => {}
+            RouteId::Urlscan => {}
+            RouteId::Asn => {}
+            RouteId::Extract => {}
             RouteId::VirustotalDetection => {}
             RouteId::VirustotalDetails => {}
             RouteId::VirustotalCommunity => {}
+            RouteId::VirustotalRelations => {}
             RouteId::Unloaded => {}
             RouteId::Shodan => {}
             RouteId::ShodanGeoLookup => {}
+            RouteId::ShodanSearch => {}
+            RouteId::ShodanServiceDetail => {}
             RouteId::Error => {}
             RouteId::NotFound => {}
             RouteId::NotQueried => {}
@@ -0,0 +1,30 @@
+use super::common_key_events;
+use crate::{app::App, event::Key, ActiveBlock};
+
+pub fn handler(key: Key, app: &mut App) {
+    let certificates = &app.crtsh.certificates;
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index = common_key_events::on_down_press_handler(
+                certificates,
+                Some(app.crtsh.certificate_index),
+            );
+            app.crtsh.certificate_index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index = common_key_events::on_up_press_handler(
+                certificates,
+                Some(app.crtsh.certificate_index),
+            );
+            app.crtsh.certificate_index = next_index;
+        }
+        k if common_key_events::right_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::CrtshSubdomains),
+                Some(ActiveBlock::CrtshSubdomains),
+            );
+        }
+        _ => {}
+    };
+}
@@ -0,0 +1,39 @@
+use super::{common_key_events, input};
+use crate::{app::App, event::Key, ActiveBlock};
+
+pub fn handler(key: Key, app: &mut App) {
+    let subdomains = &app.crtsh.subdomains;
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index = common_key_events::on_down_press_handler(
+                subdomains,
+                Some(app.crtsh.subdomain_index),
+            );
+            app.crtsh.subdomain_index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index =
+                common_key_events::on_up_press_handler(subdomains, Some(app.crtsh.subdomain_index));
+            app.crtsh.subdomain_index = next_index;
+        }
+        k if common_key_events::left_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::CrtshCertificates),
+                Some(ActiveBlock::CrtshCertificates),
+            );
+        }
+        k if k == app.user_config.keys.bulk_lookup && !subdomains.is_empty() => {
+            let source = format!("crt.sh subdomains of {}", app.crtsh.domain);
+            let subdomains = subdomains.clone();
+            app.queue_bulk_lookup(source, subdomains);
+        }
+        Key::Enter => {
+            if let Some(subdomain) = subdomains.get(app.crtsh.subdomain_index) {
+                let subdomain = subdomain.to_owned();
+                input::search_indicator(app, subdomain);
+            }
+        }
+        _ => {}
+    };
+}
@@ -0,0 +1,31 @@
+use super::{common_key_events, input};
+use crate::{app::App, event::Key, ActiveBlock};
+
+pub fn handler(key: Key, app: &mut App) {
+    let records = app.passive_dns();
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index =
+                common_key_events::on_down_press_handler(&records, Some(app.dns.passive_index));
+            app.dns.passive_index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index =
+                common_key_events::on_up_press_handler(&records, Some(app.dns.passive_index));
+            app.dns.passive_index = next_index;
+        }
+        k if common_key_events::left_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::DnsRecords),
+                Some(ActiveBlock::DnsRecords),
+            );
+        }
+        Key::Enter => {
+            if let Some(record) = records.get(app.dns.passive_index) {
+                input::search_indicator(app, record.value.to_owned());
+            }
+        }
+        _ => {}
+    };
+}
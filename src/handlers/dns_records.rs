@@ -0,0 +1,38 @@
+use super::{common_key_events, input};
+use crate::{app::App, event::Key, indicator::Indicator, ActiveBlock};
+
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index = common_key_events::on_down_press_handler(
+                &app.dns.records,
+                Some(app.dns.record_index),
+            );
+            app.dns.record_index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index = common_key_events::on_up_press_handler(
+                &app.dns.records,
+                Some(app.dns.record_index),
+            );
+            app.dns.record_index = next_index;
+        }
+        k if common_key_events::right_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::DnsPassive),
+                Some(ActiveBlock::DnsPassive),
+            );
+        }
+        Key::Enter => {
+            let record = app.dns.records.get(app.dns.record_index);
+            // TXT records aren't names, only pivot on values that are indicators themselves
+            if let Some(value) = record
+                .map(|record| record.value.to_owned())
+                .filter(|value| Indicator::parse(value).is_some())
+            {
+                input::search_indicator(app, value);
+            }
+        }
+        _ => {}
+    };
+}
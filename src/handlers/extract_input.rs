@@ -0,0 +1,19 @@
+use crate::{app::App, event::Key};
+
+// Pasted text arrives one key at a time, so everything typed goes into the text until it is
+// submitted with Ctrl-d
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        Key::Ctrl('d') => app.extract_indicators(),
+        Key::Esc => {
+            app.pop_navigation_stack();
+        }
+        Key::Enter => app.extract.text.push('\n'),
+        Key::Tab => app.extract.text.push('\t'),
+        Key::Backspace => {
+            app.extract.text.pop();
+        }
+        Key::Char(c) => app.extract.text.push(c),
+        _ => {}
+    }
+}
@@ -0,0 +1,56 @@
+use super::{common_key_events, input};
+use crate::{app::App, event::Key, indicator::Indicator};
+
+pub fn handler(key: Key, app: &mut App) {
+    let indicators = &app.extract.indicators;
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index =
+                common_key_events::on_down_press_handler(indicators, Some(app.extract.index));
+            app.extract.index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index =
+                common_key_events::on_up_press_handler(indicators, Some(app.extract.index));
+            app.extract.index = next_index;
+        }
+        Key::Char(' ') => {
+            if let Some(selected) = app.extract.selected.get_mut(app.extract.index) {
+                *selected = !*selected;
+            }
+        }
+        Key::Enter => {
+            if let Some(indicator) = indicators.get(app.extract.index) {
+                let indicator = indicator.to_string();
+                input::search_indicator(app, indicator);
+            }
+        }
+        // The selected indicators are queued together, or all of them when none are selected.
+        // Hashes, ASNs and ranges have no single address for the bulk view to resolve, they're
+        // looked up one at a time with Enter
+        k if k == app.user_config.keys.bulk_lookup => {
+            let any_selected = app.extract.selected.contains(&true);
+            let queued = indicators
+                .iter()
+                .zip(&app.extract.selected)
+                .filter(|(_, selected)| **selected || !any_selected)
+                .filter(|(indicator, _)| {
+                    matches!(
+                        indicator,
+                        Indicator::Ip(_)
+                            | Indicator::Domain(_)
+                            | Indicator::Url(_)
+                            | Indicator::Email(_)
+                    )
+                })
+                .map(|(indicator, _)| indicator.to_string())
+                .collect::<Vec<String>>();
+
+            if !queued.is_empty() {
+                app.queue_bulk_lookup(String::from("pasted text"), queued);
+            }
+        }
+        _ => {}
+    };
+}
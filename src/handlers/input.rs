@@ -1,10 +1,19 @@
 extern crate unicode_width;
-use super::super::app::{ActiveBlock, App, RouteId};
+use super::super::app::{ActiveBlock, App, ResultStatus, RouteId};
 use super::super::network::IoEvent;
-use crate::event::Key;
-use std::{convert::TryInto, net::IpAddr, str::FromStr};
+use crate::{
+    clients::{censys::CensysHostSearch, shodan::ShodanHostSearch},
+    event::Key,
+    indicator::{self, Indicator},
+};
+use std::convert::TryInto;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
+// Inputs starting with this prefix are run as a Censys Search 2.0 query rather than an IP lookup
+const CENSYS_QUERY_PREFIX: &str = "censys:";
+// Inputs starting with this prefix are run as a Shodan host search using Shodan filter syntax
+const SHODAN_QUERY_PREFIX: &str = "shodan:";
+
 // Handle event when the search input block is active
 pub fn handler(key: Key, app: &mut App) {
     match key {
@@ -54,36 +63,30 @@ pub fn handler(key: Key, app: &mut App) {
             app.input_idx = 0;
             app.input_cursor_position = 0;
         }
-        Key::Left | Key::Ctrl('b') => {
-            if !app.input.is_empty() && app.input_idx > 0 {
-                let last_c = app.input[app.input_idx - 1];
-                app.input_idx -= 1;
-                app.input_cursor_position -= compute_character_width(last_c);
-            }
+        Key::Left | Key::Ctrl('b') if !app.input.is_empty() && app.input_idx > 0 => {
+            let last_c = app.input[app.input_idx - 1];
+            app.input_idx -= 1;
+            app.input_cursor_position -= compute_character_width(last_c);
         }
-        Key::Right | Key::Ctrl('f') => {
-            if app.input_idx < app.input.len() {
-                let next_c = app.input[app.input_idx];
-                app.input_idx += 1;
-                app.input_cursor_position += compute_character_width(next_c);
-            }
+        Key::Right | Key::Ctrl('f') if app.input_idx < app.input.len() => {
+            let next_c = app.input[app.input_idx];
+            app.input_idx += 1;
+            app.input_cursor_position += compute_character_width(next_c);
         }
         Key::Char(c) => {
             app.input.insert(app.input_idx, c);
             app.input_idx += 1;
             app.input_cursor_position += compute_character_width(c);
         }
-        Key::Backspace | Key::Ctrl('h') => {
-            if !app.input.is_empty() && app.input_idx > 0 {
-                let last_c = app.input.remove(app.input_idx - 1);
-                app.input_idx -= 1;
-                app.input_cursor_position -= compute_character_width(last_c);
-            }
+        Key::Backspace | Key::Ctrl('h') if !app.input.is_empty() && app.input_idx > 0 => {
+            let last_c = app.input.remove(app.input_idx - 1);
+            app.input_idx -= 1;
+            app.input_cursor_position -= compute_character_width(last_c);
         }
-        Key::Delete | Key::Ctrl('d') => {
-            if !app.input.is_empty() && app.input_idx < app.input.len() {
-                app.input.remove(app.input_idx);
-            }
+        Key::Delete | Key::Ctrl('d')
+            if !app.input.is_empty() && app.input_idx < app.input.len() =>
+        {
+            app.input.remove(app.input_idx);
         }
         Key::Esc => {
             app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Home));
@@ -98,34 +101,190 @@ fn process_input(app: &mut App, input: String) {
         return;
     }
 
-    let ip = refang(input);
+    if let Some(query) = input.strip_prefix(CENSYS_QUERY_PREFIX) {
+        search_censys_hosts(app, query.trim().to_owned());
+        return;
+    }
+
+    if let Some(query) = input.strip_prefix(SHODAN_QUERY_PREFIX) {
+        search_shodan_hosts(app, query.trim().to_owned());
+        return;
+    }
+
+    let indicator = match Indicator::parse(&indicator::refang(&input)) {
+        Some(indicator) => indicator,
+        None => {
+            app.is_input_error = true;
+            return;
+        }
+    };
+    app.is_input_error = false;
+    app.reset_results();
+
+    // Emails have no reputation of their own, their domain is looked up instead
+    let indicator = match indicator {
+        Indicator::Email(email) => match email.rsplit_once('@') {
+            Some((_, domain)) => Indicator::Domain(domain.trim_end_matches('.').to_owned()),
+            None => return,
+        },
+        indicator => indicator,
+    };
+
+    let query = indicator.to_string();
+    app.query = query.to_owned();
+    app.export_message = None;
+    app.list_tags.clear();
+
+    app.watchlist_matches = app
+        .watchlist
+        .matches(&indicator)
+        .into_iter()
+        .cloned()
+        .collect();
+
+    // ASNs and networks get views of their own instead of a search result page
+    match indicator {
+        Indicator::Asn(asn) => {
+            app.asn.address = None;
+            app.open_asn(asn);
+            return;
+        }
+        Indicator::Cidr(network) => {
+            app.queue_network_lookup(network);
+            return;
+        }
+        _ => {}
+    }
+
+    // Censys and Shodan only know about hosts, VirusTotal handles every indicator type
+    if indicator.is_ip() {
+        app.dispatch(IoEvent::ListTags(query.to_owned()));
+
+        if !app.client_config.keys.censys_secret.is_empty() {
+            app.dispatch(IoEvent::Censys(query.to_owned()));
+            app.censys.status = ResultStatus::Loading;
+        }
+
+        if !app.client_config.keys.shodan.is_empty() {
+            app.dispatch(IoEvent::Shodan(query.to_owned()));
+            app.shodan.status = ResultStatus::Loading;
+        }
+
+        app.dispatch(IoEvent::Rdap(query.to_owned()));
+        app.rdap.status = ResultStatus::Loading;
+
+        if app.client_config.geoip.is_enabled() {
+            app.dispatch(IoEvent::GeoIp(query.to_owned()));
+            app.geoip.status = ResultStatus::Loading;
+        }
 
-    if !is_ip_addr(ip.to_owned()) {
-        app.is_input_error = true
+        if !app.client_config.keys.abuseipdb.is_empty() {
+            app.dispatch(IoEvent::Abuseipdb(query.to_owned()));
+            app.dispatch(IoEvent::AbuseipdbReports(query.to_owned(), 1));
+            app.abuseipdb.status = ResultStatus::Loading;
+        }
+
+        if !app.client_config.keys.greynoise.is_empty() {
+            app.dispatch(IoEvent::Greynoise(query.to_owned()));
+            app.greynoise.status = ResultStatus::Loading;
+        }
+    }
+
+    app.dns.records.clear();
+    app.dns.record_index = 0;
+    app.dns.passive_index = 0;
+    if matches!(indicator, Indicator::Ip(_) | Indicator::Domain(_)) {
+        app.dispatch(IoEvent::Dns(query.to_owned()));
+        app.dns.status = ResultStatus::Loading;
     }
 
-    if !app.client_config.keys.censys_secret.is_empty() {
-        app.dispatch(IoEvent::Censys(ip.to_owned()));
+    if matches!(indicator, Indicator::Ip(_) | Indicator::Domain(_))
+        && !app.client_config.keys.securitytrails.is_empty()
+    {
+        app.dispatch(IoEvent::SecurityTrails(query.to_owned()));
+        app.securitytrails.status = ResultStatus::Loading;
     }
 
-    if !app.client_config.keys.shodan.is_empty() {
-        app.dispatch(IoEvent::Shodan(ip.to_owned()));
+    app.urlscan.indicator = query.to_owned();
+    if !app.client_config.keys.urlscan.is_empty() {
+        app.dispatch(IoEvent::Urlscan(query.to_owned()));
+        app.urlscan.status = ResultStatus::Loading;
+    }
+
+    app.asn.address = match &indicator {
+        Indicator::Ip(ip) => Some(*ip),
+        _ => None,
+    };
+
+    app.dispatch(IoEvent::Abusech(query.to_owned()));
+    app.abusech.status = ResultStatus::Loading;
+
+    // OTX has indicator endpoints for every type osintui searches
+    if !app.client_config.keys.otx.is_empty() {
+        app.dispatch(IoEvent::Otx(query.to_owned()));
+        app.otx.status = ResultStatus::Loading;
     }
 
     if !app.client_config.keys.virustotal.is_empty() {
-        app.dispatch(IoEvent::VirusTotal(ip.clone()));
-        app.dispatch(IoEvent::VirustotalComments(ip));
+        app.virustotal.indicator = query.to_owned();
+        app.virustotal.relationships.clear();
+        app.virustotal.relationships_loading.clear();
+        app.virustotal.relationship_errors.clear();
+        app.virustotal.relationship_index = 0;
+        app.virustotal.relation_item_index = 0;
+        app.virustotal.comments_loading = false;
+        app.dispatch(IoEvent::VirusTotal(query.clone()));
+        app.dispatch(IoEvent::VirustotalComments(query, None));
+        app.virustotal.status = ResultStatus::Loading;
     }
 
+    // crt.sh is slow for popular domains, so it goes last to keep the other lookups moving
+    if let Indicator::Domain(domain) = &indicator {
+        app.dispatch(IoEvent::Crtsh(domain.to_owned()));
+        app.crtsh.status = ResultStatus::Loading;
+    }
+
+    app.search_result_index = 0;
     app.push_navigation_stack(RouteId::SearchResult, ActiveBlock::SearchResult);
 }
 
-fn is_ip_addr(input: String) -> bool {
-    IpAddr::from_str(&input).is_ok()
+fn search_censys_hosts(app: &mut App, query: String) {
+    if app.client_config.keys.censys_secret.is_empty() {
+        app.push_navigation_stack(RouteId::Unloaded, ActiveBlock::CensysUnloaded);
+        return;
+    }
+
+    app.censys.search_query = query.to_owned();
+    app.censys.search_items = CensysHostSearch::default();
+    app.censys.search_index = 0;
+    app.censys.search_loading = false;
+    app.dispatch(IoEvent::CensysSearch(query, None));
+    app.push_navigation_stack(RouteId::CensysSearch, ActiveBlock::CensysSearchResults);
 }
 
-fn refang(input: String) -> String {
-    input.replace(&['[', ']'], "")
+fn search_shodan_hosts(app: &mut App, query: String) {
+    if app.client_config.keys.shodan.is_empty() {
+        app.push_navigation_stack(RouteId::Unloaded, ActiveBlock::ShodanUnloaded);
+        return;
+    }
+
+    app.shodan.search_query = query.to_owned();
+    app.shodan.search_items = ShodanHostSearch::default();
+    app.shodan.search_index = 0;
+    app.shodan.search_page = 1;
+    app.shodan.search_loading = false;
+    app.dispatch(IoEvent::ShodanSearch(query, 1));
+    app.push_navigation_stack(RouteId::ShodanSearch, ActiveBlock::ShodanSearchResults);
+}
+
+// Replace the search input with `indicator` and look it up, used when pivoting from a result
+pub fn search_indicator(app: &mut App, indicator: String) {
+    app.input = indicator.chars().collect();
+    app.input_idx = app.input.len();
+    app.input_cursor_position = UnicodeWidthStr::width(indicator.as_str())
+        .try_into()
+        .unwrap();
+    process_input(app, indicator);
 }
 
 fn compute_character_width(character: char) -> u16 {
@@ -134,3 +293,94 @@ fn compute_character_width(character: char) -> u16 {
         .try_into()
         .unwrap()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn network_search_drops_previous_tags() {
+        let mut app = App::default();
+        app.list_tags = vec![String::from("Tor exit node")];
+
+        search_indicator(&mut app, String::from("192.0.2.0/30"));
+
+        assert!(app.list_tags.is_empty());
+    }
+
+    #[test]
+    fn defanged_input_is_refanged() {
+        let mut app = App::default();
+
+        search_indicator(&mut app, String::from("hxxps://evil[.]example/gate.php"));
+
+        assert!(!app.is_input_error);
+        assert_eq!(app.query, "https://evil.example/gate.php");
+    }
+
+    #[test]
+    fn censys_query_starts_a_new_search() {
+        let mut app = App::default();
+        app.client_config.keys.censys_secret = String::from("secret");
+        app.censys.search_loading = true;
+        app.censys.search_index = 3;
+
+        process_input(&mut app, String::from("censys: services.port: 22"));
+
+        assert_eq!(app.censys.search_query, "services.port: 22");
+        assert_eq!(app.censys.search_index, 0);
+        assert!(!app.censys.search_loading);
+        assert_eq!(
+            app.get_current_route().active_block,
+            ActiveBlock::CensysSearchResults
+        );
+    }
+
+    #[test]
+    fn shodan_query_starts_from_the_first_page() {
+        let mut app = App::default();
+        app.client_config.keys.shodan = String::from("key");
+        app.shodan.search_page = 3;
+        app.shodan.search_loading = true;
+
+        process_input(&mut app, String::from("shodan: org:\"Google\" port:443"));
+
+        assert_eq!(app.shodan.search_query, "org:\"Google\" port:443");
+        assert_eq!(app.shodan.search_page, 1);
+        assert!(!app.shodan.search_loading);
+        assert_eq!(
+            app.get_current_route().active_block,
+            ActiveBlock::ShodanSearchResults
+        );
+    }
+
+    #[test]
+    fn new_search_resets_provider_statuses() {
+        let mut app = App::default();
+        app.censys.status = ResultStatus::Found;
+        app.shodan.status = ResultStatus::Found;
+        app.greynoise.status = ResultStatus::Found;
+
+        // Censys, Shodan and GreyNoise only look up IPs, a domain must not show their results
+        search_indicator(&mut app, String::from("evil.example"));
+
+        assert_eq!(app.censys.status, ResultStatus::NotQueried);
+        assert_eq!(app.shodan.status, ResultStatus::NotQueried);
+        assert_eq!(app.greynoise.status, ResultStatus::NotQueried);
+        assert_eq!(app.get_current_route().id, RouteId::SearchResult);
+    }
+
+    #[test]
+    fn search_marks_dispatched_providers_as_loading() {
+        let mut app = App::default();
+        app.client_config.keys.shodan = String::from("key");
+
+        search_indicator(&mut app, String::from("203.0.113.7"));
+
+        assert_eq!(app.shodan.status, ResultStatus::Loading);
+        assert_eq!(app.abusech.status, ResultStatus::Loading);
+        // Providers without a key are never asked
+        assert_eq!(app.censys.status, ResultStatus::NotQueried);
+        assert_eq!(app.virustotal.status, ResultStatus::NotQueried);
+    }
+}
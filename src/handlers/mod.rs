@@ -1,21 +1,53 @@
+mod abusech_iocs;
+mod abusech_payloads;
+mod abuseipdb_reports;
+mod asn_peers;
+mod asn_prefixes;
+mod bulk_results;
 mod censys;
+mod censys_certificate;
+mod censys_history;
+mod censys_search;
+mod censys_services;
 mod common_key_events;
+mod crtsh_certificates;
+mod crtsh_subdomains;
+mod dns_passive;
+mod dns_records;
 mod empty;
 mod error_screen;
+mod extract_input;
+mod extract_results;
 mod home;
 mod input;
+mod otx_pulse_indicators;
+mod otx_pulses;
+mod rdap;
 mod search_result;
+mod securitytrails_history;
+mod securitytrails_whois;
 mod shodan;
+mod shodan_search;
+mod shodan_service_detail;
+mod shodan_services;
 mod unloaded;
+mod urlscan_scans;
 mod virustotal;
+mod virustotal_comment_detail;
+mod virustotal_comment_pivots;
 mod virustotal_comments;
+mod virustotal_relation_items;
+mod virustotal_relationships;
 mod virustotal_results;
 mod virustotal_summary;
 mod virustotal_whois;
 
 use super::app::{ActiveBlock, App, ResultStatus, RouteId};
+use crate::clients::asn::parse_asn;
 use crate::event::Key;
+use crate::export::Report;
 
+pub use extract_input::handler as extract_input_handler;
 pub use input::handler as input_handler;
 
 pub fn handle_app(key: Key, app: &mut App) {
@@ -26,6 +58,19 @@ pub fn handle_app(key: Key, app: &mut App) {
         _ if key == app.user_config.keys.censys => handle_censys(app),
         _ if key == app.user_config.keys.shodan => handle_shodan(app),
         _ if key == app.user_config.keys.virustotal => handle_virustotal(app),
+        _ if key == app.user_config.keys.dns => handle_dns(app),
+        _ if key == app.user_config.keys.geoip => handle_geoip(app),
+        _ if key == app.user_config.keys.rdap => handle_rdap(app),
+        _ if key == app.user_config.keys.abuseipdb => handle_abuseipdb(app),
+        _ if key == app.user_config.keys.greynoise => handle_greynoise(app),
+        _ if key == app.user_config.keys.otx => handle_otx(app),
+        _ if key == app.user_config.keys.abusech => handle_abusech(app),
+        _ if key == app.user_config.keys.crtsh => handle_crtsh(app),
+        _ if key == app.user_config.keys.securitytrails => handle_securitytrails(app),
+        _ if key == app.user_config.keys.urlscan => handle_urlscan(app),
+        _ if key == app.user_config.keys.asn => handle_asn(app),
+        _ if key == app.user_config.keys.extract => handle_extract(app),
+        _ if key == app.user_config.keys.export => handle_export(app),
         _ => handle_block_events(key, app),
     }
 }
@@ -48,7 +93,7 @@ fn handle_censys(app: &mut App) {
             ResultStatus::NotFound => {
                 app.push_navigation_stack(RouteId::NotFound, ActiveBlock::CensysNotFound)
             }
-            ResultStatus::NotQueried => {
+            ResultStatus::NotQueried | ResultStatus::Loading => {
                 app.push_navigation_stack(RouteId::NotQueried, ActiveBlock::CensysNotQueried)
             }
             _ => {
@@ -67,7 +112,7 @@ fn handle_shodan(app: &mut App) {
             ResultStatus::NotFound => {
                 app.push_navigation_stack(RouteId::NotFound, ActiveBlock::ShodanNotFound)
             }
-            ResultStatus::NotQueried => {
+            ResultStatus::NotQueried | ResultStatus::Loading => {
                 app.push_navigation_stack(RouteId::NotQueried, ActiveBlock::ShodanNotQueried)
             }
             _ => {
@@ -86,7 +131,7 @@ fn handle_virustotal(app: &mut App) {
             ResultStatus::NotFound => {
                 app.push_navigation_stack(RouteId::NotFound, ActiveBlock::VirustotalNotFound)
             }
-            ResultStatus::NotQueried => {
+            ResultStatus::NotQueried | ResultStatus::Loading => {
                 app.push_navigation_stack(RouteId::NotQueried, ActiveBlock::VirustotalNotQueried)
             }
             _ => {
@@ -100,6 +145,188 @@ fn handle_virustotal(app: &mut App) {
     }
 }
 
+fn handle_dns(app: &mut App) {
+    // Hostnames VirusTotal has seen resolving to or from the indicator fill the passive side
+    if !app.client_config.keys.virustotal.is_empty() {
+        virustotal_relationships::request_relationship(app, "resolutions");
+    }
+
+    app.dns.record_index = 0;
+    app.dns.passive_index = 0;
+    app.push_navigation_stack(RouteId::Dns, ActiveBlock::DnsRecords);
+}
+
+fn handle_abuseipdb(app: &mut App) {
+    if app.client_config.keys.abuseipdb.is_empty() {
+        app.push_navigation_stack(RouteId::Unloaded, ActiveBlock::AbuseipdbUnloaded);
+    } else {
+        match app.abuseipdb.status {
+            ResultStatus::NotFound => {
+                app.push_navigation_stack(RouteId::NotFound, ActiveBlock::AbuseipdbNotFound)
+            }
+            ResultStatus::NotQueried | ResultStatus::Loading => {
+                app.push_navigation_stack(RouteId::NotQueried, ActiveBlock::AbuseipdbNotQueried)
+            }
+            _ => {
+                app.push_navigation_stack(RouteId::Abuseipdb, ActiveBlock::AbuseipdbReports);
+            }
+        }
+    }
+}
+
+fn handle_greynoise(app: &mut App) {
+    if app.client_config.keys.greynoise.is_empty() {
+        app.push_navigation_stack(RouteId::Unloaded, ActiveBlock::GreynoiseUnloaded);
+    } else {
+        match app.greynoise.status {
+            ResultStatus::NotFound => {
+                app.push_navigation_stack(RouteId::NotFound, ActiveBlock::GreynoiseNotFound)
+            }
+            ResultStatus::NotQueried | ResultStatus::Loading => {
+                app.push_navigation_stack(RouteId::NotQueried, ActiveBlock::GreynoiseNotQueried)
+            }
+            _ => {
+                app.push_navigation_stack(RouteId::Greynoise, ActiveBlock::Greynoise);
+            }
+        }
+    }
+}
+
+fn handle_otx(app: &mut App) {
+    if app.client_config.keys.otx.is_empty() {
+        app.push_navigation_stack(RouteId::Unloaded, ActiveBlock::OtxUnloaded);
+    } else {
+        match app.otx.status {
+            ResultStatus::NotFound => {
+                app.push_navigation_stack(RouteId::NotFound, ActiveBlock::OtxNotFound)
+            }
+            ResultStatus::NotQueried | ResultStatus::Loading => {
+                app.push_navigation_stack(RouteId::NotQueried, ActiveBlock::OtxNotQueried)
+            }
+            _ => {
+                app.push_navigation_stack(RouteId::Otx, ActiveBlock::OtxPulses);
+            }
+        }
+    }
+}
+
+fn handle_abusech(app: &mut App) {
+    match app.abusech.status {
+        ResultStatus::NotFound => {
+            app.push_navigation_stack(RouteId::NotFound, ActiveBlock::AbusechNotFound)
+        }
+        ResultStatus::NotQueried | ResultStatus::Loading => {
+            app.push_navigation_stack(RouteId::NotQueried, ActiveBlock::AbusechNotQueried)
+        }
+        _ => {
+            app.push_navigation_stack(RouteId::Abusech, ActiveBlock::AbusechIocs);
+        }
+    }
+}
+
+fn handle_crtsh(app: &mut App) {
+    match app.crtsh.status {
+        ResultStatus::NotFound => {
+            app.push_navigation_stack(RouteId::NotFound, ActiveBlock::CrtshNotFound)
+        }
+        ResultStatus::NotQueried | ResultStatus::Loading => {
+            app.push_navigation_stack(RouteId::NotQueried, ActiveBlock::CrtshNotQueried)
+        }
+        _ => {
+            app.push_navigation_stack(RouteId::Crtsh, ActiveBlock::CrtshCertificates);
+        }
+    }
+}
+
+fn handle_securitytrails(app: &mut App) {
+    if app.client_config.keys.securitytrails.is_empty() {
+        app.push_navigation_stack(RouteId::Unloaded, ActiveBlock::SecurityTrailsUnloaded);
+    } else {
+        match app.securitytrails.status {
+            ResultStatus::NotFound => {
+                app.push_navigation_stack(RouteId::NotFound, ActiveBlock::SecurityTrailsNotFound)
+            }
+            ResultStatus::NotQueried | ResultStatus::Loading => app
+                .push_navigation_stack(RouteId::NotQueried, ActiveBlock::SecurityTrailsNotQueried),
+            _ => {
+                app.push_navigation_stack(
+                    RouteId::SecurityTrails,
+                    ActiveBlock::SecurityTrailsHistory,
+                );
+            }
+        }
+    }
+}
+
+fn handle_urlscan(app: &mut App) {
+    if app.client_config.keys.urlscan.is_empty() {
+        app.push_navigation_stack(RouteId::Unloaded, ActiveBlock::UrlscanUnloaded);
+    } else {
+        match app.urlscan.status {
+            // Indicators without scans can still be submitted from the scan list
+            ResultStatus::NotQueried | ResultStatus::Loading => {
+                app.push_navigation_stack(RouteId::NotQueried, ActiveBlock::UrlscanNotQueried)
+            }
+            _ => {
+                app.push_navigation_stack(RouteId::Urlscan, ActiveBlock::UrlscanScans);
+            }
+        }
+    }
+}
+
+fn handle_asn(app: &mut App) {
+    // Rows of the bulk queue carry the AS of the address they resolved to
+    let asn = match app.get_current_route().active_block {
+        ActiveBlock::BulkResults => app
+            .bulk
+            .items
+            .get(app.bulk.index)
+            .and_then(|item| item.asn.as_deref())
+            .and_then(parse_asn),
+        _ => app.current_asn(),
+    };
+
+    match asn {
+        Some(asn) => app.open_asn(asn),
+        None => app.push_navigation_stack(RouteId::NotQueried, ActiveBlock::AsnNotQueried),
+    }
+}
+
+fn handle_extract(app: &mut App) {
+    app.extract.text.clear();
+    app.push_navigation_stack(RouteId::Extract, ActiveBlock::ExtractInput);
+}
+
+fn handle_export(app: &mut App) {
+    // Only single indicators have a search result to export
+    if app.query.is_empty() || app.get_current_route().id == RouteId::Home {
+        return;
+    }
+
+    let result = app
+        .client_config
+        .get_or_build_paths()
+        .and_then(|paths| Report::new(app).write(&paths.exports_dir));
+    app.export_message = Some(match result {
+        Ok(path) => format!("Exported to {}", path.display()),
+        Err(e) => format!("Export failed: {}", e),
+    });
+}
+
+fn handle_geoip(app: &mut App) {
+    if app.client_config.geoip.is_enabled() {
+        app.push_navigation_stack(RouteId::GeoIp, ActiveBlock::GeoIp);
+    } else {
+        app.push_navigation_stack(RouteId::Unloaded, ActiveBlock::GeoIpUnloaded);
+    }
+}
+
+// RDAP is keyless, the view explains itself when the indicator isn't an address
+fn handle_rdap(app: &mut App) {
+    app.rdap.record_index = 0;
+    app.push_navigation_stack(RouteId::Rdap, ActiveBlock::Rdap);
+}
+
 // Handle event for the current active block
 fn handle_block_events(key: Key, app: &mut App) {
     let current_route = app.get_current_route();
@@ -122,23 +349,99 @@ fn handle_block_events(key: Key, app: &mut App) {
         ActiveBlock::SearchResult => search_result::handler(key, app),
 
         // Censys blocks
-        ActiveBlock::CensysMenu | ActiveBlock::CensysServices => censys::handler(key, app),
+        ActiveBlock::CensysMenu => censys::handler(key, app),
+        ActiveBlock::CensysServices => censys_services::handler(key, app),
+        ActiveBlock::CensysHistory => censys_history::handler(key, app),
+        ActiveBlock::CensysCertificate => censys_certificate::handler(key, app),
+        ActiveBlock::CensysSearchResults => censys_search::handler(key, app),
         ActiveBlock::CensysNotFound
         | ActiveBlock::CensysNotQueried
         | ActiveBlock::CensysUnloaded => unloaded::handler(key, app),
 
+        // DNS blocks
+        ActiveBlock::DnsRecords => dns_records::handler(key, app),
+        ActiveBlock::DnsPassive => dns_passive::handler(key, app),
+
+        // AbuseIPDB blocks
+        ActiveBlock::AbuseipdbReports => abuseipdb_reports::handler(key, app),
+        ActiveBlock::AbuseipdbNotFound
+        | ActiveBlock::AbuseipdbNotQueried
+        | ActiveBlock::AbuseipdbUnloaded => unloaded::handler(key, app),
+
+        // GreyNoise blocks
+        ActiveBlock::Greynoise
+        | ActiveBlock::GreynoiseNotFound
+        | ActiveBlock::GreynoiseNotQueried
+        | ActiveBlock::GreynoiseUnloaded => unloaded::handler(key, app),
+
+        // abuse.ch blocks
+        ActiveBlock::AbusechIocs => abusech_iocs::handler(key, app),
+        ActiveBlock::AbusechPayloads => abusech_payloads::handler(key, app),
+        ActiveBlock::AbusechNotFound | ActiveBlock::AbusechNotQueried => {
+            unloaded::handler(key, app)
+        }
+
+        // crt.sh blocks
+        ActiveBlock::CrtshCertificates => crtsh_certificates::handler(key, app),
+        ActiveBlock::CrtshSubdomains => crtsh_subdomains::handler(key, app),
+        ActiveBlock::CrtshNotFound | ActiveBlock::CrtshNotQueried => unloaded::handler(key, app),
+
+        // SecurityTrails blocks
+        ActiveBlock::SecurityTrailsHistory => securitytrails_history::handler(key, app),
+        ActiveBlock::SecurityTrailsWhois => securitytrails_whois::handler(key, app),
+        ActiveBlock::SecurityTrailsNotFound
+        | ActiveBlock::SecurityTrailsNotQueried
+        | ActiveBlock::SecurityTrailsUnloaded => unloaded::handler(key, app),
+
+        // urlscan blocks
+        ActiveBlock::UrlscanScans => urlscan_scans::handler(key, app),
+        ActiveBlock::UrlscanNotQueried | ActiveBlock::UrlscanUnloaded => {
+            unloaded::handler(key, app)
+        }
+
+        // ASN blocks
+        ActiveBlock::AsnPrefixes => asn_prefixes::handler(key, app),
+        ActiveBlock::AsnPeers => asn_peers::handler(key, app),
+        ActiveBlock::AsnNotQueried => unloaded::handler(key, app),
+
+        // Extraction blocks
+        ActiveBlock::ExtractInput => extract_input::handler(key, app),
+        ActiveBlock::ExtractResults => extract_results::handler(key, app),
+
+        // Bulk lookup block
+        ActiveBlock::BulkResults => bulk_results::handler(key, app),
+
+        // OTX blocks
+        ActiveBlock::OtxPulses => otx_pulses::handler(key, app),
+        ActiveBlock::OtxPulseIndicators => otx_pulse_indicators::handler(key, app),
+        ActiveBlock::OtxNotFound | ActiveBlock::OtxNotQueried | ActiveBlock::OtxUnloaded => {
+            unloaded::handler(key, app)
+        }
+
+        // GeoIP blocks
+        ActiveBlock::GeoIp | ActiveBlock::GeoIpUnloaded => unloaded::handler(key, app),
+        // RDAP blocks
+        ActiveBlock::Rdap => rdap::handler(key, app),
+
         // Virustotal blocks
         ActiveBlock::VirustotalMenu
         | ActiveBlock::VirustotalSummary
         | ActiveBlock::VirustotalResults
         | ActiveBlock::VirustotalWhois
-        | ActiveBlock::VirustotalComments => virustotal_handler(key, app),
+        | ActiveBlock::VirustotalComments
+        | ActiveBlock::VirustotalCommentDetail
+        | ActiveBlock::VirustotalCommentPivots
+        | ActiveBlock::VirustotalRelationships
+        | ActiveBlock::VirustotalRelationItems => virustotal_handler(key, app),
         ActiveBlock::VirustotalNotFound
         | ActiveBlock::VirustotalNotQueried
         | ActiveBlock::VirustotalUnloaded => unloaded::handler(key, app),
 
         // Shodan blocks
-        ActiveBlock::ShodanMenu | ActiveBlock::ShodanServices => shodan::handler(key, app),
+        ActiveBlock::ShodanMenu => shodan::handler(key, app),
+        ActiveBlock::ShodanServices => shodan_services::handler(key, app),
+        ActiveBlock::ShodanServiceDetail => shodan_service_detail::handler(key, app),
+        ActiveBlock::ShodanSearchResults => shodan_search::handler(key, app),
         ActiveBlock::ShodanNotQueried
         | ActiveBlock::ShodanNotFound
         | ActiveBlock::ShodanUnloaded => unloaded::handler(key, app),
@@ -153,6 +456,10 @@ fn virustotal_handler(key: Key, app: &mut App) {
         ActiveBlock::VirustotalResults => virustotal_results::handler(key, app),
         ActiveBlock::VirustotalWhois => virustotal_whois::handler(key, app),
         ActiveBlock::VirustotalComments => virustotal_comments::handler(key, app),
+        ActiveBlock::VirustotalCommentDetail => virustotal_comment_detail::handler(key, app),
+        ActiveBlock::VirustotalCommentPivots => virustotal_comment_pivots::handler(key, app),
+        ActiveBlock::VirustotalRelationships => virustotal_relationships::handler(key, app),
+        ActiveBlock::VirustotalRelationItems => virustotal_relation_items::handler(key, app),
         _ => unreachable!(),
     }
 }
@@ -0,0 +1,35 @@
+use super::{common_key_events, input};
+use crate::{app::App, event::Key, ActiveBlock};
+
+pub fn handler(key: Key, app: &mut App) {
+    let indicators = &app.otx.pulse_indicators.results;
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index = common_key_events::on_down_press_handler(
+                indicators,
+                Some(app.otx.pulse_indicator_index),
+            );
+            app.otx.pulse_indicator_index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index = common_key_events::on_up_press_handler(
+                indicators,
+                Some(app.otx.pulse_indicator_index),
+            );
+            app.otx.pulse_indicator_index = next_index;
+        }
+        k if common_key_events::left_event(k) => {
+            app.set_current_route_state(Some(ActiveBlock::OtxPulses), Some(ActiveBlock::OtxPulses));
+        }
+        Key::Enter => {
+            let pivot = indicators
+                .get(app.otx.pulse_indicator_index)
+                .and_then(|indicator| indicator.pivot());
+            if let Some(indicator) = pivot {
+                input::search_indicator(app, indicator.to_string());
+            }
+        }
+        _ => {}
+    };
+}
@@ -0,0 +1,30 @@
+use super::common_key_events;
+use crate::{app::App, event::Key, network::IoEvent, ActiveBlock};
+
+pub fn handler(key: Key, app: &mut App) {
+    let pulses = &app.otx.indicator.general.pulse_info.pulses;
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index =
+                common_key_events::on_down_press_handler(pulses, Some(app.otx.pulse_index));
+            app.otx.pulse_index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index =
+                common_key_events::on_up_press_handler(pulses, Some(app.otx.pulse_index));
+            app.otx.pulse_index = next_index;
+        }
+        Key::Enter => {
+            if let Some(pulse) = pulses.get(app.otx.pulse_index) {
+                let pulse_id = pulse.id.to_owned();
+                app.dispatch(IoEvent::OtxPulseIndicators(pulse_id));
+                app.set_current_route_state(
+                    Some(ActiveBlock::OtxPulseIndicators),
+                    Some(ActiveBlock::OtxPulseIndicators),
+                );
+            }
+        }
+        _ => {}
+    };
+}
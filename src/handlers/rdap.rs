@@ -0,0 +1,20 @@
+use super::common_key_events;
+use crate::{app::App, event::Key};
+
+pub fn handler(key: Key, app: &mut App) {
+    let records = app.rdap.network.summary_to_vec();
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index =
+                common_key_events::on_down_press_handler(&records, Some(app.rdap.record_index));
+            app.rdap.record_index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index =
+                common_key_events::on_up_press_handler(&records, Some(app.rdap.record_index));
+            app.rdap.record_index = next_index;
+        }
+        _ => {}
+    };
+}
@@ -1,4 +1,31 @@
-use super::super::app::App;
+use super::{
+    super::app::{App, SEARCH_RESULT_PANELS},
+    common_key_events,
+};
 use crate::event::Key;
 
-pub fn handler(_key: Key, _app: &mut App) {}
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        k if common_key_events::right_event(k) || common_key_events::down_event(k) => {
+            let next_index = common_key_events::on_down_press_handler(
+                &SEARCH_RESULT_PANELS,
+                Some(app.search_result_index),
+            );
+            app.search_result_index = next_index;
+        }
+        k if common_key_events::left_event(k) || common_key_events::up_event(k) => {
+            let next_index = common_key_events::on_up_press_handler(
+                &SEARCH_RESULT_PANELS,
+                Some(app.search_result_index),
+            );
+            app.search_result_index = next_index;
+        }
+        Key::Enter => match app.search_result_index {
+            0 => super::handle_censys(app),
+            1 => super::handle_shodan(app),
+            2 => super::handle_virustotal(app),
+            _ => super::handle_geoip(app),
+        },
+        _ => {}
+    }
+}
@@ -0,0 +1,36 @@
+use super::{common_key_events, input};
+use crate::{app::App, event::Key, ActiveBlock};
+
+pub fn handler(key: Key, app: &mut App) {
+    let entries = &app.securitytrails.entries;
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index = common_key_events::on_down_press_handler(
+                entries,
+                Some(app.securitytrails.entry_index),
+            );
+            app.securitytrails.entry_index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index = common_key_events::on_up_press_handler(
+                entries,
+                Some(app.securitytrails.entry_index),
+            );
+            app.securitytrails.entry_index = next_index;
+        }
+        k if common_key_events::right_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::SecurityTrailsWhois),
+                Some(ActiveBlock::SecurityTrailsWhois),
+            );
+        }
+        Key::Enter => {
+            if let Some(entry) = entries.get(app.securitytrails.entry_index) {
+                let value = entry.value.to_owned();
+                input::search_indicator(app, value);
+            }
+        }
+        _ => {}
+    };
+}
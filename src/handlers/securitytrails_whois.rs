@@ -0,0 +1,40 @@
+use super::{common_key_events, input};
+use crate::{app::App, event::Key, ActiveBlock};
+
+pub fn handler(key: Key, app: &mut App) {
+    let records = &app.securitytrails.whois.result.items;
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index = common_key_events::on_down_press_handler(
+                records,
+                Some(app.securitytrails.whois_index),
+            );
+            app.securitytrails.whois_index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index = common_key_events::on_up_press_handler(
+                records,
+                Some(app.securitytrails.whois_index),
+            );
+            app.securitytrails.whois_index = next_index;
+        }
+        k if common_key_events::left_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::SecurityTrailsHistory),
+                Some(ActiveBlock::SecurityTrailsHistory),
+            );
+        }
+        // Registrant details are rarely searchable, the name servers of the era are
+        Key::Enter => {
+            let name_server = records
+                .get(app.securitytrails.whois_index)
+                .and_then(|record| record.name_servers.first())
+                .map(|name_server| name_server.to_lowercase());
+            if let Some(name_server) = name_server {
+                input::search_indicator(app, name_server);
+            }
+        }
+        _ => {}
+    };
+}
@@ -3,6 +3,12 @@ use crate::{app::SHODAN_MENU, event::Key};
 
 pub fn handler(key: Key, app: &mut App) {
     match key {
+        k if common_key_events::right_event(k) && app.get_current_route().id == RouteId::Shodan => {
+            app.set_current_route_state(
+                Some(ActiveBlock::ShodanServices),
+                Some(ActiveBlock::ShodanServices),
+            );
+        }
         k if common_key_events::down_event(k) => {
             let next_index =
                 common_key_events::on_down_press_handler(&SHODAN_MENU, Some(app.shodan.menu_index));
@@ -0,0 +1,94 @@
+use super::{
+    super::app::{ActiveBlock, App, RouteId},
+    common_key_events, input,
+};
+use crate::{clients::shodan::ShodanSearchIp, event::Key, network::IoEvent};
+
+pub fn handler(key: Key, app: &mut App) {
+    let matches = app.shodan.search_items.matches.clone();
+
+    match key {
+        k if common_key_events::up_event(k) => {
+            let next_index =
+                common_key_events::on_up_press_handler(&matches, Some(app.shodan.search_index));
+            app.shodan.search_index = next_index;
+        }
+        k if common_key_events::down_event(k) => {
+            let next_index =
+                common_key_events::on_down_press_handler(&matches, Some(app.shodan.search_index));
+            app.shodan.search_index = next_index;
+        }
+        k if k == app.user_config.keys.load_more
+            && !app.shodan.search_loading
+            && (matches.len() as i64) < app.shodan.search_items.total =>
+        {
+            let query = app.shodan.search_query.to_owned();
+            app.shodan.search_loading = true;
+            app.dispatch(IoEvent::ShodanSearch(query, app.shodan.search_page + 1));
+        }
+        // Open the regular Shodan host view for the selected match
+        Key::Enter => {
+            if let Some(hit) = matches.get(app.shodan.search_index) {
+                input::search_indicator(app, hit.ip_str.to_owned());
+                // The view fills in once the lookup answers, the previous host mustn't show meanwhile
+                app.shodan.search_ip_items = ShodanSearchIp::default();
+                app.shodan.service_index = 0;
+                app.shodan.menu_index = 0;
+                app.push_navigation_stack(RouteId::Shodan, ActiveBlock::ShodanMenu);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::shodan::ShodanMatch;
+
+    #[test]
+    fn load_more_requests_the_next_page_once() {
+        let mut app = App::default();
+        app.shodan.search_query = String::from("port:22");
+        app.shodan.search_items.matches = vec![ShodanMatch::default(); 100];
+        app.shodan.search_items.total = 250;
+        let load_more = app.user_config.keys.load_more;
+
+        handler(load_more, &mut app);
+        assert!(app.is_loading);
+        assert!(app.shodan.search_loading);
+
+        // A second press before the page arrives must not request page 2 again
+        app.is_loading = false;
+        handler(load_more, &mut app);
+        assert!(!app.is_loading);
+    }
+
+    #[test]
+    fn load_more_stops_once_every_match_is_shown() {
+        let mut app = App::default();
+        app.shodan.search_items.matches = vec![ShodanMatch::default(); 2];
+        app.shodan.search_items.total = 2;
+
+        handler(app.user_config.keys.load_more, &mut app);
+
+        assert!(!app.is_loading);
+        assert!(!app.shodan.search_loading);
+    }
+
+    #[test]
+    fn opening_a_match_drops_the_previous_host() {
+        let mut app = App::default();
+        app.shodan.search_ip_items.ip_str = Some(String::from("192.0.2.1"));
+        app.shodan.search_items.matches = vec![ShodanMatch {
+            ip_str: String::from("198.51.100.7"),
+            ..ShodanMatch::default()
+        }];
+
+        handler(Key::Enter, &mut app);
+
+        assert_eq!(app.query, "198.51.100.7");
+        assert_eq!(app.shodan.search_ip_items, ShodanSearchIp::default());
+        assert_eq!(app.get_current_route().id, RouteId::Shodan);
+    }
+}
@@ -0,0 +1,16 @@
+use super::common_key_events;
+use crate::{app::App, event::Key};
+
+const SMALL_SCROLL: u16 = 1;
+
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        k if common_key_events::down_event(k) => {
+            app.shodan.banner_scroll += SMALL_SCROLL;
+        }
+        k if common_key_events::up_event(k) => {
+            app.shodan.banner_scroll = app.shodan.banner_scroll.saturating_sub(SMALL_SCROLL);
+        }
+        _ => {}
+    };
+}
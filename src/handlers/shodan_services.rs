@@ -0,0 +1,36 @@
+use super::{
+    super::app::{ActiveBlock, App, RouteId},
+    common_key_events,
+};
+use crate::event::Key;
+
+pub fn handler(key: Key, app: &mut App) {
+    let services = app.shodan.search_ip_items.data.clone().unwrap_or_default();
+
+    match key {
+        k if common_key_events::left_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::ShodanMenu),
+                Some(ActiveBlock::ShodanMenu),
+            );
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index =
+                common_key_events::on_up_press_handler(&services, Some(app.shodan.service_index));
+            app.shodan.service_index = next_index;
+        }
+        k if common_key_events::down_event(k) => {
+            let next_index =
+                common_key_events::on_down_press_handler(&services, Some(app.shodan.service_index));
+            app.shodan.service_index = next_index;
+        }
+        Key::Enter if app.shodan.service_index < services.len() => {
+            app.shodan.banner_scroll = 0;
+            app.push_navigation_stack(
+                RouteId::ShodanServiceDetail,
+                ActiveBlock::ShodanServiceDetail,
+            );
+        }
+        _ => {}
+    }
+}
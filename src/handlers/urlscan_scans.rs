@@ -0,0 +1,79 @@
+use super::common_key_events;
+use crate::{app::App, event::Key, indicator::Indicator, network::IoEvent};
+
+pub fn handler(key: Key, app: &mut App) {
+    // Any other key cancels a pending submission
+    if app.urlscan.confirm_submit {
+        app.urlscan.confirm_submit = false;
+        if key == Key::Char('y') {
+            let url = app.urlscan.indicator.to_owned();
+            app.dispatch(IoEvent::UrlscanSubmit(url));
+        }
+        return;
+    }
+
+    let scans = &app.urlscan.search.results;
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index =
+                common_key_events::on_down_press_handler(scans, Some(app.urlscan.scan_index));
+            app.urlscan.scan_index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index =
+                common_key_events::on_up_press_handler(scans, Some(app.urlscan.scan_index));
+            app.urlscan.scan_index = next_index;
+        }
+        Key::Enter => {
+            if let Some(scan) = scans.get(app.urlscan.scan_index) {
+                let uuid = scan.task.uuid.to_owned();
+                app.urlscan.result = None;
+                app.dispatch(IoEvent::UrlscanResult(uuid));
+            }
+        }
+        // Hashes can only be searched for, there is nothing to browse to
+        k if k == app.user_config.keys.submit_scan => {
+            app.urlscan.confirm_submit = !matches!(
+                Indicator::parse(&app.urlscan.indicator),
+                Some(Indicator::Hash(_)) | None
+            );
+        }
+        _ => {}
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn submit_needs_confirmation() {
+        let mut app = App::default();
+        app.urlscan.indicator = String::from("https://evil.example/");
+        let submit_scan = app.user_config.keys.submit_scan;
+
+        handler(submit_scan, &mut app);
+        assert!(app.urlscan.confirm_submit);
+
+        // Any key but `y` cancels
+        handler(Key::Char('n'), &mut app);
+        assert!(!app.urlscan.confirm_submit);
+        assert!(!app.is_loading);
+
+        handler(submit_scan, &mut app);
+        handler(Key::Char('y'), &mut app);
+        assert!(!app.urlscan.confirm_submit);
+        assert!(app.is_loading);
+    }
+
+    #[test]
+    fn hashes_cannot_be_submitted() {
+        let mut app = App::default();
+        app.urlscan.indicator = String::from("44d88612fea8a8f36de82e1278abb02f");
+
+        handler(app.user_config.keys.submit_scan, &mut app);
+
+        assert!(!app.urlscan.confirm_submit);
+    }
+}
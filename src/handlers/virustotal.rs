@@ -1,7 +1,7 @@
 #![allow(clippy::single_match)]
 use super::{
     super::app::{ActiveBlock, App, RouteId, VIRUSTOTAL_MENU},
-    common_key_events,
+    common_key_events, virustotal_comments, virustotal_relationships,
 };
 use crate::event::Key;
 
@@ -29,6 +29,14 @@ pub fn handler(key: Key, app: &mut App) {
                                 Some(ActiveBlock::VirustotalComments),
                                 Some(ActiveBlock::VirustotalComments),
                             );
+                            virustotal_comments::load_comment_author(app);
+                        }
+                        RouteId::VirustotalRelations => {
+                            app.set_current_route_state(
+                                Some(ActiveBlock::VirustotalRelationships),
+                                Some(ActiveBlock::VirustotalRelationships),
+                            );
+                            virustotal_relationships::load_relationship(app);
                         }
                         _ => {}
                     }
@@ -76,7 +84,11 @@ fn switch_view(app: &mut App) {
     match app.virustotal.selected_index {
         0 => app.push_navigation_stack(RouteId::VirustotalDetection, ActiveBlock::VirustotalMenu),
         1 => app.push_navigation_stack(RouteId::VirustotalDetails, ActiveBlock::VirustotalMenu),
-        2 => app.push_navigation_stack(RouteId::VirustotalCommunity, ActiveBlock::VirustotalMenu),
+        2 => {
+            app.push_navigation_stack(RouteId::VirustotalCommunity, ActiveBlock::VirustotalMenu);
+            virustotal_comments::load_comment_author(app);
+        }
+        3 => app.push_navigation_stack(RouteId::VirustotalRelations, ActiveBlock::VirustotalMenu),
         _ => {}
     }
 }
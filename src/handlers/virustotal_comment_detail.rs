@@ -0,0 +1,64 @@
+use super::common_key_events;
+use crate::{app::App, event::Key, ActiveBlock};
+
+const SMALL_SCROLL: u16 = 1;
+
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        k if common_key_events::down_event(k) => {
+            app.virustotal.comment_scroll += SMALL_SCROLL;
+        }
+        k if common_key_events::up_event(k) => {
+            app.virustotal.comment_scroll =
+                app.virustotal.comment_scroll.saturating_sub(SMALL_SCROLL);
+        }
+        k if common_key_events::left_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::VirustotalComments),
+                Some(ActiveBlock::VirustotalComments),
+            );
+        }
+        k if common_key_events::right_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::VirustotalCommentPivots),
+                Some(ActiveBlock::VirustotalCommentPivots),
+            );
+        }
+        _ => {}
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn on_small_down_press() {
+        let mut app = App::default();
+
+        handler(Key::Down, &mut app);
+        assert_eq!(app.virustotal.comment_scroll, SMALL_SCROLL);
+
+        handler(Key::Down, &mut app);
+        assert_eq!(app.virustotal.comment_scroll, SMALL_SCROLL * 2);
+    }
+
+    #[test]
+    fn on_small_up_press() {
+        let mut app = App::default();
+
+        handler(Key::Up, &mut app);
+        assert_eq!(app.virustotal.comment_scroll, 0);
+
+        app.virustotal.comment_scroll = 1;
+
+        handler(Key::Up, &mut app);
+        assert_eq!(app.virustotal.comment_scroll, 0);
+
+        // Check that smashing the up button doesn't go to negative scroll (which would cause a crash)
+        handler(Key::Up, &mut app);
+        handler(Key::Up, &mut app);
+        handler(Key::Up, &mut app);
+        assert_eq!(app.virustotal.comment_scroll, 0);
+    }
+}
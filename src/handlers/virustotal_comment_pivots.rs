@@ -0,0 +1,41 @@
+use super::{common_key_events, input};
+use crate::{app::App, event::Key, ActiveBlock};
+
+pub fn handler(key: Key, app: &mut App) {
+    let pivots = app
+        .virustotal
+        .ip_comment_items
+        .data
+        .get(app.virustotal.comment_index)
+        .map(|comment| comment.indicators())
+        .unwrap_or_default();
+
+    match key {
+        k if common_key_events::down_event(k) => {
+            let next_index = common_key_events::on_down_press_handler(
+                &pivots,
+                Some(app.virustotal.comment_pivot_index),
+            );
+            app.virustotal.comment_pivot_index = next_index;
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index = common_key_events::on_up_press_handler(
+                &pivots,
+                Some(app.virustotal.comment_pivot_index),
+            );
+            app.virustotal.comment_pivot_index = next_index;
+        }
+        k if common_key_events::left_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::VirustotalCommentDetail),
+                Some(ActiveBlock::VirustotalCommentDetail),
+            );
+        }
+        Key::Enter => {
+            if let Some(indicator) = pivots.get(app.virustotal.comment_pivot_index) {
+                input::search_indicator(app, indicator.to_string());
+            }
+        }
+        _ => {}
+    };
+}
@@ -1,63 +1,167 @@
 use super::common_key_events;
-use crate::{app::App, event::Key, ActiveBlock, RouteId};
-
-const SMALL_SCROLL: u16 = 1;
+use crate::{app::App, event::Key, network::IoEvent, ActiveBlock, RouteId};
 
 pub fn handler(key: Key, app: &mut App) {
+    let comments = app.virustotal.ip_comment_items.data.clone();
+
     match key {
         k if common_key_events::down_event(k) => {
-            app.virustotal.comment_scroll += SMALL_SCROLL;
+            let next_index = common_key_events::on_down_press_handler(
+                &comments,
+                Some(app.virustotal.comment_index),
+            );
+            select_comment(app, next_index);
         }
         k if common_key_events::up_event(k) => {
-            if app.virustotal.comment_scroll > 0 {
-                app.virustotal.comment_scroll -= SMALL_SCROLL;
-            }
+            let next_index = common_key_events::on_up_press_handler(
+                &comments,
+                Some(app.virustotal.comment_index),
+            );
+            select_comment(app, next_index);
+        }
+        k if common_key_events::left_event(k)
+            && app.get_current_route().hovered_block == ActiveBlock::VirustotalComments
+            && app.get_current_route().id == RouteId::VirustotalCommunity =>
+        {
+            app.set_current_route_state(
+                Some(ActiveBlock::VirustotalMenu),
+                Some(ActiveBlock::VirustotalMenu),
+            );
         }
-        k if common_key_events::left_event(k) => {
-            if app.get_current_route().hovered_block == ActiveBlock::VirustotalComments
-                && app.get_current_route().id == RouteId::VirustotalCommunity
-            {
-                app.set_current_route_state(
-                    Some(ActiveBlock::VirustotalMenu),
-                    Some(ActiveBlock::VirustotalMenu),
-                );
-            };
+        k if common_key_events::right_event(k) || k == Key::Enter => {
+            app.set_current_route_state(
+                Some(ActiveBlock::VirustotalCommentDetail),
+                Some(ActiveBlock::VirustotalCommentDetail),
+            );
+        }
+        // The cursor only moves once the page arrives, ignore presses until then
+        k if k == app.user_config.keys.load_more && !app.virustotal.comments_loading => {
+            if let Some(cursor) = app.virustotal.ip_comment_items.next_cursor() {
+                let indicator = app.virustotal.indicator.to_owned();
+                app.virustotal.comments_loading = true;
+                app.dispatch(IoEvent::VirustotalComments(indicator, Some(cursor)));
+            }
         }
         _ => {}
     };
 }
 
+fn select_comment(app: &mut App, index: usize) {
+    app.virustotal.comment_index = index;
+    app.virustotal.comment_scroll = 0;
+    app.virustotal.comment_pivot_index = 0;
+    load_comment_author(app);
+}
+
+// Fetch the author of the selected comment only, every lookup counts against the VirusTotal quota
+pub fn load_comment_author(app: &mut App) {
+    let id = match app
+        .virustotal
+        .ip_comment_items
+        .data
+        .get(app.virustotal.comment_index)
+    {
+        Some(comment) if !comment.id.is_empty() => comment.id.to_owned(),
+        _ => return,
+    };
+
+    if app.virustotal.comment_authors.contains_key(&id)
+        || app.virustotal.comment_authors_loading.contains(&id)
+    {
+        return;
+    }
+
+    app.virustotal.comment_author_errors.remove(&id);
+    app.virustotal.comment_authors_loading.insert(id.to_owned());
+    app.dispatch(IoEvent::VirustotalCommentAuthor(id));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clients::virustotal::{IpCommentData, RelationshipMeta};
+    use std::collections::HashSet;
 
     #[test]
-    fn on_small_down_press() {
+    fn on_down_press_selects_next_comment() {
         let mut app = App::default();
+        app.virustotal.ip_comment_items.data = vec![IpCommentData::default(); 2];
+        app.virustotal.comment_scroll = 3;
 
         handler(Key::Down, &mut app);
-        assert_eq!(app.virustotal.comment_scroll, SMALL_SCROLL);
+        assert_eq!(app.virustotal.comment_index, 1);
+        assert_eq!(app.virustotal.comment_scroll, 0);
 
+        // Selection wraps back to the first comment
         handler(Key::Down, &mut app);
-        assert_eq!(app.virustotal.comment_scroll, SMALL_SCROLL * 2);
+        assert_eq!(app.virustotal.comment_index, 0);
     }
 
     #[test]
-    fn on_small_up_press() {
+    fn load_more_waits_for_the_pending_page() {
         let mut app = App::default();
+        app.virustotal.indicator = String::from("8.8.8.8");
+        app.virustotal.ip_comment_items.meta = Some(RelationshipMeta {
+            cursor: Some(String::from("next")),
+            count: None,
+        });
+        let load_more = app.user_config.keys.load_more;
 
-        handler(Key::Up, &mut app);
-        assert_eq!(app.virustotal.comment_scroll, 0);
+        handler(load_more, &mut app);
+        assert!(app.virustotal.comments_loading);
 
-        app.virustotal.comment_scroll = 1;
+        // A second press before the page arrives must not request the same cursor again
+        app.is_loading = false;
+        handler(load_more, &mut app);
+        assert!(!app.is_loading);
+    }
 
-        handler(Key::Up, &mut app);
-        assert_eq!(app.virustotal.comment_scroll, 0);
+    #[test]
+    fn only_the_selected_comment_author_is_requested() {
+        let mut app = App::default();
+        let comment = |id: &str| IpCommentData {
+            id: String::from(id),
+            ..Default::default()
+        };
+        app.virustotal.ip_comment_items.data = vec![comment("i-1"), comment("i-2"), comment("")];
 
-        // Check that smashing the up button doesn't go to negative scroll (which would cause a crash)
-        handler(Key::Up, &mut app);
-        handler(Key::Up, &mut app);
-        handler(Key::Up, &mut app);
-        assert_eq!(app.virustotal.comment_scroll, 0);
+        load_comment_author(&mut app);
+        assert!(app.is_loading);
+        assert_eq!(
+            app.virustotal.comment_authors_loading,
+            HashSet::from([String::from("i-1")])
+        );
+
+        // Revisiting the tab while the author is on its way doesn't request it again
+        app.is_loading = false;
+        load_comment_author(&mut app);
+        assert!(!app.is_loading);
+
+        handler(Key::Down, &mut app);
+        assert!(app.is_loading);
+        assert!(app.virustotal.comment_authors_loading.contains("i-2"));
+
+        // Comments without an id have no author to look up
+        app.is_loading = false;
+        handler(Key::Down, &mut app);
+        assert!(!app.is_loading);
+        assert_eq!(app.virustotal.comment_authors_loading.len(), 2);
+    }
+
+    #[test]
+    fn failed_authors_are_retried_on_selection() {
+        let mut app = App::default();
+        app.virustotal.ip_comment_items.data = vec![IpCommentData {
+            id: String::from("i-1"),
+            ..Default::default()
+        }];
+        app.virustotal
+            .comment_author_errors
+            .insert(String::from("i-1"), String::from("Could not load author"));
+
+        load_comment_author(&mut app);
+        assert!(app.is_loading);
+        assert!(app.virustotal.comment_author_errors.is_empty());
+        assert!(app.virustotal.comment_authors_loading.contains("i-1"));
     }
 }
@@ -0,0 +1,67 @@
+use super::{
+    super::app::{ActiveBlock, App},
+    censys_certificate, common_key_events, input,
+};
+use crate::{event::Key, network::IoEvent};
+
+pub fn handler(key: Key, app: &mut App) {
+    let relationship = app.virustotal_relationship();
+    let page = app
+        .virustotal
+        .relationships
+        .get(relationship)
+        .cloned()
+        .unwrap_or_default();
+
+    match key {
+        k if common_key_events::left_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::VirustotalRelationships),
+                Some(ActiveBlock::VirustotalRelationships),
+            );
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index = common_key_events::on_up_press_handler(
+                &page.data,
+                Some(app.virustotal.relation_item_index),
+            );
+            app.virustotal.relation_item_index = next_index;
+        }
+        k if common_key_events::down_event(k) => {
+            let next_index = common_key_events::on_down_press_handler(
+                &page.data,
+                Some(app.virustotal.relation_item_index),
+            );
+            app.virustotal.relation_item_index = next_index;
+        }
+        // The cursor only moves once the page arrives, ignore presses until then
+        k if k == app.user_config.keys.load_more
+            && !app.virustotal.relationships_loading.contains(relationship) =>
+        {
+            if let Some(cursor) = page.next_cursor() {
+                let indicator = app.virustotal.indicator.to_owned();
+                app.virustotal.relationship_errors.remove(relationship);
+                app.virustotal
+                    .relationships_loading
+                    .insert(relationship.to_owned());
+                app.dispatch(IoEvent::VirustotalRelationship(
+                    indicator,
+                    relationship.to_string(),
+                    Some(cursor),
+                ));
+            }
+        }
+        Key::Enter => {
+            if let Some(item) = page.data.get(app.virustotal.relation_item_index) {
+                let target = item.pivot(&app.virustotal.indicator);
+                // Certificates aren't a searchable indicator, show the hosts presenting them instead
+                if item.kind == "ssl_cert" {
+                    censys_certificate::open_certificate(app, target);
+                } else if !target.is_empty() {
+                    input::search_indicator(app, target);
+                }
+            }
+        }
+        _ => {}
+    }
+}
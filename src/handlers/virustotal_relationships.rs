@@ -0,0 +1,71 @@
+use super::{
+    super::app::{ActiveBlock, App},
+    common_key_events,
+};
+use crate::{clients::virustotal::Relationship, event::Key, network::IoEvent};
+
+pub fn handler(key: Key, app: &mut App) {
+    let relationships = app.virustotal_relationships();
+
+    match key {
+        k if common_key_events::left_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::VirustotalMenu),
+                Some(ActiveBlock::VirustotalMenu),
+            );
+        }
+        k if common_key_events::right_event(k) => {
+            app.set_current_route_state(
+                Some(ActiveBlock::VirustotalRelationItems),
+                Some(ActiveBlock::VirustotalRelationItems),
+            );
+        }
+        k if common_key_events::up_event(k) => {
+            let next_index = common_key_events::on_up_press_handler(
+                relationships,
+                Some(app.virustotal.relationship_index),
+            );
+            app.virustotal.relationship_index = next_index;
+            app.virustotal.relation_item_index = 0;
+            load_relationship(app);
+        }
+        k if common_key_events::down_event(k) => {
+            let next_index = common_key_events::on_down_press_handler(
+                relationships,
+                Some(app.virustotal.relationship_index),
+            );
+            app.virustotal.relationship_index = next_index;
+            app.virustotal.relation_item_index = 0;
+            load_relationship(app);
+        }
+        _ => {}
+    }
+}
+
+// Fetch the first page of the selected relationship unless it has already been requested
+pub fn load_relationship(app: &mut App) {
+    let relationship = app.virustotal_relationship();
+    request_relationship(app, relationship);
+}
+
+pub fn request_relationship(app: &mut App, relationship: &str) {
+    if app.virustotal.indicator.is_empty()
+        || !app.virustotal_relationships().contains(&relationship)
+        || app.virustotal.relationships.contains_key(relationship)
+    {
+        return;
+    }
+
+    // An empty placeholder stops repeated key presses from dispatching duplicate requests, it is
+    // removed again if the lookup fails so that selecting the relationship retries it
+    app.virustotal.relationship_errors.remove(relationship);
+    app.virustotal
+        .relationships
+        .insert(relationship.to_owned(), Relationship::default());
+    let indicator = app.virustotal.indicator.to_owned();
+    app.dispatch(IoEvent::VirustotalRelationship(
+        indicator,
+        relationship.to_owned(),
+        None,
+    ));
+}
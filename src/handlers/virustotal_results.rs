@@ -12,9 +12,8 @@ pub fn handler(key: Key, app: &mut App) {
         .data
         .attributes
         .last_analysis_results
-        .clone()
-        .into_iter()
-        .map(|(_id, score)| score.engine_name)
+        .values()
+        .map(|score| score.engine_name.clone())
         .collect();
 
     if common_key_events::right_event(key) {
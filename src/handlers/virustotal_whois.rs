@@ -11,28 +11,42 @@ pub fn handler(key: Key, app: &mut App) {
         None => "No Whois data found.".to_string(),
     };
 
-    let results = results.split('\n').collect::<Vec<&str>>();
+    let results = match app.rdap.show_raw {
+        true => results
+            .split('\n')
+            .map(String::from)
+            .collect::<Vec<String>>(),
+        false => app
+            .rdap
+            .network
+            .summary_to_vec()
+            .into_iter()
+            .map(|row| row.join(" "))
+            .collect(),
+    };
 
     match key {
-        k if common_key_events::right_event(k) => {
-            if app.get_current_route().hovered_block == ActiveBlock::VirustotalMenu
-                && app.get_current_route().id == RouteId::VirustotalDetails
-            {
-                app.set_current_route_state(
-                    Some(ActiveBlock::VirustotalWhois),
-                    Some(ActiveBlock::VirustotalWhois),
-                );
-            };
+        _ if key == app.user_config.keys.toggle_raw => {
+            app.rdap.show_raw = !app.rdap.show_raw;
+            app.virustotal.whois_result_index = 0;
+        }
+        k if common_key_events::right_event(k)
+            && app.get_current_route().hovered_block == ActiveBlock::VirustotalMenu
+            && app.get_current_route().id == RouteId::VirustotalDetails =>
+        {
+            app.set_current_route_state(
+                Some(ActiveBlock::VirustotalWhois),
+                Some(ActiveBlock::VirustotalWhois),
+            );
         }
-        k if common_key_events::left_event(k) => {
-            if app.get_current_route().hovered_block == ActiveBlock::VirustotalWhois
-                && app.get_current_route().id == RouteId::VirustotalDetails
-            {
-                app.set_current_route_state(
-                    Some(ActiveBlock::VirustotalMenu),
-                    Some(ActiveBlock::VirustotalMenu),
-                );
-            };
+        k if common_key_events::left_event(k)
+            && app.get_current_route().hovered_block == ActiveBlock::VirustotalWhois
+            && app.get_current_route().id == RouteId::VirustotalDetails =>
+        {
+            app.set_current_route_state(
+                Some(ActiveBlock::VirustotalMenu),
+                Some(ActiveBlock::VirustotalMenu),
+            );
         }
         k if common_key_events::up_event(k) => {
             let next_index = common_key_events::on_up_press_handler(
@@ -0,0 +1,373 @@
+use crate::clients::asn::parse_asn;
+use ipnet::IpNet;
+use std::{fmt, net::IpAddr, str::FromStr};
+
+/// A value that can be looked up, classified from free form user input
+#[derive(Debug, Clone, PartialEq)]
+pub enum Indicator {
+    Ip(IpAddr),
+    Domain(String),
+    Url(String),
+    Hash(String),
+    Email(String),
+    Asn(u32),
+    // Always stored with its host bits cleared, e.g. `203.0.113.0/24`
+    Cidr(IpNet),
+}
+
+impl Indicator {
+    pub fn parse(input: &str) -> Option<Indicator> {
+        let input = input.trim();
+
+        if let Ok(ip) = IpAddr::from_str(input) {
+            return Some(Indicator::Ip(ip));
+        }
+
+        if let Ok(network) = IpNet::from_str(input) {
+            return Some(Indicator::Cidr(network.trunc()));
+        }
+
+        // Bare numbers are too ambiguous, ASNs need their `AS` prefix
+        if input
+            .get(..2)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("as"))
+        {
+            if let Some(asn) = parse_asn(input) {
+                return Some(Indicator::Asn(asn));
+            }
+        }
+
+        if is_hash(input) {
+            return Some(Indicator::Hash(input.to_lowercase()));
+        }
+
+        if is_url(input) {
+            return Some(Indicator::Url(input.to_owned()));
+        }
+
+        if is_email(input) {
+            return Some(Indicator::Email(input.to_lowercase()));
+        }
+
+        if is_domain(input) {
+            return Some(Indicator::Domain(
+                input.trim_end_matches('.').to_lowercase(),
+            ));
+        }
+
+        None
+    }
+
+    pub fn is_ip(&self) -> bool {
+        matches!(self, Indicator::Ip(_))
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Indicator::Ip(_) => "IP",
+            Indicator::Domain(_) => "Domain",
+            Indicator::Url(_) => "URL",
+            Indicator::Hash(_) => "Hash",
+            Indicator::Email(_) => "Email",
+            Indicator::Asn(_) => "ASN",
+            Indicator::Cidr(_) => "CIDR",
+        }
+    }
+
+    /// Private, loopback, link-local, documentation and other addresses that never route on
+    /// the internet
+    pub fn is_reserved(&self) -> bool {
+        let ip = match self {
+            Indicator::Ip(ip) => *ip,
+            Indicator::Cidr(network) => network.network(),
+            _ => return false,
+        };
+
+        match ip {
+            IpAddr::V4(ip) => {
+                let shared = ip.octets()[0] == 100 && (ip.octets()[1] & 0xc0) == 64;
+                let benchmarking = ip.octets()[0] == 198 && (ip.octets()[1] & 0xfe) == 18;
+                ip.is_private()
+                    || ip.is_loopback()
+                    || ip.is_link_local()
+                    || ip.is_broadcast()
+                    || ip.is_documentation()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    || ip.octets()[0] == 0
+                    || ip.octets()[0] >= 240
+                    || shared
+                    || benchmarking
+            }
+            IpAddr::V6(ip) => {
+                let documentation = ip.segments()[0] == 0x2001 && ip.segments()[1] == 0xdb8;
+                ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    || ip.is_unique_local()
+                    || ip.is_unicast_link_local()
+                    || documentation
+                    || ip
+                        .to_ipv4_mapped()
+                        .is_some_and(|ip| Indicator::Ip(IpAddr::V4(ip)).is_reserved())
+            }
+        }
+    }
+}
+
+impl fmt::Display for Indicator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Indicator::Ip(ip) => write!(f, "{}", ip),
+            Indicator::Asn(asn) => write!(f, "AS{}", asn),
+            Indicator::Cidr(network) => write!(f, "{}", network),
+            Indicator::Domain(value)
+            | Indicator::Url(value)
+            | Indicator::Hash(value)
+            | Indicator::Email(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// Every distinct indicator mentioned in free form text, in order of first appearance
+pub fn extract(text: &str) -> Vec<Indicator> {
+    let mut indicators: Vec<Indicator> = Vec::new();
+
+    for token in refang(text).split(|c: char| c.is_whitespace() || ",;\"'()<>{}|".contains(c)) {
+        if let Some(indicator) = parse_token(token) {
+            if !indicators.contains(&indicator) {
+                indicators.push(indicator);
+            }
+        }
+    }
+
+    indicators
+}
+
+// Log lines put field names and ports next to indicators, as in `src=1.2.3.4`, `ip:1.2.3.4`,
+// `1.2.3.4:443` or `[2001:db8::1]:443`
+fn parse_token(token: &str) -> Option<Indicator> {
+    // Sentence punctuation ends up stuck to the last indicator of a sentence. Colons also end
+    // compressed IPv6 addresses like `2606:4700::`, so they are only trimmed when that's needed
+    let token = token.trim_matches(|c: char| ".!?[]".contains(c));
+    if let Some(indicator) = Indicator::parse(token) {
+        return Some(indicator);
+    }
+
+    let token = token.trim_matches(|c: char| ".:!?[]".contains(c));
+    if let Some(indicator) = Indicator::parse(token) {
+        return Some(indicator);
+    }
+
+    if let Some((host, _)) = token.rsplit_once("]:") {
+        return Indicator::parse(host);
+    }
+
+    if let Some((host, port)) = token.rsplit_once(':') {
+        if port.parse::<u16>().is_ok() {
+            if let Some(indicator) = Indicator::parse(host) {
+                return Some(indicator);
+            }
+        }
+    }
+
+    let (_, value) = token.split_once(['=', ':'])?;
+    parse_token(value)
+}
+
+/// Indicators in `text` worth looking up, and how many were skipped for being reserved
+/// addresses or for `is_ours`
+pub fn triage(text: &str, is_ours: impl Fn(&Indicator) -> bool) -> (Vec<Indicator>, usize) {
+    let (skipped, indicators): (Vec<Indicator>, Vec<Indicator>) = extract(text)
+        .into_iter()
+        .partition(|indicator| indicator.is_reserved() || is_ours(indicator));
+
+    (indicators, skipped.len())
+}
+
+/// Undo the common ways of defanging indicators, e.g. `hxxp://example[.]com`
+pub fn refang(text: &str) -> String {
+    text.replace("[.]", ".")
+        .replace("(.)", ".")
+        .replace("[dot]", ".")
+        .replace("[:]", ":")
+        .replace("[@]", "@")
+        .replace("[at]", "@")
+        .replace("hxxp", "http")
+        .replace("hXXp", "http")
+}
+
+// File names in alerts look like domains, these extensions are not TLDs. Extensions that are,
+// such as `zip`, `mov`, `py` or `sh`, can't be told apart and are left to be looked up
+const FILE_EXTENSIONS: &[&str] = &[
+    "bat", "bin", "cfg", "cmd", "csv", "dat", "dll", "doc", "docm", "docx", "exe", "gif", "gz",
+    "hta", "htm", "html", "ini", "iso", "jar", "jpeg", "jpg", "js", "json", "lnk", "log", "msi",
+    "pdf", "php", "png", "ppt", "pptx", "rar", "scr", "sys", "tmp", "txt", "vbs", "xls", "xlsm",
+    "xlsx", "xml", "yaml", "yml",
+];
+
+// MD5, SHA-1 and SHA-256 digests
+fn is_hash(input: &str) -> bool {
+    matches!(input.len(), 32 | 40 | 64) && input.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn is_url(input: &str) -> bool {
+    let lower = input.to_lowercase();
+    (lower.starts_with("http://") || lower.starts_with("https://"))
+        && !input.chars().any(char::is_whitespace)
+}
+
+fn is_email(input: &str) -> bool {
+    match input.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && local
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "._%+-".contains(c))
+                && is_domain(domain)
+        }
+        None => false,
+    }
+}
+
+fn is_domain(input: &str) -> bool {
+    let input = input.trim_end_matches('.');
+    let labels = input.split('.').collect::<Vec<&str>>();
+
+    if labels.len() < 2 || input.len() > 253 {
+        return false;
+    }
+
+    let valid_label = |label: &&str| {
+        !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    };
+
+    // The TLD is never numeric, which also keeps out malformed IPv4 addresses
+    let tld = labels[labels.len() - 1];
+    labels.iter().all(valid_label)
+        && tld.len() >= 2
+        && tld.chars().all(|c| c.is_ascii_alphabetic())
+        && !FILE_EXTENSIONS.contains(&tld.to_lowercase().as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_indicators() {
+        assert!(matches!(
+            Indicator::parse("8.8.8.8"),
+            Some(Indicator::Ip(_))
+        ));
+        assert!(matches!(
+            Indicator::parse("2001:db8::1"),
+            Some(Indicator::Ip(_))
+        ));
+        assert_eq!(
+            Indicator::parse("Example.COM"),
+            Some(Indicator::Domain(String::from("example.com")))
+        );
+        assert_eq!(
+            Indicator::parse("https://example.com/a?b=c"),
+            Some(Indicator::Url(String::from("https://example.com/a?b=c")))
+        );
+        assert_eq!(
+            Indicator::parse("44D88612FEA8A8F36DE82E1278ABB02F"),
+            Some(Indicator::Hash(String::from(
+                "44d88612fea8a8f36de82e1278abb02f"
+            )))
+        );
+        assert_eq!(Indicator::parse("as13335"), Some(Indicator::Asn(13335)));
+        assert_eq!(
+            Indicator::parse("203.0.113.7/24"),
+            Some(Indicator::Cidr("203.0.113.0/24".parse().unwrap()))
+        );
+    }
+
+    #[test]
+    fn extract_from_text() {
+        let text = "Seen beaconing to 1.2.3[.]4 and hxxps://bad.example.com/gate.php, \
+            dropping 44d88612fea8a8f36de82e1278abb02f. See also bad.example.com. \
+            Sent by Billing[at]bad.example.com from 10.0.0.12";
+
+        assert_eq!(
+            extract(text),
+            vec![
+                Indicator::Ip("1.2.3.4".parse().unwrap()),
+                Indicator::Url(String::from("https://bad.example.com/gate.php")),
+                Indicator::Hash(String::from("44d88612fea8a8f36de82e1278abb02f")),
+                Indicator::Domain(String::from("bad.example.com")),
+                Indicator::Email(String::from("billing@bad.example.com")),
+                Indicator::Ip("10.0.0.12".parse().unwrap()),
+            ]
+        );
+
+        let (indicators, skipped) =
+            triage(text, |indicator| matches!(indicator, Indicator::Hash(_)));
+        assert_eq!(indicators.len(), 4);
+        assert_eq!(skipped, 2);
+    }
+
+    #[test]
+    fn extract_from_log_fields() {
+        let text = "src=198.51.100.7:51234 dst=203.0.113.9:443 ip:192.0.2.1 \
+            host=evil.example:8080 [2001:db8::1]:443 url=https://evil.example/?id=1";
+
+        assert_eq!(
+            extract(text),
+            vec![
+                Indicator::Ip("198.51.100.7".parse().unwrap()),
+                Indicator::Ip("203.0.113.9".parse().unwrap()),
+                Indicator::Ip("192.0.2.1".parse().unwrap()),
+                Indicator::Domain(String::from("evil.example")),
+                Indicator::Ip("2001:db8::1".parse().unwrap()),
+                Indicator::Url(String::from("https://evil.example/?id=1")),
+            ]
+        );
+    }
+
+    #[test]
+    fn compressed_ipv6_keeps_its_colons() {
+        let text = "Announced 2606:4700:: and 2001:db8::. Reached from evil.example: twice";
+
+        assert_eq!(
+            extract(text),
+            vec![
+                Indicator::Ip("2606:4700::".parse().unwrap()),
+                Indicator::Ip("2001:db8::".parse().unwrap()),
+                Indicator::Domain(String::from("evil.example")),
+            ]
+        );
+    }
+
+    #[test]
+    fn file_names_are_not_domains() {
+        let text = "Dropped invoice.exe and report.PDF, wrote config.json to evil.example";
+
+        assert_eq!(
+            extract(text),
+            vec![Indicator::Domain(String::from("evil.example"))]
+        );
+        assert_eq!(Indicator::parse("payload.dll"), None);
+        assert!(Indicator::parse("archive.zip").is_some());
+    }
+
+    #[test]
+    fn reject_invalid_input() {
+        assert_eq!(Indicator::parse(""), None);
+        assert_eq!(Indicator::parse("not an indicator"), None);
+        assert_eq!(Indicator::parse("256.1.1.1"), None);
+        assert_eq!(Indicator::parse("localhost"), None);
+        assert_eq!(Indicator::parse("-bad-.com"), None);
+        assert_eq!(Indicator::parse("13335"), None);
+        assert_eq!(Indicator::parse("AS"), None);
+        assert_eq!(Indicator::parse("10.0.0.0/33"), None);
+    }
+}
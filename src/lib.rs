@@ -3,6 +3,10 @@ pub mod banner;
 pub mod clients;
 pub mod config;
 pub mod event;
+pub mod export;
+pub mod indicator;
 pub mod network;
+pub mod scoring;
 pub mod ui;
 pub mod user_config;
+pub mod watchlist;
@@ -1,12 +1,5 @@
-mod app;
-mod banner;
-mod clients;
-mod config;
-mod event;
+mod cli;
 mod handlers;
-mod network;
-mod ui;
-mod user_config;
 
 use backtrace::Backtrace;
 use crossterm::{
@@ -17,20 +10,25 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode, LeaveAlternateScreen},
     ExecutableCommand,
 };
-use std::io;
+use std::io::{self, IsTerminal};
 use std::{
-    panic::{self, PanicInfo},
+    panic::{self, PanicHookInfo},
     sync::Arc,
 };
 use tokio::sync::Mutex;
 use tui::{backend::CrosstermBackend, Terminal};
 
-use crate::event::Key;
-use app::{ActiveBlock, App, RouteId};
-use clients::{censys, shodan, virustotal};
-use config::Config;
-use network::{IoEvent, Network};
-use user_config::UserConfig;
+use osintui::{
+    app::{self, ActiveBlock, App, RouteId},
+    clients,
+    config::Config,
+    event::{self, Key},
+    export, indicator,
+    network::{self, IoEvent, Network},
+    ui,
+    user_config::UserConfig,
+    watchlist::Watchlist,
+};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -42,25 +40,37 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut client_config = Config::new();
     client_config.load_config()?;
 
-    let censys = censys::Client::new(
-        client_config.keys.censys_id.clone(),
-        client_config.keys.censys_secret.clone(),
-    );
-    let shodan = shodan::Client::new(client_config.keys.shodan.clone());
-    let virustotal = virustotal::Client::new(client_config.keys.virustotal.clone());
+    let watchlist = Watchlist::load(&client_config.get_or_build_paths()?.watchlists_dir)?;
+
+    // Extracted indicators are picked from in the TUI when it can be shown, and printed otherwise
+    let mut pasted = None;
+    let args = std::env::args().skip(1).collect::<Vec<String>>();
+    match args.split_first() {
+        Some((command, _)) if command == "extract" && io::stdout().is_terminal() => {
+            pasted = Some(cli::read_stdin()?);
+        }
+        Some((command, args)) => {
+            return cli::run(command, args, &client_config, &watchlist).await;
+        }
+        None => {}
+    }
+
+    let clients = clients::Clients::new(&client_config)?;
 
     let (sync_io_tx, sync_io_rx) = std::sync::mpsc::channel::<IoEvent>();
 
     // Initialise app state
-    let app = Arc::new(Mutex::new(App::new(
-        sync_io_tx,
-        user_config.clone(),
-        client_config.clone(),
-    )));
+    let mut app = App::new(sync_io_tx, user_config.clone(), client_config.clone());
+    app.watchlist = watchlist;
+    if let Some(text) = pasted {
+        app.extract.text = text;
+        app.extract_indicators();
+    }
+    let app = Arc::new(Mutex::new(app));
 
     let cloned_app = Arc::clone(&app);
     std::thread::spawn(move || {
-        let mut network = Network::new(censys, shodan, virustotal, client_config, &app);
+        let mut network = Network::new(clients, client_config, &app);
         start_tokio(sync_io_rx, &mut network);
     });
     start_ui(&cloned_app).await?;
@@ -123,6 +133,8 @@ async fn start_ui(app: &Arc<Mutex<App>>) -> Result<(), Box<dyn std::error::Error
                 // case for the input handler
                 if current_active_block == ActiveBlock::Input {
                     handlers::input_handler(key, &mut app);
+                } else if current_active_block == ActiveBlock::ExtractInput {
+                    handlers::extract_input_handler(key, &mut app);
                 } else if key == app.user_config.keys.back {
                     if app.get_current_route().active_block != ActiveBlock::Input {
                         // Go back through navigation stack when not in search input mode and
@@ -159,7 +171,7 @@ async fn start_tokio(io_rx: std::sync::mpsc::Receiver<IoEvent>, network: &mut Ne
     }
 }
 
-fn panic_hook(info: &PanicInfo<'_>) {
+fn panic_hook(info: &PanicHookInfo<'_>) {
     if cfg!(debug_assertions) {
         let location = info.location().unwrap();
 
@@ -1,42 +1,80 @@
-use crate::app::{App, ResultStatus};
-use crate::clients::{censys, shodan, virustotal};
+use crate::app::{App, BulkItem, ResultStatus};
+use crate::clients::otx::{OtxIndicator, PulseIndicators};
+use crate::clients::{
+    asn::AsnContext,
+    crtsh,
+    securitytrails::WhoisHistory,
+    threatfox::ThreatFoxSearch,
+    urlhaus::UrlhausLookup,
+    urlscan::{ScanTask, UrlscanScan},
+    Clients,
+};
 use crate::config::Config;
+use crate::indicator::Indicator;
 use anyhow::anyhow;
+use futures::StreamExt;
+use ipnet::IpNet;
 use reqwest::StatusCode;
+use std::net::IpAddr;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 
+// Bulk queue entries looked up at the same time
+const BULK_CONCURRENCY: usize = 10;
+
+// VirusTotal's public API allows four lookups a minute
+const BULK_VIRUSTOTAL_INTERVAL: Duration = Duration::from_secs(15);
+
+// Scans usually finish within a minute, a submission still without a result after this failed
+const URLSCAN_SCAN_TIMEOUT: Duration = Duration::from_secs(120);
+
 #[derive(Debug)]
 #[allow(dead_code)]
 pub enum IoEvent {
     Censys(String),
+    CensysHistory(String),
+    CensysCertificate(String),
+    CensysSearch(String, Option<String>),
     VirusTotal(String),
-    VirustotalComments(String),
+    VirustotalComments(String, Option<String>),
     VirustotalCommentAuthor(String),
+    VirustotalRelationship(String, String, Option<String>),
     Shodan(String),
+    ShodanSearch(String, u32),
+    Rdap(String),
+    Dns(String),
+    GeoIp(String),
+    ListTags(String),
+    Abuseipdb(String),
+    AbuseipdbReports(String, u32),
+    Greynoise(String),
+    Otx(String),
+    OtxPulseIndicators(String),
+    Abusech(String),
+    Crtsh(String),
+    BulkLookup,
+    SecurityTrails(String),
+    Urlscan(String),
+    UrlscanResult(String),
+    UrlscanSubmit(String),
+    Asn(u32, Option<IpAddr>),
+    AsnHostCounts(u32),
+    BulkNetwork(IpNet),
+    BulkVerdicts(IpNet),
 }
 
 #[derive(Clone)]
 pub struct Network<'a> {
-    pub censys_client: censys::Client,
-    pub shodan_client: shodan::Client,
-    pub vt_client: virustotal::Client,
+    pub clients: Clients,
     pub client_config: Config,
     pub app: &'a Arc<Mutex<App>>,
 }
 
 impl<'a> Network<'a> {
-    pub fn new(
-        censys_client: censys::Client,
-        shodan_client: shodan::Client,
-        vt_client: virustotal::Client,
-        client_config: Config,
-        app: &'a Arc<Mutex<App>>,
-    ) -> Self {
+    pub fn new(clients: Clients, client_config: Config, app: &'a Arc<Mutex<App>>) -> Self {
         Network {
-            censys_client,
-            shodan_client,
-            vt_client,
+            clients,
             client_config,
             app,
         }
@@ -47,100 +85,1150 @@ impl<'a> Network<'a> {
             IoEvent::Censys(query) => {
                 self.censys_search_ip(query).await;
             }
+            IoEvent::CensysHistory(query) => {
+                self.censys_host_events(query).await;
+            }
+            IoEvent::CensysCertificate(fingerprint) => {
+                self.censys_view_certificate(fingerprint).await;
+            }
+            IoEvent::CensysSearch(query, cursor) => {
+                self.censys_search_hosts(query, cursor).await;
+            }
             IoEvent::Shodan(query) => {
                 self.shodan_search_ip(query).await;
             }
+            IoEvent::ShodanSearch(query, page) => {
+                self.shodan_search_hosts(query, page).await;
+            }
             IoEvent::VirusTotal(query) => {
                 self.virustotal_get_ip_whois(query).await;
             }
-            IoEvent::VirustotalComments(query) => {
-                self.virustotal_get_ip_comments(query.clone()).await;
+            IoEvent::VirustotalComments(query, cursor) => {
+                self.virustotal_get_ip_comments(query, cursor).await;
+            }
+            IoEvent::VirustotalRelationship(query, relationship, cursor) => {
+                self.virustotal_get_relationship(query, relationship, cursor)
+                    .await;
             }
             IoEvent::VirustotalCommentAuthor(query) => {
                 self.virustotal_get_comment_author(query.clone()).await;
             }
+            IoEvent::Rdap(query) => {
+                self.rdap_lookup_ip(query).await;
+            }
+            IoEvent::Dns(query) => {
+                self.dns_lookup(query).await;
+            }
+            IoEvent::GeoIp(query) => {
+                self.geoip_lookup(query).await;
+            }
+            IoEvent::ListTags(query) => {
+                self.list_tags(query).await;
+            }
+            IoEvent::Abuseipdb(query) => {
+                self.abuseipdb_check(query).await;
+            }
+            IoEvent::AbuseipdbReports(query, page) => {
+                self.abuseipdb_reports(query, page).await;
+            }
+            IoEvent::Greynoise(query) => {
+                self.greynoise_lookup_ip(query).await;
+            }
+            IoEvent::Otx(query) => {
+                self.otx_lookup(query).await;
+            }
+            IoEvent::OtxPulseIndicators(pulse_id) => {
+                self.otx_pulse_indicators(pulse_id).await;
+            }
+            IoEvent::Abusech(query) => {
+                self.abusech_lookup(query).await;
+            }
+            IoEvent::Crtsh(query) => {
+                self.crtsh_certificates(query).await;
+            }
+            IoEvent::BulkLookup => {
+                self.bulk_lookup().await;
+            }
+            IoEvent::SecurityTrails(query) => {
+                self.securitytrails_history(query).await;
+            }
+            IoEvent::Urlscan(query) => {
+                self.urlscan_search(query).await;
+            }
+            IoEvent::UrlscanResult(uuid) => {
+                self.urlscan_result(uuid).await;
+            }
+            IoEvent::UrlscanSubmit(url) => {
+                self.urlscan_submit(url).await;
+            }
+            IoEvent::Asn(asn, address) => {
+                self.asn(asn, address).await;
+            }
+            IoEvent::AsnHostCounts(asn) => {
+                self.asn_host_counts(asn).await;
+            }
+            // Both run beside the queue, a network takes a while to go through
+            IoEvent::BulkNetwork(network) => {
+                if !self.client_config.keys.shodan.is_empty() {
+                    tokio::spawn(bulk_network_ports(
+                        self.clients.clone(),
+                        Arc::clone(self.app),
+                        network,
+                    ));
+                }
+            }
+            IoEvent::BulkVerdicts(network) => {
+                tokio::spawn(bulk_network_verdicts(
+                    self.clients.clone(),
+                    Arc::clone(self.app),
+                    network,
+                ));
+            }
         };
 
         let mut app = self.app.lock().await;
         app.is_loading = false;
     }
 
+    // Lookups for an indicator other than the one searched last answer too late to be shown
+    async fn is_stale(&self, query: &str) -> bool {
+        let app = self.app.lock().await;
+        app.query != query
+    }
+
     async fn handle_error(&mut self, e: anyhow::Error) {
         let mut app = self.app.lock().await;
         app.handle_error(e);
     }
 
+    async fn rdap_lookup_ip(&mut self, ip: String) {
+        let result = self.clients.rdap.lookup_ip(ip.as_str()).await;
+
+        if self.is_stale(&ip).await {
+            return;
+        }
+
+        // RDAP only enriches the whois pane, so a failed lookup must not take over the screen
+        let mut app = self.app.lock().await;
+        match result {
+            Ok(resp) => {
+                app.rdap.network = resp;
+                app.rdap.record_index = 0;
+                app.rdap.status = ResultStatus::Found;
+            }
+            Err(_) => {
+                app.rdap.status = ResultStatus::NotFound;
+            }
+        }
+    }
+
+    async fn abuseipdb_check(&mut self, ip: String) {
+        let result = self.clients.abuseipdb.check(ip.as_str()).await;
+        if self.is_stale(&ip).await {
+            return;
+        }
+
+        match result {
+            Ok(resp) => {
+                let mut app = self.app.lock().await;
+                app.abuseipdb.check = resp;
+                app.abuseipdb.status = ResultStatus::Found
+            }
+            Err(e) => {
+                self.process_error(e, |app| &mut app.abuseipdb.status).await;
+            }
+        }
+    }
+
+    async fn abuseipdb_reports(&mut self, ip: String, page: u32) {
+        let result = self.clients.abuseipdb.reports(ip.as_str(), page).await;
+        if self.is_stale(&ip).await {
+            return;
+        }
+        self.app.lock().await.abuseipdb.reports_loading = false;
+
+        match result {
+            Ok(resp) => {
+                let mut app = self.app.lock().await;
+                if page > 1 {
+                    let reports = &mut app.abuseipdb.reports.data;
+                    reports.results.extend(resp.data.results);
+                    reports.page = resp.data.page;
+                    reports.last_page = resp.data.last_page;
+                } else {
+                    app.abuseipdb.reports = resp;
+                    app.abuseipdb.report_index = 0;
+                }
+            }
+            Err(e) => {
+                self.process_error(e, |app| &mut app.abuseipdb.status).await;
+            }
+        }
+    }
+
+    async fn greynoise_lookup_ip(&mut self, ip: String) {
+        let result = self.clients.greynoise.lookup_ip(ip.as_str()).await;
+        if self.is_stale(&ip).await {
+            return;
+        }
+
+        match result {
+            Ok(resp) => {
+                let mut app = self.app.lock().await;
+                app.greynoise.ip = resp;
+                app.greynoise.status = ResultStatus::Found;
+            }
+            // GreyNoise answers 404 for addresses it has never observed scanning
+            Err(StatusCode::NOT_FOUND) => {
+                let mut app = self.app.lock().await;
+                app.greynoise.status = ResultStatus::NotFound;
+            }
+            Err(e) => {
+                self.process_error(e, |app| &mut app.greynoise.status).await;
+            }
+        }
+    }
+
+    async fn otx_lookup(&mut self, query: String) {
+        let indicator = match Indicator::parse(&query) {
+            Some(indicator) => indicator,
+            None => return,
+        };
+
+        let general = self.clients.otx.general(&indicator).await;
+        if self.is_stale(&query).await {
+            return;
+        }
+
+        let general = match general {
+            Ok(general) => general,
+            Err(StatusCode::NOT_FOUND) => {
+                let mut app = self.app.lock().await;
+                app.otx.status = ResultStatus::NotFound;
+                return;
+            }
+            Err(e) => {
+                self.process_error(e, |app| &mut app.otx.status).await;
+                return;
+            }
+        };
+
+        // Only hosts have reputation, passive DNS, malware and URL sections
+        let mut otx = OtxIndicator {
+            general,
+            ..OtxIndicator::default()
+        };
+        if matches!(indicator, Indicator::Ip(_) | Indicator::Domain(_)) {
+            let client = &self.clients.otx;
+            let (reputation, passive_dns, malware, url_list) = futures::join!(
+                client.reputation(&indicator),
+                client.passive_dns(&indicator),
+                client.malware(&indicator),
+                client.url_list(&indicator)
+            );
+            otx.reputation = reputation.unwrap_or_default();
+            otx.passive_dns = passive_dns.unwrap_or_default();
+            otx.malware = malware.unwrap_or_default();
+            otx.url_list = url_list.unwrap_or_default();
+        }
+
+        let mut app = self.app.lock().await;
+        if app.query != query {
+            return;
+        }
+        app.otx.indicator = otx;
+        app.otx.pulse_index = 0;
+        app.otx.pulse_indicators = PulseIndicators::default();
+        app.otx.pulse_indicator_index = 0;
+        app.otx.status = ResultStatus::Found;
+    }
+
+    async fn otx_pulse_indicators(&mut self, pulse_id: String) {
+        match self.clients.otx.pulse_indicators(pulse_id.as_str()).await {
+            Ok(resp) => {
+                let mut app = self.app.lock().await;
+                app.otx.pulse_indicators = resp;
+                app.otx.pulse_indicator_index = 0;
+            }
+            Err(e) => {
+                self.process_error(e, |app| &mut app.otx.status).await;
+            }
+        }
+    }
+
+    async fn abusech_lookup(&mut self, query: String) {
+        let indicator = match Indicator::parse(&query) {
+            Some(indicator) => indicator,
+            None => return,
+        };
+
+        let (urlhaus, threatfox) = futures::join!(
+            self.clients.urlhaus.lookup(&indicator),
+            self.clients.threatfox.search(&indicator)
+        );
+        if self.is_stale(&query).await {
+            return;
+        }
+
+        // A 404 is an indicator type the database doesn't index, which can't be listed either
+        let (urlhaus, urlhaus_error) = match urlhaus {
+            Ok(urlhaus) => (urlhaus, None),
+            Err(StatusCode::NOT_FOUND) => (UrlhausLookup::default(), None),
+            Err(e) => (UrlhausLookup::default(), Some(abusech_error(e))),
+        };
+        let (threatfox, threatfox_error) = match threatfox {
+            Ok(threatfox) => (threatfox, None),
+            Err(e) => (ThreatFoxSearch::default(), Some(abusech_error(e))),
+        };
+
+        // Failures keep the view reachable so that they can be read there
+        let mut app = self.app.lock().await;
+        app.abusech.status = match urlhaus.is_listed()
+            || !threatfox.data.is_empty()
+            || urlhaus_error.is_some()
+            || threatfox_error.is_some()
+        {
+            true => ResultStatus::Found,
+            false => ResultStatus::NotFound,
+        };
+        app.abusech.urlhaus = urlhaus;
+        app.abusech.threatfox = threatfox;
+        app.abusech.urlhaus_error = urlhaus_error;
+        app.abusech.threatfox_error = threatfox_error;
+        app.abusech.ioc_index = 0;
+        app.abusech.payload_index = 0;
+    }
+
+    async fn crtsh_certificates(&mut self, domain: String) {
+        let result = self.clients.crtsh.certificates(domain.as_str()).await;
+
+        if self.is_stale(&domain).await {
+            return;
+        }
+
+        // crt.sh is keyless and often overloaded, failures stay inside its own view
+        let mut app = self.app.lock().await;
+        app.crtsh.certificate_index = 0;
+        app.crtsh.subdomain_index = 0;
+        app.crtsh.error = None;
+        match result {
+            Ok(certificates) if !certificates.is_empty() => {
+                app.crtsh.subdomains = crtsh::subdomains(&certificates, &domain);
+                app.crtsh.certificates = certificates;
+                app.crtsh.status = ResultStatus::Found;
+            }
+            Ok(_) | Err(StatusCode::NOT_FOUND) => {
+                app.crtsh.certificates.clear();
+                app.crtsh.subdomains.clear();
+                app.crtsh.status = ResultStatus::NotFound;
+            }
+            // Still opened, so the failure isn't mistaken for a domain without certificates
+            Err(e) => {
+                app.crtsh.certificates.clear();
+                app.crtsh.subdomains.clear();
+                app.crtsh.status = ResultStatus::Found;
+                app.crtsh.error = Some(format!("Could not load certificates from crt.sh: {}", e));
+            }
+        }
+        app.crtsh.domain = domain;
+    }
+
+    async fn securitytrails_history(&mut self, query: String) {
+        let client = &self.clients.securitytrails;
+        let result = match Indicator::parse(&query) {
+            Some(Indicator::Ip(ip)) => client
+                .associated_domains(&ip.to_string())
+                .await
+                .map(|domains| (domains.entries(), WhoisHistory::default())),
+            Some(Indicator::Domain(domain)) => {
+                let (history, whois) =
+                    futures::join!(client.a_history(&domain), client.whois_history(&domain));
+                history.map(|history| (history.entries(), whois.unwrap_or_default()))
+            }
+            _ => return,
+        };
+        if self.is_stale(&query).await {
+            return;
+        }
+
+        match result {
+            Ok((entries, whois)) => {
+                let mut app = self.app.lock().await;
+                app.securitytrails.status =
+                    match entries.is_empty() && whois.result.items.is_empty() {
+                        true => ResultStatus::NotFound,
+                        false => ResultStatus::Found,
+                    };
+                app.securitytrails.entries = entries;
+                app.securitytrails.whois = whois;
+                app.securitytrails.entry_index = 0;
+                app.securitytrails.whois_index = 0;
+            }
+            Err(StatusCode::NOT_FOUND) => {
+                let mut app = self.app.lock().await;
+                app.securitytrails.status = ResultStatus::NotFound;
+            }
+            Err(e) => {
+                self.process_error(e, |app| &mut app.securitytrails.status)
+                    .await;
+            }
+        }
+    }
+
+    async fn urlscan_search(&mut self, query: String) {
+        let indicator = match Indicator::parse(&query) {
+            Some(indicator) => indicator,
+            None => return,
+        };
+
+        let result = self.clients.urlscan.search(&indicator).await;
+        if self.is_stale(&query).await {
+            return;
+        }
+
+        match result {
+            Ok(resp) => {
+                let mut app = self.app.lock().await;
+                app.urlscan.status = match resp.results.is_empty() {
+                    true => ResultStatus::NotFound,
+                    false => ResultStatus::Found,
+                };
+                app.urlscan.search = resp;
+                app.urlscan.scan_index = 0;
+                app.urlscan.result = None;
+                app.urlscan.message = None;
+            }
+            Err(e) => {
+                self.process_error(e, |app| &mut app.urlscan.status).await;
+            }
+        }
+    }
+
+    async fn urlscan_result(&mut self, uuid: String) {
+        let result = self.clients.urlscan.result(uuid.as_str()).await;
+
+        let error = {
+            let mut app = self.app.lock().await;
+            // Another scan may have been opened in the meantime
+            let selected = app.urlscan.search.results.get(app.urlscan.scan_index);
+            if selected.map(|scan| scan.task.uuid.as_str()) != Some(uuid.as_str()) {
+                return;
+            }
+
+            match result {
+                Ok(resp) => {
+                    app.urlscan.result = Some(resp);
+                    app.urlscan.message = None;
+                    app.urlscan.submitted.remove(&uuid);
+                    return;
+                }
+                Err(e) => {
+                    let submitted = app.urlscan.submitted.get(&uuid).map(Instant::elapsed);
+                    match missing_scan_message(submitted, e) {
+                        Some(message) => {
+                            app.urlscan.message = Some(String::from(message));
+                            return;
+                        }
+                        None => e,
+                    }
+                }
+            }
+        };
+
+        self.process_error(error, |app| &mut app.urlscan.status)
+            .await;
+    }
+
+    async fn urlscan_submit(&mut self, url: String) {
+        let visibility = self.client_config.urlscan.visibility.to_owned();
+
+        match self.clients.urlscan.submit(url.as_str(), &visibility).await {
+            Ok(resp) => {
+                // The new scan is listed right away so its result can be opened once ready
+                let scan = UrlscanScan {
+                    id: resp.uuid.to_owned(),
+                    task: ScanTask {
+                        uuid: resp.uuid,
+                        time: String::from("Submitted"),
+                        url: resp.url,
+                        visibility: resp.visibility.to_owned(),
+                    },
+                    ..UrlscanScan::default()
+                };
+
+                let mut app = self.app.lock().await;
+                app.urlscan
+                    .submitted
+                    .insert(scan.task.uuid.to_owned(), Instant::now());
+                app.urlscan.search.results.insert(0, scan);
+                app.urlscan.scan_index = 0;
+                app.urlscan.status = ResultStatus::Found;
+                app.urlscan.message = Some(format!(
+                    "Submitted as {}, press Enter on it once the scan has finished",
+                    resp.visibility
+                ));
+            }
+            Err(e) => {
+                self.process_error(e, |app| &mut app.urlscan.status).await;
+            }
+        }
+    }
+
+    async fn asn(&mut self, asn: u32, address: Option<IpAddr>) {
+        match self.clients.asn.lookup(asn, address).await {
+            Ok(context) => {
+                let mut app = self.app.lock().await;
+                app.asn.context = context;
+                app.asn.status = ResultStatus::Found;
+                app.asn.prefix_index = 0;
+                app.asn.peer_index = 0;
+            }
+            Err(StatusCode::NOT_FOUND) => {
+                let mut app = self.app.lock().await;
+                app.asn.context = AsnContext {
+                    asn,
+                    ..AsnContext::default()
+                };
+                app.asn.status = ResultStatus::NotFound;
+            }
+            Err(e) => {
+                self.process_error(e, |app| &mut app.asn.status).await;
+            }
+        }
+    }
+
+    async fn asn_host_counts(&mut self, asn: u32) {
+        let shodan = match self.client_config.keys.shodan.is_empty() {
+            true => None,
+            false => self
+                .clients
+                .shodan
+                .count_hosts(&format!("asn:AS{}", asn))
+                .await
+                .ok()
+                .map(|count| count.total),
+        };
+
+        let censys = match self.client_config.keys.censys_secret.is_empty() {
+            true => None,
+            false => self
+                .clients
+                .censys
+                .search_hosts(&format!("autonomous_system.asn: {}", asn), None)
+                .await
+                .ok()
+                .map(|search| search.result.total),
+        };
+
+        let mut app = self.app.lock().await;
+        // Another AS may have been opened in the meantime
+        if app.asn.context.asn == asn {
+            app.asn.shodan_hosts = shodan;
+            app.asn.censys_hosts = censys;
+        }
+    }
+
+    async fn bulk_lookup(&mut self) {
+        let indicators = {
+            let app = self.app.lock().await;
+            app.bulk
+                .items
+                .iter()
+                .map(|item| item.indicator.to_owned())
+                .collect::<Vec<String>>()
+        };
+
+        let clients = &self.clients;
+        let mut lookups = futures::stream::iter(indicators.into_iter().enumerate())
+            .map(|(index, indicator)| async move { (index, enrich(clients, indicator).await) })
+            .buffer_unordered(BULK_CONCURRENCY);
+
+        while let Some((index, item)) = lookups.next().await {
+            let mut app = self.app.lock().await;
+            // Another bulk lookup may have replaced the queue in the meantime
+            if let Some(queued) = app.bulk.items.get_mut(index) {
+                if queued.indicator == item.indicator {
+                    *queued = item;
+                }
+            }
+        }
+    }
+
+    async fn geoip_lookup(&mut self, ip: String) {
+        let record = ip.parse().ok().and_then(|ip| self.clients.geoip.lookup(ip));
+        if self.is_stale(&ip).await {
+            return;
+        }
+
+        let mut app = self.app.lock().await;
+        match record {
+            Some(record) => {
+                app.geoip.record = record;
+                app.geoip.status = ResultStatus::Found;
+            }
+            None => {
+                app.geoip.status = ResultStatus::NotFound;
+            }
+        }
+    }
+
+    async fn list_tags(&mut self, ip: String) {
+        let tags = ip
+            .parse()
+            .map(|ip| self.clients.lists.tags(ip))
+            .unwrap_or_default();
+        if self.is_stale(&ip).await {
+            return;
+        }
+
+        let mut app = self.app.lock().await;
+        app.list_tags = tags;
+    }
+
+    async fn dns_lookup(&mut self, query: String) {
+        let result = match Indicator::parse(&query) {
+            Some(Indicator::Ip(ip)) => self.clients.dns.reverse_lookup(ip).await,
+            Some(Indicator::Domain(domain)) => self.clients.dns.lookup_domain(&domain).await,
+            _ => return,
+        };
+        if self.is_stale(&query).await {
+            return;
+        }
+
+        // Like RDAP, live DNS is an enrichment and resolver failures stay inside the DNS view
+        let mut app = self.app.lock().await;
+        match result {
+            Ok(records) => {
+                app.dns.records = records;
+                app.dns.status = ResultStatus::Found;
+            }
+            Err(_) => {
+                app.dns.status = ResultStatus::NotFound;
+            }
+        }
+    }
+
     async fn virustotal_get_ip_whois(&mut self, ip: String) {
-        match self.vt_client.get_ip_whois(ip.as_str()).await {
+        let result = self.clients.virustotal.get_ip_whois(ip.as_str()).await;
+        if self.is_stale(&ip).await {
+            return;
+        }
+
+        match result {
             Ok(resp) => {
                 let mut app = self.app.lock().await;
                 app.virustotal.ip_whois_items = resp;
                 app.virustotal.status = ResultStatus::Found
             }
             Err(e) => {
-                self.process_error(e).await;
+                self.process_error(e, |app| &mut app.virustotal.status)
+                    .await;
             }
         }
     }
 
-    async fn virustotal_get_ip_comments(&mut self, ip: String) {
-        match self.vt_client.get_ip_comments(ip.as_str()).await {
+    async fn virustotal_get_ip_comments(&mut self, ip: String, cursor: Option<String>) {
+        match self
+            .clients
+            .virustotal
+            .get_ip_comments(ip.as_str(), cursor.as_deref())
+            .await
+        {
             Ok(resp) => {
                 let mut app = self.app.lock().await;
-                app.virustotal.ip_comment_items = resp;
+                // Ignore pages for an indicator that is no longer being shown
+                if app.virustotal.indicator != ip {
+                    return;
+                }
+                if cursor.is_some() {
+                    app.virustotal.comments_loading = false;
+                    let comments = &mut app.virustotal.ip_comment_items;
+                    comments.data.extend(resp.data);
+                    comments.meta = resp.meta;
+                } else {
+                    app.virustotal.ip_comment_items = resp;
+                    app.virustotal.comment_authors.clear();
+                    app.virustotal.comment_authors_loading.clear();
+                    app.virustotal.comment_author_errors.clear();
+                    app.virustotal.comment_index = 0;
+                    app.virustotal.comment_scroll = 0;
+                }
                 app.virustotal.status = ResultStatus::Found
             }
             Err(e) => {
-                self.process_error(e).await;
+                {
+                    let mut app = self.app.lock().await;
+                    app.virustotal.comments_loading = false;
+                }
+                self.process_error(e, |app| &mut app.virustotal.status)
+                    .await;
             }
         }
     }
 
-    async fn virustotal_get_comment_author(&mut self, comment_id: String) {
-        match self.vt_client.get_comment_author(comment_id.as_str()).await {
+    async fn virustotal_get_relationship(
+        &mut self,
+        indicator: String,
+        relationship: String,
+        cursor: Option<String>,
+    ) {
+        match self
+            .clients
+            .virustotal
+            .get_relationship(indicator.as_str(), relationship.as_str(), cursor.as_deref())
+            .await
+        {
             Ok(resp) => {
                 let mut app = self.app.lock().await;
-                app.virustotal.comment_authors = resp;
-                app.virustotal.status = ResultStatus::Found
+                // Ignore pages for an indicator that is no longer being shown
+                if app.virustotal.indicator != indicator {
+                    return;
+                }
+                app.virustotal.relationships_loading.remove(&relationship);
+                let page = app
+                    .virustotal
+                    .relationships
+                    .entry(relationship)
+                    .or_default();
+                page.data.extend(resp.data);
+                page.meta = resp.meta;
+            }
+            // A failed relationship only affects the Relations tab, the rest of the VirusTotal
+            // results stay usable
+            Err(e) => {
+                let mut app = self.app.lock().await;
+                if app.virustotal.indicator != indicator {
+                    return;
+                }
+                app.virustotal.relationships_loading.remove(&relationship);
+                if cursor.is_none() {
+                    app.virustotal.relationships.remove(&relationship);
+                }
+                let message = format!("Could not load {}: {}", relationship, e);
+                app.virustotal
+                    .relationship_errors
+                    .insert(relationship, message);
+            }
+        }
+    }
+
+    async fn virustotal_get_comment_author(&mut self, comment_id: String) {
+        let result = self
+            .clients
+            .virustotal
+            .get_comment_author(comment_id.as_str())
+            .await;
+
+        // A missing author shouldn't interrupt reading the comments, the failure is shown in
+        // place of the author instead
+        let mut app = self.app.lock().await;
+        app.virustotal.comment_authors_loading.remove(&comment_id);
+        match result {
+            Ok(resp) => {
+                app.virustotal.comment_authors.insert(comment_id, resp);
             }
             Err(e) => {
-                self.process_error(e).await;
+                let message = format!("Could not load author: {}", e);
+                app.virustotal
+                    .comment_author_errors
+                    .insert(comment_id, message);
             }
         }
     }
 
     async fn shodan_search_ip(&mut self, ip: String) {
-        match self.shodan_client.search_ip(ip.as_str()).await {
+        let result = self.clients.shodan.search_ip(ip.as_str()).await;
+        if self.is_stale(&ip).await {
+            return;
+        }
+
+        match result {
             Ok(resp) => {
                 let mut app = self.app.lock().await;
                 app.shodan.search_ip_items = resp;
                 app.shodan.status = ResultStatus::Found
             }
             Err(e) => {
-                self.process_error(e).await;
+                self.process_error(e, |app| &mut app.shodan.status).await;
+            }
+        }
+    }
+
+    async fn shodan_search_hosts(&mut self, query: String, page: u32) {
+        let result = self.clients.shodan.search_hosts(query.as_str(), page).await;
+
+        {
+            // A page of an earlier query would end up mixed into the current one
+            let mut app = self.app.lock().await;
+            if app.shodan.search_query != query {
+                return;
+            }
+            app.shodan.search_loading = false;
+        }
+
+        match result {
+            Ok(resp) => {
+                let mut app = self.app.lock().await;
+                if page > 1 {
+                    // Facets only need to be computed once, keep the ones from the first page
+                    app.shodan.search_items.matches.extend(resp.matches);
+                } else {
+                    app.shodan.search_items = resp;
+                    app.shodan.search_index = 0;
+                }
+                app.shodan.search_page = page;
+            }
+            Err(e) => {
+                self.process_error(e, |app| &mut app.shodan.status).await;
             }
         }
     }
 
     async fn censys_search_ip(&mut self, ip: String) {
-        match self.censys_client.search_ip(ip.as_str()).await {
+        let result = self.clients.censys.search_ip(ip.as_str()).await;
+        if self.is_stale(&ip).await {
+            return;
+        }
+
+        match result {
             Ok(resp) => {
                 let mut app = self.app.lock().await;
                 app.censys.search_ip_items = resp;
                 app.censys.status = ResultStatus::Found
             }
             Err(e) => {
-                self.process_error(e).await;
+                self.process_error(e, |app| &mut app.censys.status).await;
+            }
+        }
+    }
+
+    async fn censys_search_hosts(&mut self, query: String, cursor: Option<String>) {
+        let result = self
+            .clients
+            .censys
+            .search_hosts(query.as_str(), cursor.as_deref())
+            .await;
+
+        {
+            // A page of an earlier query would end up mixed into the current one
+            let mut app = self.app.lock().await;
+            if app.censys.search_query != query {
+                return;
+            }
+            app.censys.search_loading = false;
+        }
+
+        match result {
+            Ok(resp) => {
+                let mut app = self.app.lock().await;
+                if cursor.is_some() {
+                    // "Load more" keeps the hits already shown and appends the next page
+                    let search_items = &mut app.censys.search_items;
+                    search_items.result.hits.extend(resp.result.hits);
+                    search_items.result.links = resp.result.links;
+                } else {
+                    app.censys.search_items = resp;
+                    app.censys.search_index = 0;
+                }
+            }
+            Err(e) => {
+                self.process_error(e, |app| &mut app.censys.status).await;
             }
         }
     }
 
-    async fn process_error(&mut self, e: StatusCode) {
+    async fn censys_host_events(&mut self, ip: String) {
+        let result = self.clients.censys.host_events(ip.as_str()).await;
+
+        // History comes from an experimental endpoint, failures stay inside the History tab
+        let mut app = self.app.lock().await;
+        if app.censys.history_host != ip {
+            return;
+        }
+        match result {
+            Ok(resp) => {
+                app.censys.history_status = match resp.result.events.is_empty() {
+                    true => ResultStatus::NotFound,
+                    false => ResultStatus::Found,
+                };
+                app.censys.history_items = resp;
+                app.censys.history_index = 0;
+            }
+            Err(StatusCode::NOT_FOUND) => {
+                app.censys.history_status = ResultStatus::NotFound;
+            }
+            Err(StatusCode::FORBIDDEN) => {
+                app.censys.history_status = ResultStatus::NotFound;
+                app.censys.history_error = Some(String::from(
+                    "Host history needs a Censys account with experimental API access",
+                ));
+            }
+            Err(e) => {
+                app.censys.history_status = ResultStatus::NotFound;
+                app.censys.history_error = Some(format!("Could not load host history: {}", e));
+            }
+        }
+    }
+
+    async fn censys_view_certificate(&mut self, fingerprint: String) {
+        let result = self
+            .clients
+            .censys
+            .view_certificate(fingerprint.as_str())
+            .await;
+
+        // Certificates are opened from a host, failures stay inside the certificate view
+        {
+            let mut app = self.app.lock().await;
+            if app.censys.certificate_fingerprint != fingerprint {
+                return;
+            }
+            match result {
+                Ok(resp) => {
+                    app.censys.certificate_items = resp;
+                    app.censys.certificate_status = ResultStatus::Found;
+                }
+                Err(StatusCode::NOT_FOUND) => {
+                    app.censys.certificate_status = ResultStatus::NotFound;
+                    return;
+                }
+                Err(e) => {
+                    app.censys.certificate_status = ResultStatus::NotFound;
+                    app.censys.certificate_error =
+                        Some(format!("Could not load the certificate: {}", e));
+                    return;
+                }
+            }
+        }
+
+        let result = self
+            .clients
+            .censys
+            .certificate_hosts(fingerprint.as_str())
+            .await;
+
+        let mut app = self.app.lock().await;
+        if app.censys.certificate_fingerprint != fingerprint {
+            return;
+        }
+        match result {
+            Ok(resp) => {
+                app.censys.certificate_host_items = resp;
+                app.censys.certificate_host_index = 0;
+            }
+            Err(e) => {
+                app.censys.certificate_error = Some(format!(
+                    "Could not load the hosts presenting this certificate: {}",
+                    e
+                ));
+            }
+        }
+    }
+
+    // A 404 means the provider that answered it doesn't know the indicator, anything else is
+    // shown on the error screen
+    async fn process_error(&mut self, e: StatusCode, status: fn(&mut App) -> &mut ResultStatus) {
         if matches!(e, StatusCode::NOT_FOUND) {
             let mut app = self.app.lock().await;
-            app.censys.status = ResultStatus::NotFound
+            *status(&mut app) = ResultStatus::NotFound;
         } else {
+            // The provider answered, so its panel mustn't keep waiting behind the error screen
+            {
+                let mut app = self.app.lock().await;
+                let status = status(&mut app);
+                if *status == ResultStatus::Loading {
+                    *status = ResultStatus::NotQueried;
+                }
+            }
             self.handle_error(anyhow!(e)).await;
         }
     }
 }
+
+// Tags the hosts of a network with their open ports, from the first page of Shodan banners only
+async fn bulk_network_ports(clients: Clients, app: Arc<Mutex<App>>, network: IpNet) {
+    let query = format!("net:{}", network);
+    let search = match clients.shodan.search_hosts(&query, 1).await {
+        Ok(search) => search,
+        Err(_) => return,
+    };
+
+    let mut app = app.lock().await;
+    if app.bulk.network != Some(network) {
+        return;
+    }
+
+    for banner in search.matches {
+        let port = match u16::try_from(banner.port) {
+            Ok(port) => port,
+            Err(_) => continue,
+        };
+        if let Some(item) = app
+            .bulk
+            .items
+            .iter_mut()
+            .find(|item| item.indicator == banner.ip_str)
+        {
+            if !item.ports.contains(&port) {
+                item.ports.push(port);
+                item.ports.sort_unstable();
+            }
+        }
+    }
+}
+
+// Looks the hosts of a network up on VirusTotal one at a time, and gives up on the rest once the
+// key runs out of quota
+async fn bulk_network_verdicts(clients: Clients, app: Arc<Mutex<App>>, network: IpNet) {
+    let hosts = {
+        let app = app.lock().await;
+        app.bulk
+            .items
+            .iter()
+            .map(|item| item.indicator.to_owned())
+            .collect::<Vec<String>>()
+    };
+
+    let mut interval = tokio::time::interval(BULK_VIRUSTOTAL_INTERVAL);
+    for (index, host) in hosts.into_iter().enumerate() {
+        interval.tick().await;
+        let result = clients.virustotal.get_ip_whois(&host).await;
+
+        let mut app = app.lock().await;
+        // Another lookup replaced the queue, its hosts are no longer shown
+        if app.bulk.network != Some(network) {
+            return;
+        }
+
+        match result {
+            Ok(ip) => {
+                if let Some(item) = app.bulk.items.get_mut(index) {
+                    item.verdict =
+                        Some(ip.data.attributes.last_analysis_stats.verdict().to_owned());
+                }
+            }
+            Err(StatusCode::TOO_MANY_REQUESTS) => {
+                for item in app.bulk.items.iter_mut().skip(index) {
+                    item.verdict = Some(String::from("Rate limited"));
+                }
+                break;
+            }
+            Err(_) => {}
+        }
+    }
+
+    app.lock().await.bulk.verdicts_loading = false;
+}
+
+fn abusech_error(status: StatusCode) -> String {
+    match status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            String::from("abuse.ch needs an Auth-Key, set `abusech` under [keys]")
+        }
+        status => format!("Lookup failed: {}", status),
+    }
+}
+
+// Why the result of a scan is missing, `since_submitted` being set for scans submitted from
+// osintui. urlscan.io answers 404 both while a scan runs and once it has failed
+fn missing_scan_message(
+    since_submitted: Option<Duration>,
+    status: StatusCode,
+) -> Option<&'static str> {
+    match status {
+        StatusCode::NOT_FOUND
+            if since_submitted.is_some_and(|elapsed| elapsed < URLSCAN_SCAN_TIMEOUT) =>
+        {
+            Some("The scan has not finished yet, try again in a few seconds")
+        }
+        StatusCode::NOT_FOUND => Some("urlscan.io has no result for this scan, it may have failed"),
+        StatusCode::GONE => Some("The scan has been deleted from urlscan.io"),
+        _ => None,
+    }
+}
+
+// Resolves a bulk queue entry and locates its first address in the local GeoIP databases
+async fn enrich(clients: &Clients, indicator: String) -> BulkItem {
+    // URLs and emails are enriched through the host they point at
+    let host = match Indicator::parse(&indicator) {
+        Some(Indicator::Ip(ip)) => Some(ip.to_string()),
+        Some(Indicator::Domain(domain)) => Some(domain),
+        Some(Indicator::Url(url)) => reqwest::Url::parse(&url).ok().and_then(|url| {
+            url.host_str().map(|host| {
+                host.trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .to_owned()
+            })
+        }),
+        Some(Indicator::Email(email)) => {
+            email.rsplit_once('@').map(|(_, domain)| domain.to_string())
+        }
+        _ => None,
+    };
+
+    let addresses = match host {
+        Some(host) => match host.parse::<IpAddr>() {
+            Ok(ip) => vec![ip],
+            Err(_) => clients.dns.resolve(&host).await.unwrap_or_default(),
+        },
+        None => vec![],
+    };
+
+    let record = addresses
+        .first()
+        .and_then(|address| clients.geoip.lookup(*address));
+
+    BulkItem {
+        status: match addresses.is_empty() {
+            true => ResultStatus::NotFound,
+            false => ResultStatus::Found,
+        },
+        addresses: addresses
+            .iter()
+            .map(|address| address.to_string())
+            .collect(),
+        country: record
+            .as_ref()
+            .and_then(|record| record.country_code.clone()),
+        asn: record
+            .as_ref()
+            .and_then(|record| record.asn)
+            .map(|asn| format!("AS{}", asn)),
+        ports: vec![],
+        verdict: None,
+        indicator,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_scan_results() {
+        let running = Some(Duration::from_secs(10));
+        let stuck = Some(URLSCAN_SCAN_TIMEOUT + Duration::from_secs(1));
+
+        assert_eq!(
+            missing_scan_message(running, StatusCode::NOT_FOUND),
+            Some("The scan has not finished yet, try again in a few seconds")
+        );
+        // Scans that were listed by the search already finished, a 404 means they failed
+        assert_eq!(
+            missing_scan_message(None, StatusCode::NOT_FOUND),
+            Some("urlscan.io has no result for this scan, it may have failed")
+        );
+        assert_eq!(
+            missing_scan_message(stuck, StatusCode::NOT_FOUND),
+            Some("urlscan.io has no result for this scan, it may have failed")
+        );
+        assert_eq!(
+            missing_scan_message(running, StatusCode::GONE),
+            Some("The scan has been deleted from urlscan.io")
+        );
+        assert_eq!(
+            missing_scan_message(running, StatusCode::TOO_MANY_REQUESTS),
+            None
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use crate::app::{App, ResultStatus};
+use crate::clients::{
+    censys::CensysSearchIp, shodan::ShodanSearchIp, threatfox::ThreatFoxSearch,
+    urlhaus::UrlhausLookup, virustotal::IpAttributes,
+};
+use crate::config::ScoringWeights;
+use serde::Serialize;
+
+// Shodan tags that on their own make a host worth a closer look
+const MALICIOUS_TAGS: [&str; 3] = ["malware", "c2", "compromised"];
+const SUSPICIOUS_TAGS: [&str; 5] = ["tor", "scanner", "proxy", "vpn", "honeypot"];
+
+// Censys labels exposing something that should rarely face the internet
+const RISKY_LABELS: [&str; 4] = ["remote-access", "database", "login-page", "open-dir"];
+
+/// One piece of evidence contributing to the risk score
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Signal {
+    pub provider: &'static str,
+    pub name: &'static str,
+    pub detail: String,
+    pub points: f64,
+    pub weight: f64,
+}
+
+/// Risk score out of 100 with the signals it was computed from
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Verdict {
+    pub score: u8,
+    pub signals: Vec<Signal>,
+}
+
+impl Verdict {
+    pub fn from_signals(signals: Vec<Signal>) -> Verdict {
+        let total: f64 = signals.iter().map(|signal| signal.points).sum();
+
+        Verdict {
+            score: total.round().clamp(0.0, 100.0) as u8,
+            signals,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self.score {
+            0..=9 => "Clean",
+            10..=39 => "Low",
+            40..=69 => "Suspicious",
+            _ => "Malicious",
+        }
+    }
+
+    pub fn breakdown_to_vec(&self) -> Vec<Vec<String>> {
+        self.signals
+            .iter()
+            .map(|signal| {
+                vec![
+                    signal.provider.to_owned(),
+                    signal.name.to_owned(),
+                    signal.detail.to_owned(),
+                    format!("{:.0} / {:.0}", signal.points, signal.weight),
+                ]
+            })
+            .collect()
+    }
+}
+
+// Scales `count` linearly so that `saturation` or more earns the full weight
+fn scaled(count: f64, saturation: f64, weight: f64) -> f64 {
+    (count / saturation).clamp(0.0, 1.0) * weight
+}
+
+pub fn virustotal_signals(attributes: &IpAttributes, weights: &ScoringWeights) -> Vec<Signal> {
+    let stats = &attributes.last_analysis_stats;
+
+    vec![
+        Signal {
+            provider: "VirusTotal",
+            name: "Malicious detections",
+            detail: format!("{} engines", stats.malicious),
+            points: scaled(stats.malicious as f64, 5.0, weights.vt_malicious),
+            weight: weights.vt_malicious,
+        },
+        Signal {
+            provider: "VirusTotal",
+            name: "Suspicious detections",
+            detail: format!("{} engines", stats.suspicious),
+            points: scaled(stats.suspicious as f64, 5.0, weights.vt_suspicious),
+            weight: weights.vt_suspicious,
+        },
+        Signal {
+            provider: "VirusTotal",
+            name: "Community reputation",
+            detail: attributes.reputation.to_string(),
+            points: scaled(-attributes.reputation as f64, 50.0, weights.vt_reputation),
+            weight: weights.vt_reputation,
+        },
+    ]
+}
+
+pub fn shodan_signals(host: &ShodanSearchIp, weights: &ScoringWeights) -> Vec<Signal> {
+    let vulns = host.vulns.as_deref().unwrap_or_default();
+    let tags = host.tags.as_deref().unwrap_or_default();
+
+    let tag_points = if tags
+        .iter()
+        .any(|tag| MALICIOUS_TAGS.contains(&tag.as_str()))
+    {
+        weights.shodan_tags
+    } else if tags
+        .iter()
+        .any(|tag| SUSPICIOUS_TAGS.contains(&tag.as_str()))
+    {
+        weights.shodan_tags / 2.0
+    } else {
+        0.0
+    };
+
+    vec![
+        Signal {
+            provider: "Shodan",
+            name: "Vulnerabilities",
+            detail: format!("{} CVEs", vulns.len()),
+            points: scaled(vulns.len() as f64, 5.0, weights.shodan_vulns),
+            weight: weights.shodan_vulns,
+        },
+        Signal {
+            provider: "Shodan",
+            name: "Tags",
+            detail: match tags.is_empty() {
+                true => String::from("None"),
+                false => tags.join(", "),
+            },
+            points: tag_points,
+            weight: weights.shodan_tags,
+        },
+    ]
+}
+
+pub fn censys_signals(host: &CensysSearchIp, weights: &ScoringWeights) -> Vec<Signal> {
+    let mut labels = host.result.labels.clone().unwrap_or_default();
+    labels.extend(
+        host.result
+            .services
+            .iter()
+            .flat_map(|service| service.labels.clone().unwrap_or_default()),
+    );
+    labels.sort();
+    labels.dedup();
+
+    let risky = labels
+        .iter()
+        .filter(|label| RISKY_LABELS.contains(&label.as_str()))
+        .count();
+
+    vec![Signal {
+        provider: "Censys",
+        name: "Labels",
+        detail: match labels.is_empty() {
+            true => String::from("None"),
+            false => labels.join(", "),
+        },
+        points: scaled(risky as f64, 2.0, weights.censys_labels),
+        weight: weights.censys_labels,
+    }]
+}
+
+/// Lookups that failed are passed as `None`, they say nothing about the indicator
+pub fn abusech_signals(
+    urlhaus: Option<&UrlhausLookup>,
+    threatfox: Option<&ThreatFoxSearch>,
+    weights: &ScoringWeights,
+) -> Vec<Signal> {
+    let mut signals = Vec::new();
+
+    if let Some(urlhaus) = urlhaus {
+        // Malware that is still being served counts fully, past distribution half
+        let online = urlhaus.online_urls();
+        let urlhaus_points = match (urlhaus.is_listed(), online) {
+            (false, _) => 0.0,
+            (true, 0) => weights.urlhaus / 2.0,
+            (true, _) => weights.urlhaus,
+        };
+
+        signals.push(Signal {
+            provider: "URLhaus",
+            name: "Malware distribution",
+            detail: match urlhaus.is_listed() {
+                true => format!("Listed, {} URLs online", online),
+                false => String::from("Not listed"),
+            },
+            points: urlhaus_points,
+            weight: weights.urlhaus,
+        });
+    }
+
+    if let Some(threatfox) = threatfox {
+        let (threatfox_detail, threatfox_points) = match threatfox.top_ioc() {
+            Some(ioc) => (
+                format!(
+                    "{} {} ({}%)",
+                    ioc.threat_type, ioc.malware_printable, ioc.confidence_level
+                ),
+                f64::from(ioc.confidence_level.min(100)) / 100.0 * weights.threatfox,
+            ),
+            None => (String::from("Not listed"), 0.0),
+        };
+
+        signals.push(Signal {
+            provider: "ThreatFox",
+            name: "Known IOC",
+            detail: threatfox_detail,
+            points: threatfox_points,
+            weight: weights.threatfox,
+        });
+    }
+
+    signals
+}
+
+/// Combines every provider that returned data for the current lookup
+pub fn verdict(app: &App) -> Verdict {
+    let weights = &app.client_config.scoring;
+    let mut signals = Vec::new();
+
+    if app.virustotal.status == ResultStatus::Found {
+        signals.extend(virustotal_signals(
+            &app.virustotal.ip_whois_items.data.attributes,
+            weights,
+        ));
+    }
+
+    if app.shodan.status == ResultStatus::Found {
+        signals.extend(shodan_signals(&app.shodan.search_ip_items, weights));
+    }
+
+    if app.censys.status == ResultStatus::Found {
+        signals.extend(censys_signals(&app.censys.search_ip_items, weights));
+    }
+
+    if app.abusech.status == ResultStatus::Found {
+        let abusech = &app.abusech;
+        signals.extend(abusech_signals(
+            abusech.urlhaus_error.is_none().then_some(&abusech.urlhaus),
+            abusech
+                .threatfox_error
+                .is_none()
+                .then_some(&abusech.threatfox),
+            weights,
+        ));
+    }
+
+    Verdict::from_signals(signals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::virustotal::AnalysisStats;
+
+    #[test]
+    fn test_virustotal_signals_saturate() {
+        let attributes = IpAttributes {
+            last_analysis_stats: AnalysisStats {
+                malicious: 12,
+                suspicious: 1,
+                ..AnalysisStats::default()
+            },
+            reputation: -25,
+            ..IpAttributes::default()
+        };
+        let weights = ScoringWeights::default();
+
+        let verdict = Verdict::from_signals(virustotal_signals(&attributes, &weights));
+
+        assert_eq!(verdict.signals[0].points, weights.vt_malicious);
+        assert_eq!(verdict.signals[1].points, weights.vt_suspicious / 5.0);
+        assert_eq!(verdict.signals[2].points, weights.vt_reputation / 2.0);
+        assert_eq!(verdict.score, 50);
+        assert_eq!(verdict.label(), "Suspicious");
+    }
+
+    #[test]
+    fn test_abusech_signals() {
+        use crate::clients::threatfox::ThreatFoxIoc;
+
+        let urlhaus = UrlhausLookup {
+            query_status: String::from("ok"),
+            ..UrlhausLookup::default()
+        };
+        let threatfox = ThreatFoxSearch {
+            data: vec![ThreatFoxIoc {
+                threat_type: String::from("botnet_cc"),
+                malware_printable: String::from("Cobalt Strike"),
+                confidence_level: 75,
+                ..ThreatFoxIoc::default()
+            }],
+            ..ThreatFoxSearch::default()
+        };
+        let weights = ScoringWeights::default();
+
+        let signals = abusech_signals(Some(&urlhaus), Some(&threatfox), &weights);
+
+        assert_eq!(signals[0].points, weights.urlhaus / 2.0);
+        assert_eq!(signals[1].points, weights.threatfox * 0.75);
+        assert_eq!(signals[1].detail, "botnet_cc Cobalt Strike (75%)");
+
+        // A failed URLhaus lookup is left out rather than scored as not listed
+        let signals = abusech_signals(None, Some(&threatfox), &weights);
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].provider, "ThreatFox");
+    }
+
+    #[test]
+    fn test_verdict_ignores_previous_search() {
+        let mut app = App::default();
+        app.virustotal.status = ResultStatus::Found;
+        app.virustotal
+            .ip_whois_items
+            .data
+            .attributes
+            .last_analysis_stats
+            .malicious = 10;
+        assert!(verdict(&app).score > 0);
+
+        // The next search must not be scored with the detections of this one
+        app.reset_results();
+        assert_eq!(verdict(&app), Verdict::default());
+    }
+
+    #[test]
+    fn test_score_is_capped() {
+        let signal = |points| Signal {
+            provider: "Test",
+            name: "Test",
+            detail: String::new(),
+            points,
+            weight: points,
+        };
+
+        let verdict = Verdict::from_signals(vec![signal(80.0), signal(70.0)]);
+
+        assert_eq!(verdict.score, 100);
+        assert_eq!(Verdict::default().label(), "Clean");
+    }
+}
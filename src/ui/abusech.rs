@@ -0,0 +1,219 @@
+use super::super::app::{ActiveBlock, App};
+use crate::ui::util::get_percentage_width;
+use crate::ui::{draw_table, TableHeader, TableHeaderItem, TableItem};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, Cell, Row, Table},
+    Frame,
+};
+
+pub fn draw_abusech<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)].as_ref())
+        .split(layout_chunk);
+
+    let urlhaus_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(16), Constraint::Min(6)].as_ref())
+        .split(chunks[0]);
+
+    let threatfox_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)].as_ref())
+        .split(chunks[1]);
+
+    draw_urlhaus_summary(f, app, urlhaus_chunks[0]);
+    draw_urlhaus_urls(f, app, urlhaus_chunks[1]);
+    draw_threatfox_iocs(f, app, threatfox_chunks[0]);
+    draw_abusech_payloads(f, app, threatfox_chunks[1]);
+}
+
+pub fn draw_urlhaus_summary<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let summary = match &app.abusech.urlhaus_error {
+        Some(error) => vec![vec![String::from("Error"), error.to_owned()]],
+        None => app.abusech.urlhaus.summary_to_vec(),
+    };
+    let rows = summary.into_iter().map(|i| {
+        Row::new(i.into_iter().map(Cell::from))
+            .style(Style::default().add_modifier(Modifier::BOLD))
+            .bottom_margin(1)
+    });
+
+    let summary = Table::new(rows)
+        .header(Row::new(vec!["", ""]))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("🦠 URLhaus")
+                .border_type(BorderType::Plain),
+        )
+        .widths(&[Constraint::Length(12), Constraint::Percentage(100)]);
+
+    f.render_widget(summary, layout_chunk);
+}
+
+pub fn draw_urlhaus_urls<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "Added",
+                width: get_percentage_width(layout_chunk.width, 0.2),
+            },
+            TableHeaderItem {
+                text: "Status",
+                width: get_percentage_width(layout_chunk.width, 0.12),
+            },
+            TableHeaderItem {
+                text: "Threat",
+                width: get_percentage_width(layout_chunk.width, 0.2),
+            },
+            TableHeaderItem {
+                text: "URL",
+                width: get_percentage_width(layout_chunk.width, 0.48),
+            },
+        ],
+    };
+
+    let items = app
+        .abusech
+        .urlhaus
+        .urls
+        .iter()
+        .map(|url| TableItem {
+            format: url.summary_to_vec(),
+        })
+        .collect::<Vec<TableItem>>();
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        ("Malware URLs", &header),
+        &items,
+        0,
+        (false, false),
+    );
+}
+
+pub fn draw_threatfox_iocs<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "First Seen",
+                width: get_percentage_width(layout_chunk.width, 0.15),
+            },
+            TableHeaderItem {
+                text: "Threat Type",
+                width: get_percentage_width(layout_chunk.width, 0.2),
+            },
+            TableHeaderItem {
+                text: "Malware",
+                width: get_percentage_width(layout_chunk.width, 0.3),
+            },
+            TableHeaderItem {
+                text: "Confidence",
+                width: get_percentage_width(layout_chunk.width, 0.12),
+            },
+            TableHeaderItem {
+                text: "Reporter",
+                width: get_percentage_width(layout_chunk.width, 0.23),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::AbusechIocs,
+        current_route.hovered_block == ActiveBlock::AbusechIocs,
+    );
+
+    let items = app
+        .abusech
+        .threatfox
+        .data
+        .iter()
+        .map(|ioc| TableItem {
+            format: ioc.summary_to_vec(),
+        })
+        .collect::<Vec<TableItem>>();
+
+    let title = match &app.abusech.threatfox_error {
+        Some(error) => format!("🦊 ThreatFox - {}", error),
+        None => String::from("🦊 ThreatFox"),
+    };
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        (&title, &header),
+        &items,
+        app.abusech.ioc_index,
+        highlight_state,
+    );
+}
+
+pub fn draw_abusech_payloads<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "First Seen",
+                width: get_percentage_width(layout_chunk.width, 0.15),
+            },
+            TableHeaderItem {
+                text: "Malware",
+                width: get_percentage_width(layout_chunk.width, 0.2),
+            },
+            TableHeaderItem {
+                text: "SHA-256",
+                width: get_percentage_width(layout_chunk.width, 0.53),
+            },
+            TableHeaderItem {
+                text: "Source",
+                width: get_percentage_width(layout_chunk.width, 0.12),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::AbusechPayloads,
+        current_route.hovered_block == ActiveBlock::AbusechPayloads,
+    );
+
+    let items = app
+        .abusech
+        .payloads()
+        .into_iter()
+        .map(|payload| TableItem { format: payload })
+        .collect::<Vec<TableItem>>();
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        ("Payloads, Enter to look up", &header),
+        &items,
+        app.abusech.payload_index,
+        highlight_state,
+    );
+}
@@ -0,0 +1,190 @@
+use super::super::app::{ActiveBlock, App};
+use crate::ui::util::get_percentage_width;
+use crate::ui::{draw_table, TableHeader, TableHeaderItem, TableItem};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, Cell, Gauge, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+pub fn draw_abuseipdb<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)].as_ref())
+        .split(layout_chunk);
+
+    let summary_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(10)].as_ref())
+        .split(chunks[0]);
+
+    let report_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)].as_ref())
+        .split(chunks[1]);
+
+    draw_abuseipdb_confidence(f, app, summary_chunks[0]);
+    draw_abuseipdb_summary(f, app, summary_chunks[1]);
+    draw_abuseipdb_reports(f, app, report_chunks[0]);
+    draw_abuseipdb_report_detail(f, app, report_chunks[1]);
+}
+
+pub fn draw_abuseipdb_confidence<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let score = app.abuseipdb.check.data.abuse_confidence_score;
+    let color = match score {
+        0 => Color::Green,
+        1..=49 => Color::Yellow,
+        _ => Color::Red,
+    };
+
+    let gauge = Gauge::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("Abuse Confidence")
+                .border_type(BorderType::Plain),
+        )
+        .gauge_style(Style::default().fg(color).bg(Color::Black))
+        .percent(u16::from(score.min(100)));
+
+    f.render_widget(gauge, layout_chunk);
+}
+
+pub fn draw_abuseipdb_summary<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let rows = app
+        .abuseipdb
+        .check
+        .data
+        .summary_to_vec()
+        .into_iter()
+        .map(|i| {
+            Row::new(i.into_iter().map(Cell::from))
+                .style(Style::default().add_modifier(Modifier::BOLD))
+                .bottom_margin(1)
+        });
+
+    let summary = Table::new(rows)
+        .header(Row::new(vec!["", ""]))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("🚨 AbuseIPDB")
+                .border_type(BorderType::Plain),
+        )
+        .widths(&[Constraint::Length(15), Constraint::Percentage(100)]);
+
+    f.render_widget(summary, layout_chunk);
+}
+
+pub fn draw_abuseipdb_reports<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "Date",
+                width: get_percentage_width(layout_chunk.width, 0.12),
+            },
+            TableHeaderItem {
+                text: "Categories",
+                width: get_percentage_width(layout_chunk.width, 0.33),
+            },
+            TableHeaderItem {
+                text: "From",
+                width: get_percentage_width(layout_chunk.width, 0.05),
+            },
+            TableHeaderItem {
+                text: "Comment",
+                width: get_percentage_width(layout_chunk.width, 0.5),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::AbuseipdbReports,
+        current_route.hovered_block == ActiveBlock::AbuseipdbReports,
+    );
+
+    let reports = &app.abuseipdb.reports.data;
+    let items = reports
+        .results
+        .iter()
+        .map(|report| TableItem {
+            format: report.summary_to_vec(),
+        })
+        .collect::<Vec<TableItem>>();
+
+    let more = match reports.has_more() {
+        true => format!(", '{}' to load more", app.user_config.keys.load_more),
+        false => String::new(),
+    };
+    let title = format!(
+        "Reports ({} of {}){}",
+        reports.results.len(),
+        reports.total,
+        more
+    );
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        (&title, &header),
+        &items,
+        app.abuseipdb.report_index,
+        highlight_state,
+    );
+}
+
+pub fn draw_abuseipdb_report_detail<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let report = app
+        .abuseipdb
+        .reports
+        .data
+        .results
+        .get(app.abuseipdb.report_index);
+
+    let text = match report {
+        Some(report) => format!(
+            "{}\nReported {} from {}\n\n{}",
+            report.categories(),
+            report.reported_at,
+            report
+                .reporter_country_name
+                .as_deref()
+                .unwrap_or("an unknown country"),
+            report.comment.as_deref().unwrap_or_default()
+        ),
+        None => String::from("No reports in the last 90 days"),
+    };
+
+    let paragraph = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("Report")
+                .border_type(BorderType::Plain),
+        )
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(paragraph, layout_chunk);
+}
@@ -0,0 +1,165 @@
+use super::super::app::{ActiveBlock, App, ResultStatus};
+use crate::ui::util::get_percentage_width;
+use crate::ui::{draw_table, TableHeader, TableHeaderItem, TableItem};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, Cell, Row, Table},
+    Frame,
+};
+
+pub fn draw_asn<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)].as_ref())
+        .split(layout_chunk);
+
+    let left_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(20), Constraint::Min(5)].as_ref())
+        .split(chunks[0]);
+
+    draw_asn_summary(f, app, left_chunks[0]);
+    draw_asn_prefixes(f, app, left_chunks[1]);
+    draw_asn_peers(f, app, chunks[1]);
+}
+
+fn draw_asn_summary<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let title = match app.asn.status {
+        ResultStatus::NotFound => format!("🛰 AS{} is not announced", app.asn.context.asn),
+        _ => format!("🛰 AS{}", app.asn.context.asn),
+    };
+
+    let count = |hosts: Option<i64>| hosts.map_or(String::from("N/A"), |hosts| hosts.to_string());
+    let mut summary = app.asn.context.summary_to_vec();
+    summary.push(vec![
+        String::from("Shodan Hosts"),
+        count(app.asn.shodan_hosts),
+    ]);
+    summary.push(vec![
+        String::from("Censys Hosts"),
+        count(app.asn.censys_hosts),
+    ]);
+
+    let rows = summary.into_iter().map(|i| {
+        Row::new(i.into_iter().map(Cell::from))
+            .style(Style::default().add_modifier(Modifier::BOLD))
+            .bottom_margin(1)
+    });
+
+    let summary = Table::new(rows)
+        .header(Row::new(vec!["", ""]))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title(title)
+                .border_type(BorderType::Plain),
+        )
+        .widths(&[Constraint::Length(16), Constraint::Percentage(100)]);
+
+    f.render_widget(summary, layout_chunk);
+}
+
+fn draw_asn_prefixes<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "Prefix",
+                width: get_percentage_width(layout_chunk.width, 0.3),
+            },
+            TableHeaderItem {
+                text: "Name",
+                width: get_percentage_width(layout_chunk.width, 0.5),
+            },
+            TableHeaderItem {
+                text: "Country",
+                width: get_percentage_width(layout_chunk.width, 0.2),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::AsnPrefixes,
+        current_route.hovered_block == ActiveBlock::AsnPrefixes,
+    );
+
+    let items = app
+        .asn
+        .context
+        .prefixes
+        .iter()
+        .map(|prefix| TableItem {
+            format: prefix.summary_to_vec(),
+        })
+        .collect::<Vec<TableItem>>();
+
+    let title = match items.is_empty() && app.asn.status == ResultStatus::Found {
+        true => "Announced Prefixes, none seen",
+        false => "Announced Prefixes, Enter to look up the hosts",
+    };
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        (title, &header),
+        &items,
+        app.asn.prefix_index,
+        highlight_state,
+    );
+}
+
+fn draw_asn_peers<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![TableHeaderItem {
+            text: "ASN",
+            width: get_percentage_width(layout_chunk.width, 1.0),
+        }],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::AsnPeers,
+        current_route.hovered_block == ActiveBlock::AsnPeers,
+    );
+
+    let items = app
+        .asn
+        .context
+        .peers
+        .iter()
+        .map(|peer| TableItem {
+            format: vec![peer.to_owned()],
+        })
+        .collect::<Vec<TableItem>>();
+
+    let title = match items.is_empty() && app.asn.status == ResultStatus::Found {
+        true => "Upstream Peers, none seen",
+        false => "Upstream Peers, Enter to look up",
+    };
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        (title, &header),
+        &items,
+        app.asn.peer_index,
+        highlight_state,
+    );
+}
@@ -0,0 +1,183 @@
+use super::super::app::{ActiveBlock, App, ResultStatus};
+use crate::ui::util::get_percentage_width;
+use crate::ui::{draw_table, TableHeader, TableHeaderItem, TableItem};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, Cell, Row, Table},
+    Frame,
+};
+
+pub fn draw_bulk<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    // Hosts of a network are also aggregated by open port and verdict
+    if app.bulk.network.is_none() {
+        return draw_bulk_results(f, app, layout_chunk);
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(75), Constraint::Percentage(25)].as_ref())
+        .split(layout_chunk);
+
+    let right_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)].as_ref())
+        .split(chunks[1]);
+
+    draw_bulk_results(f, app, chunks[0]);
+
+    let ports = app
+        .bulk
+        .port_counts()
+        .into_iter()
+        .map(|(port, hosts)| vec![port.to_string(), hosts.to_string()])
+        .collect();
+    // Only the first page of Shodan banners is fetched, bigger networks have more ports open
+    draw_bulk_counts(
+        f,
+        ("Open Ports (first Shodan page)", "Port"),
+        ports,
+        right_chunks[0],
+    );
+
+    let verdicts = app
+        .bulk
+        .verdict_counts()
+        .into_iter()
+        .map(|(verdict, hosts)| vec![verdict, hosts.to_string()])
+        .collect();
+    let checked = app
+        .bulk
+        .items
+        .iter()
+        .filter(|item| item.verdict.is_some())
+        .count();
+    let title = match (app.bulk.verdicts_loading, checked) {
+        (true, _) => format!(
+            "VirusTotal Verdicts ({} of {})",
+            checked,
+            app.bulk.items.len()
+        ),
+        (false, 0) => format!(
+            "VirusTotal Verdicts, {} to look up",
+            app.user_config.keys.bulk_verdicts
+        ),
+        (false, _) => String::from("VirusTotal Verdicts"),
+    };
+    draw_bulk_counts(f, (&title, "Verdict"), verdicts, right_chunks[1]);
+}
+
+fn draw_bulk_counts<B>(
+    f: &mut Frame<B>,
+    titles: (&str, &str),
+    counts: Vec<Vec<String>>,
+    layout_chunk: Rect,
+) where
+    B: Backend,
+{
+    let rows = counts.into_iter().map(|count| {
+        let style = match count[0].as_str() {
+            "Malicious" => Style::default().fg(Color::Red),
+            "Suspicious" => Style::default().fg(Color::Yellow),
+            _ => Style::default(),
+        };
+        Row::new(count.into_iter().map(Cell::from)).style(style)
+    });
+
+    let table = Table::new(rows)
+        .header(
+            Row::new(vec![titles.1, "Hosts"])
+                .style(Style::default().add_modifier(Modifier::BOLD))
+                .bottom_margin(1),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title(titles.0)
+                .border_type(BorderType::Plain),
+        )
+        .widths(&[Constraint::Percentage(60), Constraint::Percentage(40)]);
+
+    f.render_widget(table, layout_chunk);
+}
+
+fn draw_bulk_results<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "Indicator",
+                width: get_percentage_width(layout_chunk.width, 0.22),
+            },
+            TableHeaderItem {
+                text: "Status",
+                width: get_percentage_width(layout_chunk.width, 0.1),
+            },
+            TableHeaderItem {
+                text: "Addresses",
+                width: get_percentage_width(layout_chunk.width, 0.24),
+            },
+            TableHeaderItem {
+                text: "Country",
+                width: get_percentage_width(layout_chunk.width, 0.08),
+            },
+            TableHeaderItem {
+                text: "ASN",
+                width: get_percentage_width(layout_chunk.width, 0.1),
+            },
+            TableHeaderItem {
+                text: "Ports",
+                width: get_percentage_width(layout_chunk.width, 0.16),
+            },
+            TableHeaderItem {
+                text: "VirusTotal",
+                width: get_percentage_width(layout_chunk.width, 0.1),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::BulkResults,
+        current_route.hovered_block == ActiveBlock::BulkResults,
+    );
+
+    let items = app
+        .bulk
+        .items
+        .iter()
+        .map(|item| TableItem {
+            format: item.summary_to_vec(),
+        })
+        .collect::<Vec<TableItem>>();
+
+    let done = app
+        .bulk
+        .items
+        .iter()
+        .filter(|item| item.status != ResultStatus::NotQueried)
+        .count();
+    let title = format!(
+        "Bulk lookup: {} ({} of {} done), Enter to look up",
+        app.bulk.source,
+        done,
+        items.len()
+    );
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        (&title, &header),
+        &items,
+        app.bulk.index,
+        highlight_state,
+    );
+}
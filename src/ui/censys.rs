@@ -1,11 +1,11 @@
-use super::super::app::{ActiveBlock, App, CENSYS_MENU};
+use super::super::app::{ActiveBlock, App, ResultStatus, CENSYS_MENU};
 use crate::ui::{
     draw_map, draw_selectable_list, draw_table, util::get_percentage_width, TableHeader,
     TableHeaderItem, TableItem,
 };
 use tui::{
     backend::Backend,
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Span, Spans},
     widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table, Wrap},
@@ -63,15 +63,19 @@ where
         items: vec![
             TableHeaderItem {
                 text: "Port",
-                width: get_percentage_width(layout_chunk.width, 0.3),
+                width: get_percentage_width(layout_chunk.width, 0.2),
             },
             TableHeaderItem {
                 text: "Transport",
-                width: get_percentage_width(layout_chunk.width, 0.3),
+                width: get_percentage_width(layout_chunk.width, 0.25),
             },
             TableHeaderItem {
                 text: "Service",
-                width: get_percentage_width(layout_chunk.width, 0.3),
+                width: get_percentage_width(layout_chunk.width, 0.25),
+            },
+            TableHeaderItem {
+                text: "Certificate",
+                width: get_percentage_width(layout_chunk.width, 0.15),
             },
         ],
     };
@@ -102,6 +106,10 @@ where
                     Some(service_name) => service_name.to_string(),
                     None => "N/A".to_string(),
                 },
+                match &services.certificate {
+                    Some(certificate) if !certificate.is_empty() => "🔒 <Enter>".to_string(),
+                    _ => String::new(),
+                },
             ],
         })
         .collect::<Vec<TableItem>>();
@@ -253,3 +261,354 @@ where
         draw_map(f, coordinates.latitude, coordinates.longitude, chunks[2]);
     }
 }
+
+pub fn draw_censys_history<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(10), Constraint::Percentage(90)].as_ref())
+        .split(layout_chunk);
+
+    draw_censys_menu(f, app, chunks[0]);
+
+    let message = match (&app.censys.history_error, app.censys.history_status) {
+        (Some(error), _) => Some(error.as_str()),
+        (None, ResultStatus::NotQueried | ResultStatus::Loading) => Some("Loading host history..."),
+        (None, ResultStatus::NotFound) => Some("Censys has no history recorded for this host"),
+        (None, ResultStatus::Found) => None,
+    };
+    match message {
+        Some(message) => draw_message(f, "History", message, chunks[1]),
+        None => draw_history_timeline(f, app, chunks[1]),
+    }
+}
+
+fn draw_message<B>(f: &mut Frame<B>, title: &str, message: &str, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let paragraph = Paragraph::new(message)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title(title)
+                .border_type(BorderType::Plain),
+        )
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(paragraph, layout_chunk);
+}
+
+pub fn draw_history_timeline<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "Timestamp",
+                width: get_percentage_width(layout_chunk.width, 0.2),
+            },
+            TableHeaderItem {
+                text: "Event",
+                width: get_percentage_width(layout_chunk.width, 0.15),
+            },
+            TableHeaderItem {
+                text: "Port",
+                width: get_percentage_width(layout_chunk.width, 0.1),
+            },
+            TableHeaderItem {
+                text: "Service",
+                width: get_percentage_width(layout_chunk.width, 0.15),
+            },
+            TableHeaderItem {
+                text: "Changes",
+                width: get_percentage_width(layout_chunk.width, 0.4),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::CensysHistory,
+        current_route.hovered_block == ActiveBlock::CensysHistory,
+    );
+
+    let items = app
+        .censys
+        .history_items
+        .result
+        .events
+        .iter()
+        .map(|event| {
+            let service = event.service();
+            TableItem {
+                format: vec![
+                    event.timestamp.to_string(),
+                    event.kind(),
+                    service
+                        .and_then(|s| s.id.port)
+                        .map(|port| port.to_string())
+                        .unwrap_or_default(),
+                    service
+                        .and_then(|s| s.id.service_name.clone())
+                        .unwrap_or_default(),
+                    event.details(),
+                ],
+            }
+        })
+        .collect::<Vec<TableItem>>();
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        ("History", &header),
+        &items,
+        app.censys.history_index,
+        highlight_state,
+    );
+}
+
+pub fn draw_censys_certificate<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let message = match (&app.censys.certificate_error, app.censys.certificate_status) {
+        // The certificate itself loaded, a failure listing its hosts is shown in their pane
+        (_, ResultStatus::Found) => None,
+        (Some(error), _) => Some(error.as_str()),
+        (None, ResultStatus::NotQueried | ResultStatus::Loading) => Some("Loading certificate..."),
+        (None, ResultStatus::NotFound) => Some("Censys has no record of this certificate"),
+    };
+    if let Some(message) = message {
+        draw_message(f, "🔒 Certificate", message, layout_chunk);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+        .split(layout_chunk);
+
+    let left_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(13), Constraint::Min(3)].as_ref())
+        .split(chunks[0]);
+
+    draw_certificate_summary(f, app, left_chunks[0]);
+    draw_certificate_names(f, app, left_chunks[1]);
+    draw_certificate_hosts(f, app, chunks[1]);
+}
+
+pub fn draw_certificate_summary<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let summary_vec = app.censys.certificate_items.result.summary_to_vec();
+
+    let rows = summary_vec.iter().map(|i| {
+        let cells = i.iter().map(|c| {
+            let x = c.clone();
+            Cell::from(x)
+        });
+        Row::new(cells)
+            .style(Style::default().add_modifier(Modifier::BOLD))
+            .bottom_margin(1)
+    });
+
+    let summary = Table::new(rows)
+        .header(Row::new(vec!["", ""]))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("🔒 Certificate")
+                .border_type(BorderType::Plain),
+        )
+        .widths(&[Constraint::Length(12), Constraint::Percentage(100)]);
+
+    f.render_widget(summary, layout_chunk);
+}
+
+pub fn draw_certificate_names<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let names = app
+        .censys
+        .certificate_items
+        .result
+        .names
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|name| Spans::from(Span::raw(name)))
+        .collect::<Vec<Spans>>();
+
+    let paragraph = Paragraph::new(names)
+        .wrap(Wrap { trim: true })
+        .style(Style::default().fg(app.user_config.theme.text))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("Subject Alternative Names")
+                .border_type(BorderType::Plain),
+        );
+
+    f.render_widget(paragraph, layout_chunk);
+}
+
+pub fn draw_certificate_hosts<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "IP",
+                width: get_percentage_width(layout_chunk.width, 0.4),
+            },
+            TableHeaderItem {
+                text: "Last Observed",
+                width: get_percentage_width(layout_chunk.width, 0.6),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::CensysCertificate,
+        current_route.hovered_block == ActiveBlock::CensysCertificate,
+    );
+
+    let items = app
+        .censys
+        .certificate_host_items
+        .result
+        .hosts
+        .iter()
+        .map(|host| TableItem {
+            format: vec![
+                host.ip.to_string(),
+                host.observed_at.as_deref().unwrap_or("N/A").to_string(),
+            ],
+        })
+        .collect::<Vec<TableItem>>();
+
+    let title = match &app.censys.certificate_error {
+        Some(error) => error.as_str(),
+        None => "Hosts presenting this certificate",
+    };
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        (title, &header),
+        &items,
+        app.censys.certificate_host_index,
+        highlight_state,
+    );
+}
+
+pub fn draw_censys_search<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "IP",
+                width: get_percentage_width(layout_chunk.width, 0.15),
+            },
+            TableHeaderItem {
+                text: "Services",
+                width: get_percentage_width(layout_chunk.width, 0.3),
+            },
+            TableHeaderItem {
+                text: "Country",
+                width: get_percentage_width(layout_chunk.width, 0.1),
+            },
+            TableHeaderItem {
+                text: "Autonomous System",
+                width: get_percentage_width(layout_chunk.width, 0.25),
+            },
+            TableHeaderItem {
+                text: "Last Updated",
+                width: get_percentage_width(layout_chunk.width, 0.2),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::CensysSearchResults,
+        current_route.hovered_block == ActiveBlock::CensysSearchResults,
+    );
+
+    let search_result = &app.censys.search_items.result;
+
+    let items = search_result
+        .hits
+        .iter()
+        .map(|hit| TableItem {
+            format: vec![
+                hit.ip.to_string(),
+                hit.services
+                    .iter()
+                    .map(|service| {
+                        format!(
+                            "{}/{}",
+                            service.port.map(|p| p.to_string()).unwrap_or_default(),
+                            service.service_name.as_deref().unwrap_or("N/A")
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                hit.location
+                    .as_ref()
+                    .and_then(|location| location.country_code.clone())
+                    .unwrap_or_else(|| "N/A".to_string()),
+                hit.autonomous_system
+                    .as_ref()
+                    .map(|system| {
+                        format!(
+                            "AS{} {}",
+                            system.asn.map(|asn| asn.to_string()).unwrap_or_default(),
+                            system.name.as_deref().unwrap_or_default()
+                        )
+                    })
+                    .unwrap_or_else(|| "N/A".to_string()),
+                hit.last_updated_at.as_deref().unwrap_or("N/A").to_string(),
+            ],
+        })
+        .collect::<Vec<TableItem>>();
+
+    let more = match search_result.next_cursor() {
+        Some(_) => format!(", '{}' to load more", app.user_config.keys.load_more),
+        None => String::new(),
+    };
+    let title = format!(
+        "Censys: {} ({} of {}{})",
+        app.censys.search_query,
+        search_result.hits.len(),
+        search_result.total,
+        more
+    );
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        (&title, &header),
+        &items,
+        app.censys.search_index,
+        highlight_state,
+    );
+}
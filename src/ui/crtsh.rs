@@ -0,0 +1,156 @@
+use super::super::app::{ActiveBlock, App};
+use crate::ui::util::get_percentage_width;
+use crate::ui::{draw_table, TableHeader, TableHeaderItem, TableItem};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn draw_crtsh<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)].as_ref())
+        .split(layout_chunk);
+
+    let certificate_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)].as_ref())
+        .split(chunks[0]);
+
+    draw_crtsh_certificates(f, app, certificate_chunks[0]);
+    draw_crtsh_names(f, app, certificate_chunks[1]);
+    draw_crtsh_subdomains(f, app, chunks[1]);
+}
+
+pub fn draw_crtsh_certificates<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "Not Before",
+                width: get_percentage_width(layout_chunk.width, 0.13),
+            },
+            TableHeaderItem {
+                text: "Not After",
+                width: get_percentage_width(layout_chunk.width, 0.13),
+            },
+            TableHeaderItem {
+                text: "Issuer",
+                width: get_percentage_width(layout_chunk.width, 0.3),
+            },
+            TableHeaderItem {
+                text: "Common Name",
+                width: get_percentage_width(layout_chunk.width, 0.36),
+            },
+            TableHeaderItem {
+                text: "SANs",
+                width: get_percentage_width(layout_chunk.width, 0.08),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::CrtshCertificates,
+        current_route.hovered_block == ActiveBlock::CrtshCertificates,
+    );
+
+    let items = app
+        .crtsh
+        .certificates
+        .iter()
+        .map(|certificate| TableItem {
+            format: certificate.summary_to_vec(),
+        })
+        .collect::<Vec<TableItem>>();
+
+    let title = match &app.crtsh.error {
+        Some(error) => format!("📜 {}", error),
+        None => format!("📜 crt.sh Certificates ({})", items.len()),
+    };
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        (&title, &header),
+        &items,
+        app.crtsh.certificate_index,
+        highlight_state,
+    );
+}
+
+pub fn draw_crtsh_names<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let names = app
+        .crtsh
+        .certificates
+        .get(app.crtsh.certificate_index)
+        .map(|certificate| certificate.names().join("\n"))
+        .unwrap_or_default();
+
+    let paragraph = Paragraph::new(names)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("Subject Alternative Names")
+                .border_type(BorderType::Plain),
+        )
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(paragraph, layout_chunk);
+}
+
+pub fn draw_crtsh_subdomains<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![TableHeaderItem {
+            text: "Name",
+            width: get_percentage_width(layout_chunk.width, 1.0),
+        }],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::CrtshSubdomains,
+        current_route.hovered_block == ActiveBlock::CrtshSubdomains,
+    );
+
+    let items = app
+        .crtsh
+        .subdomains
+        .iter()
+        .map(|subdomain| TableItem {
+            format: vec![subdomain.to_owned()],
+        })
+        .collect::<Vec<TableItem>>();
+
+    let title = format!(
+        "Subdomains ({}), '{}' to look up all",
+        items.len(),
+        app.user_config.keys.bulk_lookup
+    );
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        (&title, &header),
+        &items,
+        app.crtsh.subdomain_index,
+        highlight_state,
+    );
+}
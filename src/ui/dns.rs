@@ -0,0 +1,130 @@
+use super::super::app::{ActiveBlock, App, ResultStatus};
+use crate::ui::util::get_percentage_width;
+use crate::ui::{draw_table, TableHeader, TableHeaderItem, TableItem};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    Frame,
+};
+
+pub fn draw_dns<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+        .split(layout_chunk);
+
+    draw_dns_records(f, app, chunks[0]);
+    draw_passive_dns(f, app, chunks[1]);
+}
+
+pub fn draw_dns_records<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "Type",
+                width: get_percentage_width(layout_chunk.width, 0.1),
+            },
+            TableHeaderItem {
+                text: "Value",
+                width: get_percentage_width(layout_chunk.width, 0.55),
+            },
+            TableHeaderItem {
+                text: "Detail",
+                width: get_percentage_width(layout_chunk.width, 0.2),
+            },
+            TableHeaderItem {
+                text: "TTL",
+                width: get_percentage_width(layout_chunk.width, 0.15),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::DnsRecords,
+        current_route.hovered_block == ActiveBlock::DnsRecords,
+    );
+
+    let message = |text: &str| {
+        vec![TableItem {
+            format: vec![String::new(), text.to_string()],
+        }]
+    };
+
+    let items = match app.dns.status {
+        ResultStatus::Found if app.dns.records.is_empty() => message("No records found"),
+        ResultStatus::Found => app
+            .dns
+            .records
+            .iter()
+            .map(|record| TableItem {
+                format: record.summary_to_vec(),
+            })
+            .collect::<Vec<TableItem>>(),
+        ResultStatus::NotFound => message("Lookup failed, check the [dns] servers in config"),
+        ResultStatus::Loading => message("Resolving..."),
+        ResultStatus::NotQueried => message("Only IP addresses and domains are resolved"),
+    };
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        ("Live DNS - <Enter> to pivot", &header),
+        &items,
+        app.dns.record_index,
+        highlight_state,
+    );
+}
+
+pub fn draw_passive_dns<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "Host",
+                width: get_percentage_width(layout_chunk.width, 0.55),
+            },
+            TableHeaderItem {
+                text: "Source",
+                width: get_percentage_width(layout_chunk.width, 0.2),
+            },
+            TableHeaderItem {
+                text: "Last Seen",
+                width: get_percentage_width(layout_chunk.width, 0.25),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::DnsPassive,
+        current_route.hovered_block == ActiveBlock::DnsPassive,
+    );
+
+    let items = app
+        .passive_dns()
+        .iter()
+        .map(|record| TableItem {
+            format: record.summary_to_vec(),
+        })
+        .collect::<Vec<TableItem>>();
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        ("Passive DNS - <Enter> to pivot", &header),
+        &items,
+        app.dns.passive_index,
+        highlight_state,
+    );
+}
@@ -0,0 +1,119 @@
+use super::super::app::{ActiveBlock, App};
+use crate::indicator::Indicator;
+use crate::ui::util::get_percentage_width;
+use crate::ui::{draw_table, TableHeader, TableHeaderItem, TableItem};
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Style},
+    text::Text,
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn draw_extract<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    match app.get_current_route().active_block {
+        ActiveBlock::ExtractInput => draw_extract_input(f, app, layout_chunk),
+        _ => draw_extract_results(f, app, layout_chunk),
+    }
+}
+
+fn draw_extract_input<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    // Keep the end of long pastes in sight
+    let visible = layout_chunk.height.saturating_sub(2) as usize;
+    let lines = app.extract.text.lines().count() + app.extract.text.ends_with('\n') as usize;
+    let scroll = lines.saturating_sub(visible) as u16;
+
+    let paragraph = Paragraph::new(Text::raw(app.extract.text.to_owned()))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("Paste an alert or email, Ctrl-d to extract its indicators, Esc to cancel")
+                .border_style(Style::default().fg(Color::LightYellow))
+                .border_type(BorderType::Plain),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((scroll, 0));
+
+    f.render_widget(paragraph, layout_chunk);
+}
+
+fn draw_extract_results<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem { text: "", width: 4 },
+            TableHeaderItem {
+                text: "Type",
+                width: get_percentage_width(layout_chunk.width, 0.1),
+            },
+            TableHeaderItem {
+                text: "Indicator",
+                width: get_percentage_width(layout_chunk.width, 0.55),
+            },
+            TableHeaderItem {
+                text: "Watchlist",
+                width: get_percentage_width(layout_chunk.width, 0.3),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::ExtractResults,
+        current_route.hovered_block == ActiveBlock::ExtractResults,
+    );
+
+    let items = app
+        .extract
+        .indicators
+        .iter()
+        .zip(&app.extract.selected)
+        .map(|(indicator, selected)| TableItem {
+            format: vec![
+                String::from(match selected {
+                    true => "[x]",
+                    false => "[ ]",
+                }),
+                indicator.kind().to_owned(),
+                indicator.to_string(),
+                watchlist_banners(app, indicator),
+            ],
+        })
+        .collect::<Vec<TableItem>>();
+
+    let title = format!(
+        "{} indicators, {} reserved or ours skipped. Space to select, Enter to look up, '{}' to look up the selection in bulk",
+        items.len(),
+        app.extract.skipped,
+        app.user_config.keys.bulk_lookup
+    );
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        (&title, &header),
+        &items,
+        app.extract.index,
+        highlight_state,
+    );
+}
+
+fn watchlist_banners(app: &App, indicator: &Indicator) -> String {
+    app.watchlist
+        .matches(indicator)
+        .iter()
+        .map(|entry| entry.banner())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
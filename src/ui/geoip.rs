@@ -0,0 +1,63 @@
+use super::super::app::{App, ResultStatus};
+use crate::ui::draw_map;
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table},
+    Frame,
+};
+
+pub fn draw_geoip<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)].as_ref())
+        .split(layout_chunk);
+
+    draw_geoip_summary(f, app, chunks[0]);
+    if let Some((lat, long)) = app.geoip.record.coordinates() {
+        draw_map(f, lat, long, chunks[1]);
+    }
+}
+
+pub fn draw_geoip_summary<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .style(Style::default().fg(Color::White))
+        .title("🌐 GeoIP (MaxMind)")
+        .border_type(BorderType::Plain);
+
+    let message = match app.geoip.status {
+        ResultStatus::Found => None,
+        ResultStatus::NotFound => Some("The address is not in the local databases"),
+        ResultStatus::Loading => Some("Looking up the address..."),
+        ResultStatus::NotQueried => Some("Only IP addresses are looked up"),
+    };
+
+    if let Some(message) = message {
+        let paragraph = Paragraph::new(message)
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, layout_chunk);
+        return;
+    }
+
+    let rows = app.geoip.record.summary_to_vec().into_iter().map(|i| {
+        Row::new(i.into_iter().map(Cell::from))
+            .style(Style::default().add_modifier(Modifier::BOLD))
+            .bottom_margin(1)
+    });
+
+    let summary = Table::new(rows)
+        .header(Row::new(vec!["", ""]))
+        .block(block)
+        .widths(&[Constraint::Length(16), Constraint::Percentage(100)]);
+
+    f.render_widget(summary, layout_chunk);
+}
@@ -0,0 +1,113 @@
+use super::super::app::App;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+pub fn draw_greynoise<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
+        .split(layout_chunk);
+
+    let right_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+        .split(chunks[1]);
+
+    draw_greynoise_summary(f, app, chunks[0]);
+    draw_greynoise_list(f, "Tags", &app.greynoise.ip.tags, right_chunks[0]);
+    draw_greynoise_list(
+        f,
+        "CVE Exploitation Attempts",
+        &app.greynoise.ip.cve,
+        right_chunks[1],
+    );
+}
+
+fn draw_greynoise_summary<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(10), Constraint::Length(5)].as_ref())
+        .split(layout_chunk);
+
+    let ip = &app.greynoise.ip;
+    let color = match ip.classification.as_str() {
+        _ if ip.is_benign() => Color::Green,
+        "malicious" => Color::Red,
+        _ => Color::Yellow,
+    };
+
+    let rows = ip.summary_to_vec().into_iter().map(|i| {
+        let style = match i[0].as_str() {
+            "Classification" => Style::default().fg(color),
+            _ => Style::default(),
+        };
+        Row::new(i.into_iter().map(Cell::from))
+            .style(style.add_modifier(Modifier::BOLD))
+            .bottom_margin(1)
+    });
+
+    let summary = Table::new(rows)
+        .header(Row::new(vec!["", ""]))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("📡 GreyNoise")
+                .border_type(BorderType::Plain),
+        )
+        .widths(&[Constraint::Length(16), Constraint::Percentage(100)]);
+
+    f.render_widget(summary, chunks[0]);
+
+    let message = Paragraph::new(Spans::from(Span::raw(ip.message.to_owned())))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("Message")
+                .border_type(BorderType::Plain),
+        )
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(message, chunks[1]);
+}
+
+fn draw_greynoise_list<B>(f: &mut Frame<B>, title: &str, items: &[String], layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let rows = match items.is_empty() {
+        // The community API only returns tags and CVEs to enterprise accounts
+        true => vec![Row::new(vec!["N/A"])],
+        false => items
+            .iter()
+            .map(|item| {
+                Row::new(vec![item.to_owned()]).style(Style::default().add_modifier(Modifier::BOLD))
+            })
+            .collect(),
+    };
+
+    let table = Table::new(rows)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title(title.to_owned())
+                .border_type(BorderType::Plain),
+        )
+        .widths(&[Constraint::Percentage(100)]);
+
+    f.render_widget(table, layout_chunk);
+}
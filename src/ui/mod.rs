@@ -1,17 +1,53 @@
+pub mod abusech;
+pub mod abuseipdb;
+pub mod asn;
+pub mod bulk;
 pub mod censys;
+pub mod crtsh;
+pub mod dns;
+pub mod extract;
+pub mod geoip;
+pub mod greynoise;
+pub mod otx;
+pub mod rdap;
+pub mod search_result;
+pub mod securitytrails;
 pub mod shodan;
+pub mod urlscan;
 pub mod util;
 pub mod virustotal;
 
 use super::{
-    app::{ActiveBlock, App, RouteId},
+    app::{ActiveBlock, App, ResultStatus, RouteId},
     banner::BANNER,
+    indicator,
+    watchlist::WatchlistEntry,
 };
 use crate::ui::{
-    censys::{draw_censys, draw_censys_geo_lookup},
-    shodan::{draw_shodan, draw_shodan_geo_lookup},
-    util::get_color,
-    virustotal::{draw_virustotal_community, draw_virustotal_details, draw_virustotal_detection},
+    abusech::draw_abusech,
+    abuseipdb::draw_abuseipdb,
+    asn::draw_asn,
+    bulk::draw_bulk,
+    censys::{
+        draw_censys, draw_censys_certificate, draw_censys_geo_lookup, draw_censys_history,
+        draw_censys_search,
+    },
+    crtsh::draw_crtsh,
+    dns::draw_dns,
+    extract::draw_extract,
+    geoip::draw_geoip,
+    greynoise::draw_greynoise,
+    otx::draw_otx,
+    rdap::draw_rdap,
+    search_result::draw_search_result_page,
+    securitytrails::draw_securitytrails,
+    shodan::{draw_shodan, draw_shodan_geo_lookup, draw_shodan_search, draw_shodan_service_detail},
+    urlscan::draw_urlscan,
+    util::{get_color, get_watchlist_color},
+    virustotal::{
+        draw_virustotal_community, draw_virustotal_details, draw_virustotal_detection,
+        draw_virustotal_relations,
+    },
 };
 use tui::{
     backend::Backend,
@@ -39,17 +75,12 @@ impl From<MenuItem> for usize {
     }
 }
 
-#[derive(PartialEq)]
+#[derive(PartialEq, Default)]
 pub enum ColumnId {
+    #[default]
     None,
 }
 
-impl Default for ColumnId {
-    fn default() -> Self {
-        ColumnId::None
-    }
-}
-
 #[derive(Default)]
 pub struct TableHeaderItem<'a> {
     text: &'a str,
@@ -68,14 +99,88 @@ pub fn draw_main_layout<B>(f: &mut Frame<B>, app: &App)
 where
     B: Backend,
 {
+    // Watchlist matches and tags of the searched indicator stay in sight on every page of
+    // its results
+    let has_banner = !app.list_tags.is_empty()
+        || !app.watchlist_matches.is_empty()
+        || app.export_message.is_some();
+    if !has_banner || app.get_current_route().id == RouteId::Home {
+        let parent_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints([Constraint::Length(3), Constraint::Min(2)].as_ref())
+            .split(f.size());
+
+        draw_menu_search_help_box(f, app, parent_layout[0]);
+        draw_routes(f, app, parent_layout[1]);
+        return;
+    }
+
     let parent_layout = Layout::default()
         .direction(Direction::Vertical)
         .margin(2)
-        .constraints([Constraint::Length(3), Constraint::Min(2)].as_ref())
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Length(1),
+                Constraint::Min(2),
+            ]
+            .as_ref(),
+        )
         .split(f.size());
 
     draw_menu_search_help_box(f, app, parent_layout[0]);
-    draw_routes(f, app, parent_layout[1]);
+    draw_banner(f, app, parent_layout[1]);
+    draw_routes(f, app, parent_layout[2]);
+}
+
+pub fn draw_banner<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let mut spans = vec![];
+    for entry in &app.watchlist_matches {
+        spans.push(Span::styled(
+            format!(" {} ", entry.banner()),
+            Style::default()
+                .fg(Color::Black)
+                .bg(get_watchlist_color(entry))
+                .add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::raw(" "));
+    }
+
+    if let Some(message) = &app.export_message {
+        spans.push(Span::styled(
+            format!("{} ", message),
+            Style::default().fg(app.user_config.theme.hint),
+        ));
+    }
+
+    if app.list_tags.is_empty() {
+        f.render_widget(Paragraph::new(Spans::from(spans)), layout_chunk);
+        return;
+    }
+
+    spans.push(Span::styled(
+        " TAGS ",
+        Style::default()
+            .fg(Color::Black)
+            .bg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    ));
+    for (index, tag) in app.list_tags.iter().enumerate() {
+        spans.push(Span::raw(match index {
+            0 => " ",
+            _ => " | ",
+        }));
+        spans.push(Span::styled(
+            tag.to_owned(),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+
+    f.render_widget(Paragraph::new(Spans::from(spans)), layout_chunk);
 }
 
 pub fn draw_routes<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
@@ -103,6 +208,54 @@ where
         RouteId::CensysGeoLookup => {
             draw_censys_geo_lookup(f, app, chunks[0]);
         }
+        RouteId::CensysHistory => {
+            draw_censys_history(f, app, chunks[0]);
+        }
+        RouteId::CensysCertificate => {
+            draw_censys_certificate(f, app, chunks[0]);
+        }
+        RouteId::CensysSearch => {
+            draw_censys_search(f, app, chunks[0]);
+        }
+        RouteId::Dns => {
+            draw_dns(f, app, chunks[0]);
+        }
+        RouteId::GeoIp => {
+            draw_geoip(f, app, chunks[0]);
+        }
+        RouteId::Rdap => {
+            draw_rdap(f, app, chunks[0]);
+        }
+        RouteId::Abuseipdb => {
+            draw_abuseipdb(f, app, chunks[0]);
+        }
+        RouteId::Greynoise => {
+            draw_greynoise(f, app, chunks[0]);
+        }
+        RouteId::Otx => {
+            draw_otx(f, app, chunks[0]);
+        }
+        RouteId::Abusech => {
+            draw_abusech(f, app, chunks[0]);
+        }
+        RouteId::Crtsh => {
+            draw_crtsh(f, app, chunks[0]);
+        }
+        RouteId::Bulk => {
+            draw_bulk(f, app, chunks[0]);
+        }
+        RouteId::SecurityTrails => {
+            draw_securitytrails(f, app, chunks[0]);
+        }
+        RouteId::Urlscan => {
+            draw_urlscan(f, app, chunks[0]);
+        }
+        RouteId::Asn => {
+            draw_asn(f, app, chunks[0]);
+        }
+        RouteId::Extract => {
+            draw_extract(f, app, chunks[0]);
+        }
         RouteId::VirustotalDetection => {
             draw_virustotal_detection(f, app, chunks[0]);
         }
@@ -112,6 +265,9 @@ where
         RouteId::VirustotalCommunity => {
             draw_virustotal_community(f, app, chunks[0]);
         }
+        RouteId::VirustotalRelations => {
+            draw_virustotal_relations(f, app, chunks[0]);
+        }
         RouteId::NotFound => {
             draw_not_found(f, app, chunks[0]);
         }
@@ -127,6 +283,12 @@ where
         RouteId::ShodanGeoLookup => {
             draw_shodan_geo_lookup(f, app, chunks[0]);
         }
+        RouteId::ShodanSearch => {
+            draw_shodan_search(f, app, chunks[0]);
+        }
+        RouteId::ShodanServiceDetail => {
+            draw_shodan_service_detail(f, app, chunks[0]);
+        }
         RouteId::Error => {} // This is handled as a "full screen" route in main.rs
     };
 }
@@ -156,7 +318,7 @@ where
     );
 
     let input_string: String = app.input.iter().collect();
-    let lines = Text::from((&input_string).as_str());
+    let lines = Text::from(input_string.as_str());
     let input = Paragraph::new(lines).block(
         Block::default()
             .borders(Borders::ALL)
@@ -165,7 +327,7 @@ where
 
     f.render_widget(input, chunks[0]);
 
-    let menu = vec!["Home", "Shodan", "Virustotal", "Censys", "Quit"]
+    let menu = ["Home", "Shodan", "Virustotal", "Censys", "Quit"]
         .iter()
         .map(|t| {
             let (first, rest) = t.split_at(1);
@@ -268,6 +430,27 @@ where
         Spans::from(vec![Span::raw("'/' to search")]),
         Spans::from(vec![Span::raw("'s' to access shodan")]),
         Spans::from(vec![Span::raw("'v' to access virustotal")]),
+        Spans::from(vec![Span::raw("'d' to access dns")]),
+        Spans::from(vec![Span::raw("'g' to access geoip")]),
+        Spans::from(vec![Span::raw("'W' to access rdap whois")]),
+        Spans::from(vec![Span::raw("'a' to access abuseipdb")]),
+        Spans::from(vec![Span::raw("'b' to access greynoise")]),
+        Spans::from(vec![Span::raw("'o' to access otx")]),
+        Spans::from(vec![Span::raw("'u' to access urlhaus and threatfox")]),
+        Spans::from(vec![Span::raw("'t' to access crt.sh")]),
+        Spans::from(vec![Span::raw("'p' to access dns history")]),
+        Spans::from(vec![Span::raw("'w' to access urlscan")]),
+        Spans::from(vec![Span::raw("'i' to access asn context")]),
+        Spans::from(vec![Span::raw(
+            "'f' to paste text and extract its indicators",
+        )]),
+        Spans::from(vec![Span::raw("")]),
+        Spans::from(vec![Span::raw("'censys: <query>' to search Censys hosts")]),
+        Spans::from(vec![Span::raw("'shodan: <query>' to search Shodan hosts")]),
+        Spans::from(vec![Span::raw("'AS13335' to look up an autonomous system")]),
+        Spans::from(vec![Span::raw(
+            "'203.0.113.0/24' to look up every host of a network",
+        )]),
     ])
     .style(Style::default().fg(app.user_config.theme.text))
     .alignment(Alignment::Center)
@@ -320,29 +503,6 @@ where
     f.render_widget(api_view, layout_chunk);
 }
 
-pub fn draw_search_result_page<B>(f: &mut Frame<B>, _app: &App, layout_chunk: Rect)
-where
-    B: Backend,
-{
-    let home = Paragraph::new(vec![
-        Spans::from(vec![Span::raw("")]),
-        Spans::from(vec![Span::raw("Lookup complete!")]),
-        Spans::from(vec![Span::raw("")]),
-        Spans::from(vec![Span::raw("'/' to search")]),
-        Spans::from(vec![Span::raw("'s' to access shodan")]),
-        Spans::from(vec![Span::raw("'v' to access virustotal")]),
-    ])
-    .alignment(Alignment::Center)
-    .block(
-        Block::default()
-            .borders(Borders::ALL)
-            .style(Style::default().fg(Color::White))
-            .title("Home")
-            .border_type(BorderType::Plain),
-    );
-    f.render_widget(home, layout_chunk);
-}
-
 pub fn draw_error_screen<B>(f: &mut Frame<B>, app: &App)
 where
     B: Backend,
@@ -415,6 +575,17 @@ fn draw_selectable_list<B, S>(
     f.render_stateful_widget(list, layout_chunk, &mut state);
 }
 
+fn watchlist_match(app: &App, cells: &[String]) -> Option<WatchlistEntry> {
+    if app.watchlist.is_empty() {
+        return None;
+    }
+
+    cells
+        .iter()
+        .flat_map(|cell| indicator::extract(cell))
+        .find_map(|indicator| app.watchlist.matches(&indicator).first().cloned().cloned())
+}
+
 fn draw_table<B>(
     f: &mut Frame<B>,
     app: &App,
@@ -439,18 +610,31 @@ fn draw_table<B>(
         .and_then(|height| selected_index.checked_sub(height as usize))
         .unwrap_or(0);
 
-    let rows = items.iter().skip(offset).enumerate().map(|(i, item)| {
-        let formatted_row = item.format.clone();
-        let mut style = Style::default().fg(app.user_config.theme.text);
+    // Rows past the bottom of the chunk are never drawn, so they aren't matched against the
+    // watchlist either
+    let visible_rows = layout_chunk.height as usize;
+    let rows = items
+        .iter()
+        .skip(offset)
+        .take(visible_rows)
+        .enumerate()
+        .map(|(i, item)| {
+            let formatted_row = item.format.clone();
+            let mut style = Style::default().fg(app.user_config.theme.text);
+
+            // Rows mentioning a watchlisted indicator take the colour of its entry
+            if let Some(entry) = watchlist_match(app, &formatted_row) {
+                style = style.fg(get_watchlist_color(&entry));
+            }
 
-        // Next check if the item is under selection.
-        if Some(i) == selected_index.checked_sub(offset) {
-            style = selected_style;
-        }
+            // Next check if the item is under selection.
+            if Some(i) == selected_index.checked_sub(offset) {
+                style = selected_style;
+            }
 
-        // Return row styled data
-        Row::new(formatted_row).style(style)
-    });
+            // Return row styled data
+            Row::new(formatted_row).style(style)
+        });
 
     let widths = header
         .items
@@ -505,6 +689,12 @@ where
         ActiveBlock::VirustotalNotFound => "Virustotal",
         ActiveBlock::ShodanNotFound => "Shodan",
         ActiveBlock::CensysNotFound => "Censys",
+        ActiveBlock::AbuseipdbNotFound => "AbuseIPDB",
+        ActiveBlock::GreynoiseNotFound => "GreyNoise",
+        ActiveBlock::OtxNotFound => "OTX",
+        ActiveBlock::AbusechNotFound => "URLhaus or ThreatFox",
+        ActiveBlock::CrtshNotFound => "crt.sh",
+        ActiveBlock::SecurityTrailsNotFound => "SecurityTrails",
         _ => "",
     };
 
@@ -532,19 +722,31 @@ pub fn draw_not_queried<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
 where
     B: Backend,
 {
-    let plugin = match app.get_current_route().active_block {
-        ActiveBlock::VirustotalNotQueried => "Virustotal",
-        ActiveBlock::ShodanNotQueried => "Shodan",
-        ActiveBlock::CensysNotQueried => "Censys",
-        _ => "",
+    let (plugin, status) = match app.get_current_route().active_block {
+        ActiveBlock::VirustotalNotQueried => ("Virustotal", app.virustotal.status),
+        ActiveBlock::ShodanNotQueried => ("Shodan", app.shodan.status),
+        ActiveBlock::CensysNotQueried => ("Censys", app.censys.status),
+        ActiveBlock::AbuseipdbNotQueried => ("AbuseIPDB", app.abuseipdb.status),
+        ActiveBlock::GreynoiseNotQueried => ("GreyNoise", app.greynoise.status),
+        ActiveBlock::OtxNotQueried => ("OTX", app.otx.status),
+        ActiveBlock::AbusechNotQueried => ("URLhaus or ThreatFox", app.abusech.status),
+        ActiveBlock::CrtshNotQueried => ("crt.sh", app.crtsh.status),
+        ActiveBlock::SecurityTrailsNotQueried => ("SecurityTrails", app.securitytrails.status),
+        ActiveBlock::UrlscanNotQueried => ("urlscan.io", app.urlscan.status),
+        ActiveBlock::AsnNotQueried => ("ASN context", app.asn.status),
+        _ => ("", ResultStatus::NotQueried),
     };
 
+    let message = match status {
+        ResultStatus::Loading => format!("\n{} is still loading, try again in a moment.", plugin),
+        _ => format!(
+            "\n{} has not been queried yet, press '/' to search for an IP.",
+            plugin
+        ),
+    };
     let text = vec![
         Spans::from(Span::styled(
-            format!(
-                "\n{} has not been queried yet, press '/' to search for an IP.",
-                plugin
-            ),
+            message,
             Style::default().fg(app.user_config.theme.inactive),
         )),
         Spans::from(Span::styled(
@@ -570,6 +772,12 @@ where
         ActiveBlock::VirustotalUnloaded => "Virustotal",
         ActiveBlock::ShodanUnloaded => "Shodan",
         ActiveBlock::CensysUnloaded => "Censys",
+        ActiveBlock::GeoIpUnloaded => "GeoIP",
+        ActiveBlock::AbuseipdbUnloaded => "AbuseIPDB",
+        ActiveBlock::GreynoiseUnloaded => "GreyNoise",
+        ActiveBlock::SecurityTrailsUnloaded => "SecurityTrails",
+        ActiveBlock::UrlscanUnloaded => "urlscan.io",
+        ActiveBlock::OtxUnloaded => "OTX",
         _ => "",
     };
 
@@ -0,0 +1,208 @@
+use super::super::app::{ActiveBlock, App};
+use crate::ui::util::get_percentage_width;
+use crate::ui::{draw_table, TableHeader, TableHeaderItem, TableItem};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+pub fn draw_otx<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+        .split(layout_chunk);
+
+    let top_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(25), Constraint::Percentage(75)].as_ref())
+        .split(chunks[0]);
+
+    let bottom_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
+        .split(chunks[1]);
+
+    draw_otx_summary(f, app, top_chunks[0]);
+    draw_otx_pulses(f, app, top_chunks[1]);
+    draw_otx_pulse_description(f, app, bottom_chunks[0]);
+    draw_otx_pulse_indicators(f, app, bottom_chunks[1]);
+}
+
+pub fn draw_otx_summary<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let rows = app.otx.indicator.summary_to_vec().into_iter().map(|i| {
+        Row::new(i.into_iter().map(Cell::from))
+            .style(Style::default().add_modifier(Modifier::BOLD))
+            .bottom_margin(1)
+    });
+
+    let summary = Table::new(rows)
+        .header(Row::new(vec!["", ""]))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("👽 AlienVault OTX")
+                .border_type(BorderType::Plain),
+        )
+        .widths(&[Constraint::Length(14), Constraint::Percentage(100)]);
+
+    f.render_widget(summary, layout_chunk);
+}
+
+pub fn draw_otx_pulses<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "Pulse",
+                width: get_percentage_width(layout_chunk.width, 0.3),
+            },
+            TableHeaderItem {
+                text: "Author",
+                width: get_percentage_width(layout_chunk.width, 0.12),
+            },
+            TableHeaderItem {
+                text: "Adversary",
+                width: get_percentage_width(layout_chunk.width, 0.12),
+            },
+            TableHeaderItem {
+                text: "TLP",
+                width: get_percentage_width(layout_chunk.width, 0.06),
+            },
+            TableHeaderItem {
+                text: "Created",
+                width: get_percentage_width(layout_chunk.width, 0.1),
+            },
+            TableHeaderItem {
+                text: "Tags",
+                width: get_percentage_width(layout_chunk.width, 0.3),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::OtxPulses,
+        current_route.hovered_block == ActiveBlock::OtxPulses,
+    );
+
+    let pulse_info = &app.otx.indicator.general.pulse_info;
+    let items = pulse_info
+        .pulses
+        .iter()
+        .map(|pulse| TableItem {
+            format: pulse.summary_to_vec(),
+        })
+        .collect::<Vec<TableItem>>();
+
+    let title = format!(
+        "Pulses ({} of {}), Enter to list IOCs",
+        pulse_info.pulses.len(),
+        pulse_info.count
+    );
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        (&title, &header),
+        &items,
+        app.otx.pulse_index,
+        highlight_state,
+    );
+}
+
+pub fn draw_otx_pulse_description<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let pulse = app
+        .otx
+        .indicator
+        .general
+        .pulse_info
+        .pulses
+        .get(app.otx.pulse_index);
+
+    let text = match pulse {
+        Some(pulse) if pulse.description.is_empty() => String::from("No description"),
+        Some(pulse) => pulse.description.to_owned(),
+        None => String::from("No pulses reference the indicator"),
+    };
+
+    let paragraph = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("Description")
+                .border_type(BorderType::Plain),
+        )
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(paragraph, layout_chunk);
+}
+
+pub fn draw_otx_pulse_indicators<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "Type",
+                width: get_percentage_width(layout_chunk.width, 0.2),
+            },
+            TableHeaderItem {
+                text: "Indicator",
+                width: get_percentage_width(layout_chunk.width, 0.6),
+            },
+            TableHeaderItem {
+                text: "Created",
+                width: get_percentage_width(layout_chunk.width, 0.2),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::OtxPulseIndicators,
+        current_route.hovered_block == ActiveBlock::OtxPulseIndicators,
+    );
+
+    let indicators = &app.otx.pulse_indicators;
+    let items = indicators
+        .results
+        .iter()
+        .map(|indicator| TableItem {
+            format: indicator.summary_to_vec(),
+        })
+        .collect::<Vec<TableItem>>();
+
+    let title = format!(
+        "IOCs ({} of {}), Enter to look up",
+        indicators.results.len(),
+        indicators.count
+    );
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        (&title, &header),
+        &items,
+        app.otx.pulse_indicator_index,
+        highlight_state,
+    );
+}
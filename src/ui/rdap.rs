@@ -0,0 +1,60 @@
+use super::super::app::{ActiveBlock, App, ResultStatus};
+use crate::ui::util::get_percentage_width;
+use crate::ui::{draw_table, TableHeader, TableHeaderItem, TableItem};
+use tui::{backend::Backend, layout::Rect, Frame};
+
+pub fn draw_rdap<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "",
+                width: get_percentage_width(layout_chunk.width, 0.25),
+            },
+            TableHeaderItem {
+                text: "",
+                width: get_percentage_width(layout_chunk.width, 0.75),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::Rdap,
+        current_route.hovered_block == ActiveBlock::Rdap,
+    );
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        ("Whois Lookup (RDAP)", &header),
+        &rdap_items(app),
+        app.rdap.record_index,
+        highlight_state,
+    );
+}
+
+/// Rows of the RDAP record, or a single row explaining why there is none
+pub fn rdap_items(app: &App) -> Vec<TableItem> {
+    let message = |text: &str| {
+        vec![TableItem {
+            format: vec![text.to_string()],
+        }]
+    };
+
+    match app.rdap.status {
+        ResultStatus::Found => app
+            .rdap
+            .network
+            .summary_to_vec()
+            .into_iter()
+            .map(|format| TableItem { format })
+            .collect::<Vec<TableItem>>(),
+        ResultStatus::NotFound => message("No RDAP record found"),
+        ResultStatus::Loading => message("Loading RDAP record..."),
+        ResultStatus::NotQueried => message("RDAP lookups only cover IP addresses"),
+    }
+}
@@ -0,0 +1,509 @@
+use super::super::app::{ActiveBlock, App, ResultStatus, SEARCH_RESULT_PANELS};
+use crate::scoring;
+use crate::ui::{draw_map, util::get_color};
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, BorderType, Borders, Cell, Gauge, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+pub fn draw_search_result_page<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Min(14),
+                Constraint::Length(14),
+            ]
+            .as_ref(),
+        )
+        .split(layout_chunk);
+
+    let bottom_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
+        .split(chunks[2]);
+
+    // Known scanners and C2 servers get a badge next to the score, so those alerts can be
+    // closed or escalated at a glance
+    let badges = badges(app);
+    let mut constraints = vec![Constraint::Min(20)];
+    constraints.extend(badges.iter().map(|_| Constraint::Percentage(30)));
+    let score_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(constraints)
+        .split(chunks[0]);
+
+    let verdict = scoring::verdict(app);
+    draw_verdict_score(f, &verdict, score_chunks[0]);
+    for (badge, chunk) in badges.into_iter().zip(score_chunks.into_iter().skip(1)) {
+        draw_badge(f, badge, chunk);
+    }
+    draw_provider_panels(f, app, chunks[1]);
+    draw_mini_map(f, app, bottom_chunks[0]);
+    draw_verdict_breakdown(f, &verdict, bottom_chunks[1]);
+}
+
+fn draw_verdict_score<B>(f: &mut Frame<B>, verdict: &scoring::Verdict, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let color = match verdict.score {
+        0..=9 => Color::Green,
+        10..=39 => Color::Yellow,
+        40..=69 => Color::LightRed,
+        _ => Color::Red,
+    };
+
+    let gauge = Gauge::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("Risk Score")
+                .border_type(BorderType::Plain),
+        )
+        .gauge_style(Style::default().fg(color).bg(Color::Black))
+        .percent(u16::from(verdict.score))
+        .label(format!("{} / 100 - {}", verdict.score, verdict.label()));
+
+    f.render_widget(gauge, layout_chunk);
+}
+
+/// Provider, text and color of every badge that applies to the current lookup
+fn badges(app: &App) -> Vec<(&'static str, String, Color)> {
+    let mut badges = Vec::new();
+
+    let c2 = app
+        .abusech
+        .threatfox
+        .data
+        .iter()
+        .find(|ioc| ioc.threat_type == "botnet_cc")
+        .filter(|_| app.abusech.status == ResultStatus::Found);
+    if let Some(ioc) = c2 {
+        badges.push((
+            "ThreatFox",
+            format!("KNOWN C2 - {}", ioc.malware_printable),
+            Color::Red,
+        ));
+    }
+
+    let ip = &app.greynoise.ip;
+    if app.greynoise.status == ResultStatus::Found && (ip.noise || ip.riot) {
+        let (label, color) = match ip.classification.as_str() {
+            _ if ip.riot => ("BENIGN SERVICE", Color::Green),
+            _ if ip.is_benign() => ("BENIGN SCANNER", Color::Green),
+            "malicious" => ("MALICIOUS SCANNER", Color::Red),
+            _ => ("INTERNET SCANNER", Color::Yellow),
+        };
+        let text = match ip.name.as_str() {
+            "" | "unknown" => label.to_owned(),
+            name => format!("{} - {}", label, name),
+        };
+        badges.push(("GreyNoise", text, color));
+    }
+
+    badges
+}
+
+fn draw_badge<B>(f: &mut Frame<B>, badge: (&'static str, String, Color), layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let (title, text, color) = badge;
+
+    let badge = Paragraph::new(Span::styled(
+        text,
+        Style::default()
+            .fg(Color::Black)
+            .bg(color)
+            .add_modifier(Modifier::BOLD),
+    ))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(color))
+            .title(title)
+            .border_type(BorderType::Plain),
+    )
+    .alignment(Alignment::Center);
+
+    f.render_widget(badge, layout_chunk);
+}
+
+fn draw_verdict_breakdown<B>(f: &mut Frame<B>, verdict: &scoring::Verdict, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let rows = verdict
+        .breakdown_to_vec()
+        .into_iter()
+        .map(|row| Row::new(row).style(Style::default().add_modifier(Modifier::BOLD)));
+
+    let breakdown = Table::new(rows)
+        .header(
+            Row::new(vec!["Provider", "Signal", "Observed", "Points"])
+                .style(Style::default().fg(Color::Yellow))
+                .bottom_margin(1),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("Breakdown")
+                .border_type(BorderType::Plain),
+        )
+        .widths(&[
+            Constraint::Percentage(15),
+            Constraint::Percentage(25),
+            Constraint::Percentage(45),
+            Constraint::Percentage(15),
+        ]);
+
+    f.render_widget(breakdown, layout_chunk);
+}
+
+fn draw_provider_panels<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Ratio(1, 4),
+                Constraint::Ratio(1, 4),
+                Constraint::Ratio(1, 4),
+                Constraint::Ratio(1, 4),
+            ]
+            .as_ref(),
+        )
+        .split(layout_chunk);
+
+    for (index, (name, chunk)) in SEARCH_RESULT_PANELS.iter().zip(chunks).enumerate() {
+        let panel = panel(app, index);
+
+        let is_hovered = app.get_current_route().active_block == ActiveBlock::SearchResult
+            && app.search_result_index == index;
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(Span::styled(
+                format!("{} - Enter to open", name),
+                get_color((false, is_hovered)),
+            ))
+            .border_style(get_color((false, is_hovered)))
+            .border_type(BorderType::Plain);
+        let inner = block.inner(chunk);
+        f.render_widget(block, chunk);
+
+        let (status_text, status_color) = provider_status(panel.status, panel.configured);
+        let panel_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(1),
+                    Constraint::Min(4),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(inner);
+
+        f.render_widget(
+            Paragraph::new(Spans::from(vec![
+                Span::raw("Status: "),
+                Span::styled(status_text, Style::default().fg(status_color)),
+            ])),
+            panel_chunks[0],
+        );
+
+        if panel.status != ResultStatus::Found {
+            continue;
+        }
+
+        let rows = panel.facts.into_iter().map(|row| {
+            Row::new(row.into_iter().map(Cell::from))
+                .style(Style::default().add_modifier(Modifier::BOLD))
+        });
+        let table = Table::new(rows).widths(&[Constraint::Length(16), Constraint::Percentage(100)]);
+        f.render_widget(table, panel_chunks[1]);
+
+        match index {
+            // VirusTotal has no ports, the detection ratio takes their place
+            2 => draw_detection_gauge(f, app, panel_chunks[2]),
+            3 => {}
+            _ => {
+                let ports = match panel.ports.is_empty() {
+                    true => String::from("N/A"),
+                    false => panel.ports.join(", "),
+                };
+                let paragraph = Paragraph::new(vec![
+                    Spans::from(Span::styled(
+                        "Open Ports",
+                        Style::default().fg(Color::Yellow),
+                    )),
+                    Spans::from(Span::raw(ports)),
+                ])
+                .wrap(Wrap { trim: true });
+                f.render_widget(paragraph, panel_chunks[2]);
+            }
+        }
+    }
+}
+
+// What one of the dashboard panels shows, from the provider it summarizes
+struct Panel {
+    status: ResultStatus,
+    configured: bool,
+    facts: Vec<Vec<String>>,
+    ports: Vec<String>,
+}
+
+fn panel(app: &App, index: usize) -> Panel {
+    let (status, configured, facts, ports) = match index {
+        0 => (
+            app.censys.status,
+            !app.client_config.keys.censys_secret.is_empty(),
+            app.censys.search_ip_items.summary_to_vec(),
+            app.censys
+                .search_ip_items
+                .result
+                .services
+                .iter()
+                .map(|service| {
+                    format!(
+                        "{}/{}",
+                        service.port.unwrap_or_default(),
+                        service.service_name.as_deref().unwrap_or("unknown")
+                    )
+                })
+                .collect::<Vec<String>>(),
+        ),
+        1 => (
+            app.shodan.status,
+            !app.client_config.keys.shodan.is_empty(),
+            app.shodan.search_ip_items.summary_to_vec(),
+            app.shodan
+                .search_ip_items
+                .data
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .map(|service| {
+                    format!(
+                        "{}/{}",
+                        service.port,
+                        service.transport.as_deref().unwrap_or("tcp")
+                    )
+                })
+                .collect::<Vec<String>>(),
+        ),
+        2 => (
+            app.virustotal.status,
+            !app.client_config.keys.virustotal.is_empty(),
+            app.virustotal
+                .ip_whois_items
+                .data
+                .attributes
+                .details_to_vec(),
+            vec![],
+        ),
+        _ => (
+            app.geoip.status,
+            app.client_config.geoip.is_enabled(),
+            app.geoip.record.summary_to_vec(),
+            vec![],
+        ),
+    };
+
+    Panel {
+        status,
+        configured,
+        facts,
+        ports,
+    }
+}
+
+fn provider_status(status: ResultStatus, configured: bool) -> (String, Color) {
+    match status {
+        _ if !configured => (String::from("Not configured"), Color::Gray),
+        ResultStatus::Found => (String::from("Found"), Color::Green),
+        ResultStatus::NotFound => (String::from("Not found"), Color::LightRed),
+        ResultStatus::Loading => (String::from("Loading..."), Color::Yellow),
+        ResultStatus::NotQueried => (String::from("Not queried"), Color::Gray),
+    }
+}
+
+fn draw_detection_gauge<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let stats = &app
+        .virustotal
+        .ip_whois_items
+        .data
+        .attributes
+        .last_analysis_stats;
+    let flagged = stats.malicious + stats.suspicious;
+    let total = flagged + stats.harmless + stats.undetected;
+
+    let ratio = match total {
+        0 => 0.0,
+        total => f64::from(flagged) / f64::from(total),
+    };
+    let color = match flagged {
+        0 => Color::Green,
+        _ => Color::Red,
+    };
+
+    let gauge = Gauge::default()
+        .block(Block::default().title("Detections"))
+        .gauge_style(Style::default().fg(color).bg(Color::Black))
+        .ratio(ratio)
+        .label(format!("{} / {} engines", flagged, total));
+
+    f.render_widget(gauge, layout_chunk);
+}
+
+fn draw_mini_map<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let censys_coordinates = app
+        .censys
+        .search_ip_items
+        .result
+        .location
+        .coordinates
+        .as_ref()
+        .filter(|_| app.censys.status == ResultStatus::Found)
+        .map(|coordinates| (coordinates.latitude, coordinates.longitude));
+
+    let shodan_coordinates = Some((
+        app.shodan.search_ip_items.latitude,
+        app.shodan.search_ip_items.longitude,
+    ))
+    .filter(|_| app.shodan.status == ResultStatus::Found);
+
+    // The local databases are the fallback when no provider returned a location
+    let geoip_coordinates = app
+        .geoip
+        .record
+        .coordinates()
+        .filter(|_| app.geoip.status == ResultStatus::Found);
+
+    match censys_coordinates
+        .or(shodan_coordinates)
+        .or(geoip_coordinates)
+    {
+        Some((lat, long)) => draw_map(f, lat, long, layout_chunk),
+        None => {
+            let paragraph = Paragraph::new("No location available")
+                .block(Block::default().title("Geo Lookup").borders(Borders::ALL))
+                .alignment(Alignment::Center);
+            f.render_widget(paragraph, layout_chunk);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::threatfox::ThreatFoxIoc;
+
+    // Every dashboard provider configured and found, with a C2 hit and a GreyNoise scanner
+    fn found_everywhere() -> App {
+        let mut app = App::default();
+        app.client_config.keys.censys_secret = String::from("secret");
+        app.client_config.keys.shodan = String::from("key");
+        app.client_config.keys.virustotal = String::from("key");
+        app.client_config.geoip.city_database = String::from("GeoLite2-City.mmdb");
+
+        app.censys.status = ResultStatus::Found;
+        app.shodan.status = ResultStatus::Found;
+        app.virustotal.status = ResultStatus::Found;
+        app.geoip.status = ResultStatus::Found;
+
+        app.abusech.status = ResultStatus::Found;
+        app.abusech.threatfox.data = vec![ThreatFoxIoc {
+            threat_type: String::from("botnet_cc"),
+            malware_printable: String::from("Cobalt Strike"),
+            ..Default::default()
+        }];
+        app.greynoise.status = ResultStatus::Found;
+        app.greynoise.ip.noise = true;
+        app.greynoise.ip.classification = String::from("malicious");
+        app
+    }
+
+    fn statuses(app: &App) -> Vec<String> {
+        (0..SEARCH_RESULT_PANELS.len())
+            .map(|index| {
+                let panel = panel(app, index);
+                provider_status(panel.status, panel.configured).0
+            })
+            .collect()
+    }
+
+    #[test]
+    fn panels_summarize_found_providers() {
+        let app = found_everywhere();
+
+        assert_eq!(statuses(&app), vec!["Found"; 4]);
+        assert_eq!(
+            badges(&app)
+                .into_iter()
+                .map(|(provider, text, _)| format!("{}: {}", provider, text))
+                .collect::<Vec<String>>(),
+            vec![
+                "ThreatFox: KNOWN C2 - Cobalt Strike",
+                "GreyNoise: MALICIOUS SCANNER"
+            ]
+        );
+    }
+
+    #[test]
+    fn panels_drop_results_of_the_previous_search() {
+        let mut app = found_everywhere();
+
+        // A new search resets every provider before its own lookups are dispatched
+        app.reset_results();
+        assert_eq!(statuses(&app), vec!["Not queried"; 4]);
+        assert!(badges(&app).is_empty());
+
+        app.censys.status = ResultStatus::Loading;
+        app.shodan.status = ResultStatus::Loading;
+        app.virustotal.status = ResultStatus::Loading;
+        app.geoip.status = ResultStatus::Loading;
+        assert_eq!(statuses(&app), vec!["Loading..."; 4]);
+
+        // The first answer must not end the wait of the providers still running
+        app.is_loading = false;
+        app.shodan.status = ResultStatus::NotFound;
+        assert_eq!(
+            statuses(&app),
+            vec!["Loading...", "Not found", "Loading...", "Loading..."]
+        );
+    }
+
+    #[test]
+    fn panels_without_a_key_are_not_configured() {
+        let mut app = found_everywhere();
+        app.client_config.keys.shodan.clear();
+        app.client_config.geoip.city_database.clear();
+
+        assert_eq!(
+            statuses(&app),
+            vec!["Found", "Not configured", "Found", "Not configured"]
+        );
+    }
+}
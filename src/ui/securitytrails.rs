@@ -0,0 +1,129 @@
+use super::super::app::{ActiveBlock, App};
+use crate::ui::util::get_percentage_width;
+use crate::ui::{draw_table, TableHeader, TableHeaderItem, TableItem};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    Frame,
+};
+
+pub fn draw_securitytrails<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(45), Constraint::Percentage(55)].as_ref())
+        .split(layout_chunk);
+
+    draw_securitytrails_history(f, app, chunks[0]);
+    draw_securitytrails_whois(f, app, chunks[1]);
+}
+
+pub fn draw_securitytrails_history<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "Value",
+                width: get_percentage_width(layout_chunk.width, 0.35),
+            },
+            TableHeaderItem {
+                text: "First Seen",
+                width: get_percentage_width(layout_chunk.width, 0.15),
+            },
+            TableHeaderItem {
+                text: "Last Seen",
+                width: get_percentage_width(layout_chunk.width, 0.15),
+            },
+            TableHeaderItem {
+                text: "Detail",
+                width: get_percentage_width(layout_chunk.width, 0.35),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::SecurityTrailsHistory,
+        current_route.hovered_block == ActiveBlock::SecurityTrailsHistory,
+    );
+
+    let items = app
+        .securitytrails
+        .entries
+        .iter()
+        .map(|entry| TableItem {
+            format: entry.summary_to_vec(),
+        })
+        .collect::<Vec<TableItem>>();
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        ("🕰 DNS History, Enter to look up", &header),
+        &items,
+        app.securitytrails.entry_index,
+        highlight_state,
+    );
+}
+
+pub fn draw_securitytrails_whois<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "Started",
+                width: get_percentage_width(layout_chunk.width, 0.12),
+            },
+            TableHeaderItem {
+                text: "Ended",
+                width: get_percentage_width(layout_chunk.width, 0.12),
+            },
+            TableHeaderItem {
+                text: "Registrar",
+                width: get_percentage_width(layout_chunk.width, 0.22),
+            },
+            TableHeaderItem {
+                text: "Registrant",
+                width: get_percentage_width(layout_chunk.width, 0.22),
+            },
+            TableHeaderItem {
+                text: "Name Servers",
+                width: get_percentage_width(layout_chunk.width, 0.32),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::SecurityTrailsWhois,
+        current_route.hovered_block == ActiveBlock::SecurityTrailsWhois,
+    );
+
+    let items = app
+        .securitytrails
+        .whois
+        .result
+        .items
+        .iter()
+        .map(|record| TableItem {
+            format: record.summary_to_vec(),
+        })
+        .collect::<Vec<TableItem>>();
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        ("WHOIS History, Enter to look up the name server", &header),
+        &items,
+        app.securitytrails.whois_index,
+        highlight_state,
+    );
+}
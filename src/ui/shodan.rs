@@ -1,14 +1,16 @@
 use super::super::app::{ActiveBlock, App, SHODAN_MENU};
+use crate::clients::shodan::{FacetBucket, ServiceData};
 use crate::ui::{
-    draw_map, draw_selectable_list, draw_table, util::get_percentage_width, TableHeader,
-    TableHeaderItem, TableItem,
+    draw_map, draw_selectable_list, draw_table,
+    util::{get_color, get_percentage_width},
+    TableHeader, TableHeaderItem, TableItem,
 };
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Span, Spans},
-    widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table, Wrap},
+    widgets::{BarChart, Block, BorderType, Borders, Cell, Paragraph, Row, Table, Wrap},
     Frame,
 };
 
@@ -94,15 +96,19 @@ where
         items: vec![
             TableHeaderItem {
                 text: "Port",
-                width: get_percentage_width(layout_chunk.width, 0.3),
+                width: get_percentage_width(layout_chunk.width, 0.2),
             },
             TableHeaderItem {
                 text: "Transport",
-                width: get_percentage_width(layout_chunk.width, 0.3),
+                width: get_percentage_width(layout_chunk.width, 0.25),
             },
             TableHeaderItem {
                 text: "Service",
-                width: get_percentage_width(layout_chunk.width, 0.3),
+                width: get_percentage_width(layout_chunk.width, 0.25),
+            },
+            TableHeaderItem {
+                text: "CVEs",
+                width: get_percentage_width(layout_chunk.width, 0.1),
             },
         ],
     };
@@ -117,9 +123,8 @@ where
         .shodan
         .search_ip_items
         .data
-        .as_ref()
-        .unwrap()
         .iter()
+        .flatten()
         .map(|services| TableItem {
             format: vec![
                 services.port.to_string(),
@@ -131,6 +136,10 @@ where
                     Some(product) => product.to_string(),
                     None => "N/A".to_string(),
                 },
+                match &services.vulns {
+                    Some(vulns) if !vulns.is_empty() => vulns.len().to_string(),
+                    _ => String::new(),
+                },
             ],
         })
         .collect::<Vec<TableItem>>();
@@ -139,7 +148,7 @@ where
         f,
         app,
         layout_chunk,
-        ("Services", &header),
+        ("Services (<Enter> for details)", &header),
         items,
         app.shodan.service_index,
         highlight_state,
@@ -229,3 +238,298 @@ where
     draw_geo_info(f, app, chunks[1]);
     draw_map(f, lat, lon, chunks[2]);
 }
+
+pub fn draw_shodan_search<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)].as_ref())
+        .split(layout_chunk);
+
+    draw_search_matches(f, app, chunks[0]);
+    draw_search_facets(f, app, chunks[1]);
+}
+
+pub fn draw_search_matches<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "IP",
+                width: get_percentage_width(layout_chunk.width, 0.2),
+            },
+            TableHeaderItem {
+                text: "Port",
+                width: get_percentage_width(layout_chunk.width, 0.1),
+            },
+            TableHeaderItem {
+                text: "Product",
+                width: get_percentage_width(layout_chunk.width, 0.2),
+            },
+            TableHeaderItem {
+                text: "Organization",
+                width: get_percentage_width(layout_chunk.width, 0.3),
+            },
+            TableHeaderItem {
+                text: "Country",
+                width: get_percentage_width(layout_chunk.width, 0.1),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::ShodanSearchResults,
+        current_route.hovered_block == ActiveBlock::ShodanSearchResults,
+    );
+
+    let search_items = &app.shodan.search_items;
+
+    let items = search_items
+        .matches
+        .iter()
+        .map(|hit| TableItem {
+            format: vec![
+                hit.ip_str.to_string(),
+                hit.port.to_string(),
+                hit.product.as_deref().unwrap_or("N/A").to_string(),
+                hit.org.as_deref().unwrap_or("N/A").to_string(),
+                hit.location
+                    .as_ref()
+                    .and_then(|location| location.country_code.clone())
+                    .unwrap_or_else(|| "N/A".to_string()),
+            ],
+        })
+        .collect::<Vec<TableItem>>();
+
+    let more = if (search_items.matches.len() as i64) < search_items.total {
+        format!(", '{}' to load more", app.user_config.keys.load_more)
+    } else {
+        String::new()
+    };
+    let title = format!(
+        "Shodan: {} ({} of {}{})",
+        app.shodan.search_query,
+        search_items.matches.len(),
+        search_items.total,
+        more
+    );
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        (&title, &header),
+        &items,
+        app.shodan.search_index,
+        highlight_state,
+    );
+}
+
+pub fn draw_search_facets<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Ratio(1, 3),
+                Constraint::Ratio(1, 3),
+                Constraint::Ratio(1, 3),
+            ]
+            .as_ref(),
+        )
+        .split(layout_chunk);
+
+    let facets = app.shodan.search_items.facets.clone().unwrap_or_default();
+
+    draw_facet(f, "Top Ports", &facets.port.unwrap_or_default(), chunks[0]);
+    draw_facet(
+        f,
+        "Top Countries",
+        &facets.country.unwrap_or_default(),
+        chunks[1],
+    );
+    draw_facet(
+        f,
+        "Top Organizations",
+        &facets.org.unwrap_or_default(),
+        chunks[2],
+    );
+}
+
+fn draw_facet<B>(f: &mut Frame<B>, title: &str, buckets: &[FacetBucket], layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let bar_width = 7;
+
+    // Only show as many bars as fit inside the borders
+    let max_bars = (layout_chunk.width.saturating_sub(2) / (bar_width + 1)).max(1) as usize;
+
+    let labels = buckets
+        .iter()
+        .take(max_bars)
+        .map(|bucket| bucket.label())
+        .collect::<Vec<String>>();
+    let data = buckets
+        .iter()
+        .zip(labels.iter())
+        .map(|(bucket, label)| (label.as_str(), bucket.count))
+        .collect::<Vec<(&str, u64)>>();
+
+    let chart = BarChart::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title(title)
+                .border_type(BorderType::Plain),
+        )
+        .data(&data)
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .bar_style(Style::default().fg(Color::Cyan))
+        .value_style(Style::default().fg(Color::Black).bg(Color::Cyan));
+
+    f.render_widget(chart, layout_chunk);
+}
+
+pub fn draw_shodan_service_detail<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)].as_ref())
+        .split(layout_chunk);
+
+    let right_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(14), Constraint::Min(3)].as_ref())
+        .split(chunks[1]);
+
+    let service = app
+        .shodan
+        .search_ip_items
+        .data
+        .as_ref()
+        .and_then(|data| data.get(app.shodan.service_index))
+        .cloned()
+        .unwrap_or_default();
+
+    draw_banner(f, app, &service, chunks[0]);
+    draw_service_details(f, &service, right_chunks[0]);
+    draw_service_vulns(f, app, &service, right_chunks[1]);
+}
+
+fn draw_banner<B>(f: &mut Frame<B>, app: &App, service: &ServiceData, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::ShodanServiceDetail,
+        current_route.hovered_block == ActiveBlock::ShodanServiceDetail,
+    );
+
+    // Banners with new lines need to be split and handled as a new span per line
+    let lines = service
+        .service
+        .as_deref()
+        .unwrap_or("N/A")
+        .split('\n')
+        .map(|line| Spans::from(Span::raw(line.trim_end_matches('\r').to_string())))
+        .collect::<Vec<Spans>>();
+
+    let paragraph = Paragraph::new(lines)
+        .style(Style::default().fg(app.user_config.theme.text))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(Span::styled(
+                    format!("Banner ({})", service.port),
+                    get_color(highlight_state),
+                ))
+                .border_style(get_color(highlight_state)),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((app.shodan.banner_scroll, 0));
+
+    f.render_widget(paragraph, layout_chunk);
+}
+
+fn draw_service_details<B>(f: &mut Frame<B>, service: &ServiceData, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let details_vec = service.details_to_vec();
+
+    let rows = details_vec.iter().map(|i| {
+        let cells = i.iter().map(|c| {
+            let x = c.clone();
+            Cell::from(x)
+        });
+        Row::new(cells).style(Style::default().add_modifier(Modifier::BOLD))
+    });
+
+    let details = Table::new(rows)
+        .header(Row::new(vec!["", ""]))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("Details")
+                .border_type(BorderType::Plain),
+        )
+        .widths(&[Constraint::Length(14), Constraint::Percentage(100)]);
+
+    f.render_widget(details, layout_chunk);
+}
+
+fn draw_service_vulns<B>(f: &mut Frame<B>, app: &App, service: &ServiceData, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let rows = service.sorted_vulns().into_iter().map(|(id, vuln)| {
+        let cvss = vuln.cvss();
+        let color = match cvss {
+            Some(score) if score >= 9.0 => Color::LightRed,
+            Some(score) if score >= 7.0 => Color::Red,
+            Some(score) if score >= 4.0 => Color::Yellow,
+            _ => app.user_config.theme.text,
+        };
+
+        Row::new(vec![
+            Cell::from(id),
+            Cell::from(cvss.map_or(String::from("N/A"), |score| format!("{:.1}", score))),
+            Cell::from(vuln.summary.unwrap_or_default()),
+        ])
+        .style(Style::default().fg(color))
+    });
+
+    let vulns = Table::new(rows)
+        .header(
+            Row::new(vec!["CVE", "CVSS", "Summary"])
+                .style(Style::default().fg(app.user_config.theme.header)),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("Vulnerabilities")
+                .border_type(BorderType::Plain),
+        )
+        .widths(&[
+            Constraint::Length(16),
+            Constraint::Length(5),
+            Constraint::Percentage(100),
+        ]);
+
+    f.render_widget(vulns, layout_chunk);
+}
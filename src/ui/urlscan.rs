@@ -0,0 +1,263 @@
+use super::super::app::{ActiveBlock, App};
+use crate::ui::util::get_percentage_width;
+use crate::ui::{draw_table, TableHeader, TableHeaderItem, TableItem};
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, BorderType, Borders, Cell, Clear, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+pub fn draw_urlscan<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
+        .split(layout_chunk);
+
+    draw_urlscan_scans(f, app, chunks[0]);
+
+    match &app.urlscan.message {
+        Some(message) => draw_urlscan_message(f, message, chunks[1]),
+        None => draw_urlscan_result(f, app, chunks[1]),
+    }
+
+    if app.urlscan.confirm_submit {
+        draw_urlscan_confirm(f, app, layout_chunk);
+    }
+}
+
+pub fn draw_urlscan_scans<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "Time",
+                width: get_percentage_width(layout_chunk.width, 0.15),
+            },
+            TableHeaderItem {
+                text: "URL",
+                width: get_percentage_width(layout_chunk.width, 0.45),
+            },
+            TableHeaderItem {
+                text: "Verdict",
+                width: get_percentage_width(layout_chunk.width, 0.12),
+            },
+            TableHeaderItem {
+                text: "Screenshot",
+                width: get_percentage_width(layout_chunk.width, 0.08),
+            },
+            TableHeaderItem {
+                text: "ASN",
+                width: get_percentage_width(layout_chunk.width, 0.2),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::UrlscanScans,
+        current_route.hovered_block == ActiveBlock::UrlscanScans,
+    );
+
+    let items = app
+        .urlscan
+        .search
+        .results
+        .iter()
+        .map(|scan| TableItem {
+            format: scan.summary_to_vec(),
+        })
+        .collect::<Vec<TableItem>>();
+
+    let title = format!(
+        "🔍 urlscan.io ({} of {}), Enter to open, '{}' to submit a new scan",
+        items.len(),
+        app.urlscan.search.total,
+        app.user_config.keys.submit_scan
+    );
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        (&title, &header),
+        &items,
+        app.urlscan.scan_index,
+        highlight_state,
+    );
+}
+
+fn draw_urlscan_message<B>(f: &mut Frame<B>, message: &str, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let paragraph = Paragraph::new(message)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("Result")
+                .border_type(BorderType::Plain),
+        )
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(paragraph, layout_chunk);
+}
+
+pub fn draw_urlscan_result<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let result = match &app.urlscan.result {
+        Some(result) => result,
+        None => {
+            draw_urlscan_message(f, "Press Enter on a scan to load its result", layout_chunk);
+            return;
+        }
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Percentage(25),
+                Constraint::Percentage(25),
+                Constraint::Percentage(50),
+            ]
+            .as_ref(),
+        )
+        .split(layout_chunk);
+
+    let detail_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(7),
+                Constraint::Percentage(50),
+                Constraint::Percentage(50),
+            ]
+            .as_ref(),
+        )
+        .split(chunks[2]);
+
+    draw_list(f, "Domains", &result.lists.domains, chunks[0]);
+    draw_list(f, "IPs", &result.lists.ips, chunks[1]);
+
+    let rows = result.verdicts_to_vec().into_iter().map(|row| {
+        Row::new(row.into_iter().map(Cell::from))
+            .style(Style::default().add_modifier(Modifier::BOLD))
+    });
+    let verdicts = Table::new(rows)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("Verdicts")
+                .border_type(BorderType::Plain),
+        )
+        .widths(&[
+            Constraint::Length(10),
+            Constraint::Length(16),
+            Constraint::Percentage(100),
+        ]);
+    f.render_widget(verdicts, detail_chunks[0]);
+
+    let rows = result.lists.certificates.iter().map(|certificate| {
+        Row::new(certificate.summary_to_vec()).style(Style::default().add_modifier(Modifier::BOLD))
+    });
+    let certificates = Table::new(rows)
+        .header(
+            Row::new(vec!["Subject", "Issuer", "Valid From", "Valid To"])
+                .style(Style::default().fg(Color::Yellow)),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("Certificates")
+                .border_type(BorderType::Plain),
+        )
+        .widths(&[
+            Constraint::Percentage(35),
+            Constraint::Percentage(35),
+            Constraint::Percentage(15),
+            Constraint::Percentage(15),
+        ]);
+    f.render_widget(certificates, detail_chunks[1]);
+
+    let console = result
+        .data
+        .console
+        .iter()
+        .map(|entry| format!("[{}] {}", entry.message.level, entry.message.text))
+        .collect::<Vec<String>>();
+    draw_list(f, "Console", &console, detail_chunks[2]);
+}
+
+fn draw_list<B>(f: &mut Frame<B>, title: &str, items: &[String], layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let text = items
+        .iter()
+        .map(|item| Spans::from(Span::raw(item.to_owned())))
+        .collect::<Vec<Spans>>();
+
+    let paragraph = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title(format!("{} ({})", title, items.len()))
+                .border_type(BorderType::Plain),
+        )
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(paragraph, layout_chunk);
+}
+
+fn draw_urlscan_confirm<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let width = layout_chunk.width.min(70);
+    let area = Rect {
+        x: layout_chunk.x + (layout_chunk.width - width) / 2,
+        y: layout_chunk.y + layout_chunk.height.saturating_sub(5) / 2,
+        width,
+        height: layout_chunk.height.min(5),
+    };
+
+    let text = vec![
+        Spans::from(Span::raw(format!(
+            "Submit {} as a {} scan?",
+            app.urlscan.indicator, app.client_config.urlscan.visibility
+        ))),
+        Spans::from(Span::styled(
+            "Press 'y' to confirm, any other key to cancel",
+            Style::default().fg(Color::Yellow),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("urlscan.io")
+                .border_type(BorderType::Plain),
+        )
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
@@ -1,3 +1,4 @@
+use crate::watchlist::{ListKind, Severity, WatchlistEntry};
 use tui::style::{Color, Style};
 
 pub fn get_color((is_active, is_hovered): (bool, bool)) -> Style {
@@ -14,3 +15,13 @@ pub fn get_percentage_width(width: u16, percentage: f32) -> u16 {
     let width = width - padding;
     (f32::from(width) * percentage) as u16
 }
+
+// Our own infrastructure is reassuring, watchlist entries get louder with their severity
+pub fn get_watchlist_color(entry: &WatchlistEntry) -> Color {
+    match (entry.kind, entry.severity) {
+        (ListKind::Ours, _) => Color::Green,
+        (ListKind::Watch, Severity::Critical | Severity::High) => Color::Red,
+        (ListKind::Watch, Severity::Medium) => Color::Yellow,
+        (ListKind::Watch, Severity::Low | Severity::Info) => Color::Blue,
+    }
+}
@@ -1,5 +1,6 @@
 use super::super::app::{ActiveBlock, App, VIRUSTOTAL_MENU};
 use crate::clients::virustotal::AnalysisResult;
+use crate::ui::rdap::rdap_items;
 use crate::ui::util::{get_color, get_percentage_width};
 use crate::ui::{draw_selectable_list, draw_table, TableHeader, TableHeaderItem, TableItem};
 use chrono::DateTime;
@@ -8,7 +9,7 @@ use tui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Span, Spans},
-    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table, Wrap},
     Frame,
 };
 
@@ -152,7 +153,10 @@ where
         .collect();
 
     for pair in tmp_res.iter() {
-        if !pair.result.contains("clean") && !pair.result.contains("unrated") {
+        if !pair.result.is_empty()
+            && !pair.result.contains("clean")
+            && !pair.result.contains("unrated")
+        {
             results.insert(0, pair.to_owned().clone());
         } else {
             results.push(pair.to_owned().clone())
@@ -171,6 +175,8 @@ where
                     "unrated" => {
                         format!("❔ {}", scan.result)
                     }
+                    // Files report no result at all for engines that didn't detect anything
+                    "" => "✅ undetected".to_string(),
                     _ => {
                         format!("❗️ {}", scan.result)
                     }
@@ -200,34 +206,141 @@ where
         .split(layout_chunk);
 
     draw_virustotal_menu(f, app, chunks[0]);
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(45), Constraint::Percentage(55)].as_ref())
+        .split(chunks[1]);
+
+    let attribute_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(13), Constraint::Min(10)].as_ref())
+        .split(chunks[0]);
+
+    draw_virustotal_attributes(f, app, attribute_chunks[0]);
+    draw_virustotal_https_certificate(f, app, attribute_chunks[1]);
     draw_virustotal_whois_lookup(f, app, chunks[1]);
 }
 
+fn draw_key_value_table<B>(
+    f: &mut Frame<B>,
+    title: &str,
+    items: Vec<Vec<String>>,
+    layout_chunk: Rect,
+) where
+    B: Backend,
+{
+    let rows = items.into_iter().map(|i| {
+        let cells = i.into_iter().map(Cell::from);
+        Row::new(cells).style(Style::default().add_modifier(Modifier::BOLD))
+    });
+
+    let table = Table::new(rows)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title(title)
+                .border_type(BorderType::Plain),
+        )
+        .widths(&[Constraint::Length(14), Constraint::Percentage(100)]);
+
+    f.render_widget(table, layout_chunk);
+}
+
+pub fn draw_virustotal_attributes<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let attributes = &app.virustotal.ip_whois_items.data.attributes;
+
+    draw_key_value_table(
+        f,
+        "🌐 Attributes",
+        attributes.details_to_vec(),
+        layout_chunk,
+    );
+}
+
+pub fn draw_virustotal_https_certificate<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let attributes = &app.virustotal.ip_whois_items.data.attributes;
+
+    match &attributes.last_https_certificate {
+        Some(certificate) => draw_key_value_table(
+            f,
+            "🔒 Last HTTPS Certificate",
+            certificate.summary_to_vec(attributes.last_https_certificate_date),
+            layout_chunk,
+        ),
+        None => {
+            let paragraph = Paragraph::new("No HTTPS certificate observed")
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .style(Style::default().fg(Color::White))
+                        .title("🔒 Last HTTPS Certificate")
+                        .border_type(BorderType::Plain),
+                )
+                .alignment(Alignment::Center);
+
+            f.render_widget(paragraph, layout_chunk);
+        }
+    }
+}
+
 pub fn draw_virustotal_whois_lookup<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
 where
     B: Backend,
 {
+    // The raw text gets the full width, the RDAP record is a key/value table
+    let widths: &[f32] = match app.rdap.show_raw {
+        true => &[1.0],
+        false => &[0.25, 0.75],
+    };
     let header = TableHeader {
-        items: vec![TableHeaderItem {
-            text: "",
-            width: 100,
-        }],
+        items: widths
+            .iter()
+            .map(|&percentage| TableHeaderItem {
+                text: "",
+                width: get_percentage_width(layout_chunk.width, percentage),
+            })
+            .collect(),
     };
 
-    let items = match &app.virustotal.ip_whois_items.data.attributes.whois {
-        Some(items) => items.to_string(),
-        None => "N/A".to_string(),
+    let (title, items) = match app.rdap.show_raw {
+        true => {
+            let whois = match &app.virustotal.ip_whois_items.data.attributes.whois {
+                Some(items) => items.to_string(),
+                None => "N/A".to_string(),
+            };
+
+            let items = whois
+                .split('\n')
+                .map(|i| TableItem {
+                    format: vec![i.to_owned()],
+                })
+                .collect::<Vec<TableItem>>();
+
+            (
+                format!(
+                    "Whois Lookup (raw) - '{}' for RDAP",
+                    app.user_config.keys.toggle_raw
+                ),
+                items,
+            )
+        }
+        false => (
+            format!(
+                "Whois Lookup (RDAP) - '{}' for raw",
+                app.user_config.keys.toggle_raw
+            ),
+            rdap_items(app),
+        ),
     };
 
-    let items = items
-        .split('\n')
-        .collect::<Vec<&str>>()
-        .into_iter()
-        .map(|i| TableItem {
-            format: vec![i.to_owned()],
-        })
-        .collect::<Vec<TableItem>>();
-
     let current_route = app.get_current_route();
     let highlight_state = (
         current_route.active_block == ActiveBlock::VirustotalWhois,
@@ -238,7 +351,7 @@ where
         f,
         app,
         layout_chunk,
-        ("Whois Lookup", &header),
+        (&title, &header),
         &items,
         app.virustotal.whois_result_index,
         highlight_state,
@@ -251,56 +364,348 @@ where
 {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(10), Constraint::Percentage(90)].as_ref())
+        .constraints(
+            [
+                Constraint::Percentage(10),
+                Constraint::Percentage(45),
+                Constraint::Percentage(45),
+            ]
+            .as_ref(),
+        )
         .split(layout_chunk);
 
+    let detail_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)].as_ref())
+        .split(chunks[2]);
+
     draw_virustotal_menu(f, app, chunks[0]);
     draw_virustotal_comments(f, app, chunks[1]);
+    draw_virustotal_comment_detail(f, app, detail_chunks[0]);
+    draw_virustotal_comment_pivots(f, app, detail_chunks[1]);
+}
+
+// Convert from Epoch time to 1900-01-01 00:00:00 format
+fn format_comment_date(date: usize) -> String {
+    DateTime::from_timestamp(date.try_into().unwrap_or_default(), 0)
+        .map_or(String::from("Unknown Date"), |date| {
+            date.format("%Y-%m-%d %H:%M:%S").to_string()
+        })
+}
+
+fn comment_author(app: &App, comment_id: &str) -> String {
+    let virustotal = &app.virustotal;
+    match virustotal.comment_authors.get(comment_id) {
+        Some(author) if !author.data.id.is_empty() => author.data.id.to_string(),
+        Some(_) => String::from("Unknown"),
+        None if virustotal.comment_authors_loading.contains(comment_id) => String::from("..."),
+        None if virustotal.comment_author_errors.contains_key(comment_id) => {
+            String::from("Unavailable")
+        }
+        // Authors are only fetched for the selected comment
+        None => String::from("-"),
+    }
 }
 
 pub fn draw_virustotal_comments<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
 where
     B: Backend,
 {
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "Date",
+                width: 19,
+            },
+            TableHeaderItem {
+                text: "Author",
+                width: get_percentage_width(layout_chunk.width, 0.2),
+            },
+            TableHeaderItem {
+                text: "Comment",
+                width: get_percentage_width(layout_chunk.width, 0.4),
+            },
+            TableHeaderItem {
+                text: "Votes",
+                width: 7,
+            },
+        ],
+    };
+
     let current_route = app.get_current_route();
     let highlight_state = (
         current_route.active_block == ActiveBlock::VirustotalComments,
         current_route.hovered_block == ActiveBlock::VirustotalComments,
     );
 
-    let mut comments = Vec::new();
+    let items = app
+        .virustotal
+        .ip_comment_items
+        .data
+        .iter()
+        .map(|comment| {
+            let votes = &comment.attributes.votes;
+            TableItem {
+                format: vec![
+                    format_comment_date(comment.attributes.date),
+                    comment_author(app, &comment.id),
+                    comment
+                        .attributes
+                        .text
+                        .lines()
+                        .find(|line| !line.trim().is_empty())
+                        .unwrap_or_default()
+                        .to_string(),
+                    format!("{:+}", votes.positive - votes.negative),
+                ],
+            }
+        })
+        .collect::<Vec<TableItem>>();
 
-    for comment in app.virustotal.ip_comment_items.data.iter() {
-        // Convert from Epoch time to 1900-01-01 00:00:00 format
-        let date = DateTime::from_timestamp(comment.attributes.date.try_into().unwrap(), 0);
-        comments.push(Spans::from(vec![Span::styled(
-            format!(
-                "{}",
-                date.expect("Unknown Date").format("%Y-%m-%d %H:%M:%S")
-            ),
+    let title = match app.virustotal.ip_comment_items.next_cursor() {
+        Some(_) => format!(
+            "Comments ('{}' to load more)",
+            app.user_config.keys.load_more
+        ),
+        None => String::from("Comments"),
+    };
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        (&title, &header),
+        &items,
+        app.virustotal.comment_index,
+        highlight_state,
+    );
+}
+
+pub fn draw_virustotal_comment_detail<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::VirustotalCommentDetail,
+        current_route.hovered_block == ActiveBlock::VirustotalCommentDetail,
+    );
+
+    let mut lines = Vec::new();
+
+    if let Some(comment) = app
+        .virustotal
+        .ip_comment_items
+        .data
+        .get(app.virustotal.comment_index)
+    {
+        lines.push(Spans::from(vec![Span::styled(
+            format_comment_date(comment.attributes.date),
             Style::default().add_modifier(Modifier::BOLD),
         )]));
 
+        let author = match app.virustotal.comment_authors.get(&comment.id) {
+            Some(author) if !author.data.id.is_empty() => format!(
+                "{} · {} · reputation {}",
+                author.display_name(),
+                match author.data.attributes.status.as_str() {
+                    "" => "N/A",
+                    status => status,
+                },
+                author.data.attributes.reputation
+            ),
+            Some(_) => String::from("Unknown author"),
+            None if app.virustotal.comment_authors_loading.contains(&comment.id) => {
+                String::from("Loading author...")
+            }
+            None => match app.virustotal.comment_author_errors.get(&comment.id) {
+                Some(error) => error.to_owned(),
+                None => String::from("Unknown author"),
+            },
+        };
+        let votes = &comment.attributes.votes;
+        lines.push(Spans::from(vec![
+            Span::styled(author, Style::default().fg(app.user_config.theme.hint)),
+            Span::styled(
+                format!(
+                    "   👍 {}  👎 {}  ⚠ {}",
+                    votes.positive, votes.negative, votes.abuse
+                ),
+                Style::default().add_modifier(Modifier::DIM),
+            ),
+        ]));
+        lines.push(Spans::from(vec![Span::raw("")]));
+
         // Comments with new lines need to be split and handled as a new span per line
-        let lines = comment.attributes.text.split('\n').collect::<Vec<&str>>();
-        for line in lines.iter() {
-            comments.push(Spans::from(vec![Span::raw(line.to_string())]));
+        for line in comment.attributes.text.split('\n') {
+            lines.push(Spans::from(vec![Span::raw(line.to_string())]));
         }
-
-        // Add a space between the end of a comment and a new date
-        comments.push(Spans::from(vec![Span::raw("")]));
     }
 
-    let comment_paragraph = Paragraph::new(comments)
+    let comment_paragraph = Paragraph::new(lines)
         .style(Style::default().fg(app.user_config.theme.text))
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("Comments")
-                .style(get_color(highlight_state)),
+                .title(Span::styled("Comment", get_color(highlight_state)))
+                .border_style(get_color(highlight_state)),
         )
         .wrap(Wrap { trim: false })
         .scroll((app.virustotal.comment_scroll, 0));
 
     f.render_widget(comment_paragraph, layout_chunk);
 }
+
+pub fn draw_virustotal_comment_pivots<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::VirustotalCommentPivots,
+        current_route.hovered_block == ActiveBlock::VirustotalCommentPivots,
+    );
+
+    let pivots = app
+        .virustotal
+        .ip_comment_items
+        .data
+        .get(app.virustotal.comment_index)
+        .map(|comment| comment.indicators())
+        .unwrap_or_default()
+        .iter()
+        .map(|indicator| indicator.to_string())
+        .collect::<Vec<String>>();
+
+    draw_selectable_list(
+        f,
+        app,
+        layout_chunk,
+        "Mentioned indicators (<Enter> to pivot)",
+        &pivots,
+        highlight_state,
+        Some(app.virustotal.comment_pivot_index),
+    );
+}
+
+pub fn draw_virustotal_relations<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Percentage(10),
+                Constraint::Percentage(25),
+                Constraint::Percentage(65),
+            ]
+            .as_ref(),
+        )
+        .split(layout_chunk);
+
+    draw_virustotal_menu(f, app, chunks[0]);
+    draw_virustotal_relationships(f, app, chunks[1]);
+    draw_virustotal_relation_items(f, app, chunks[2]);
+}
+
+pub fn draw_virustotal_relationships<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::VirustotalRelationships,
+        current_route.hovered_block == ActiveBlock::VirustotalRelationships,
+    );
+
+    let items = app
+        .virustotal_relationships()
+        .iter()
+        .map(|relationship| {
+            match app
+                .virustotal
+                .relationships
+                .get(*relationship)
+                .and_then(|page| page.meta.as_ref())
+                .and_then(|meta| meta.count)
+            {
+                Some(count) => format!("{} ({})", relationship, count),
+                None => relationship.to_string(),
+            }
+        })
+        .collect::<Vec<String>>();
+
+    draw_selectable_list(
+        f,
+        app,
+        layout_chunk,
+        "Relationships",
+        &items,
+        highlight_state,
+        Some(app.virustotal.relationship_index),
+    );
+}
+
+pub fn draw_virustotal_relation_items<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let header = TableHeader {
+        items: vec![
+            TableHeaderItem {
+                text: "Indicator",
+                width: get_percentage_width(layout_chunk.width, 0.5),
+            },
+            TableHeaderItem {
+                text: "Details",
+                width: get_percentage_width(layout_chunk.width, 0.3),
+            },
+            TableHeaderItem {
+                text: "",
+                width: get_percentage_width(layout_chunk.width, 0.2),
+            },
+        ],
+    };
+
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::VirustotalRelationItems,
+        current_route.hovered_block == ActiveBlock::VirustotalRelationItems,
+    );
+
+    let relationship = app.virustotal_relationship();
+    let page = app
+        .virustotal
+        .relationships
+        .get(relationship)
+        .cloned()
+        .unwrap_or_default();
+
+    let items = page
+        .data
+        .iter()
+        .map(|item| TableItem {
+            format: item.summary_to_vec(&app.virustotal.indicator),
+        })
+        .collect::<Vec<TableItem>>();
+
+    let more = match page.next_cursor() {
+        Some(_) => format!(", '{}' to load more", app.user_config.keys.load_more),
+        None => String::new(),
+    };
+    let title = match app.virustotal.relationship_errors.get(relationship) {
+        Some(error) => error.to_owned(),
+        None => format!("<Enter> to pivot{}", more),
+    };
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        (&title, &header),
+        &items,
+        app.virustotal.relation_item_index,
+        highlight_state,
+    );
+}
@@ -39,9 +39,27 @@ pub struct KeyBindings {
     pub home: Key,
     pub search: Key,
     pub submit: Key,
+    pub load_more: Key,
+    pub toggle_raw: Key,
+    pub bulk_lookup: Key,
+    pub bulk_verdicts: Key,
+    pub submit_scan: Key,
     pub censys: Key,
     pub shodan: Key,
     pub virustotal: Key,
+    pub dns: Key,
+    pub geoip: Key,
+    pub rdap: Key,
+    pub abuseipdb: Key,
+    pub greynoise: Key,
+    pub otx: Key,
+    pub abusech: Key,
+    pub crtsh: Key,
+    pub securitytrails: Key,
+    pub urlscan: Key,
+    pub asn: Key,
+    pub extract: Key,
+    pub export: Key,
 }
 
 #[derive(Clone)]
@@ -66,8 +84,26 @@ impl UserConfig {
                 censys: Key::Char('c'),
                 search: Key::Char('/'),
                 submit: Key::Enter,
+                load_more: Key::Char('n'),
+                toggle_raw: Key::Char('r'),
+                bulk_lookup: Key::Char('e'),
+                bulk_verdicts: Key::Char('V'),
+                submit_scan: Key::Char('x'),
                 shodan: Key::Char('s'),
                 virustotal: Key::Char('v'),
+                dns: Key::Char('d'),
+                geoip: Key::Char('g'),
+                rdap: Key::Char('W'),
+                abuseipdb: Key::Char('a'),
+                greynoise: Key::Char('b'),
+                otx: Key::Char('o'),
+                abusech: Key::Char('u'),
+                crtsh: Key::Char('t'),
+                securitytrails: Key::Char('p'),
+                urlscan: Key::Char('w'),
+                asn: Key::Char('i'),
+                extract: Key::Char('f'),
+                export: Key::Char('X'),
             },
             path_to_config: None,
         }
@@ -0,0 +1,211 @@
+use crate::indicator::Indicator;
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::{fmt, fs, path::Path, sync::Arc};
+
+/// Whether a watchlist entry is our own infrastructure or something to look out for
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ListKind {
+    Ours,
+    #[default]
+    Watch,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Low,
+    #[default]
+    Medium,
+    High,
+    Critical,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let severity = match self {
+            Severity::Info => "info",
+            Severity::Low => "low",
+            Severity::Medium => "medium",
+            Severity::High => "high",
+            Severity::Critical => "critical",
+        };
+        write!(f, "{}", severity)
+    }
+}
+
+/// An address, network, domain, URL, hash or ASN from one of the user's watchlist files
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct WatchlistEntry {
+    pub value: String,
+    pub label: String,
+    #[serde(default)]
+    pub kind: ListKind,
+    #[serde(default)]
+    pub severity: Severity,
+}
+
+impl WatchlistEntry {
+    /// Text of the banner shown on indicators matching the entry, e.g. `OURS: corp VPN` or
+    /// `WATCHLIST: APT-X C2 (critical)`
+    pub fn banner(&self) -> String {
+        match self.kind {
+            ListKind::Ours => format!("OURS: {}", self.label),
+            ListKind::Watch => format!("WATCHLIST: {} ({})", self.label, self.severity),
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct WatchlistFile {
+    #[serde(default)]
+    entries: Vec<WatchlistEntry>,
+}
+
+/// Every watchlist in the config dir, matched against searched and discovered indicators
+#[derive(Clone, Default)]
+pub struct Watchlist {
+    entries: Arc<Vec<(Indicator, WatchlistEntry)>>,
+}
+
+impl Watchlist {
+    /// Reads every `.toml` file in `dir`, which does not need to exist
+    pub fn load(dir: &Path) -> Result<Watchlist> {
+        let mut entries = vec![];
+
+        if dir.is_dir() {
+            let mut paths = fs::read_dir(dir)?
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<Result<Vec<_>, _>>()?;
+            paths.sort();
+
+            for path in paths.into_iter().filter(|path| {
+                path.extension()
+                    .is_some_and(|extension| extension == "toml")
+            }) {
+                let contents = fs::read_to_string(&path)?;
+                let file: WatchlistFile = toml::from_str(&contents)
+                    .map_err(|e| anyhow!("Could not parse watchlist {}: {}", path.display(), e))?;
+
+                for entry in file.entries {
+                    let indicator = Indicator::parse(&entry.value).ok_or_else(|| {
+                        anyhow!(
+                            "Invalid value {} in watchlist {}",
+                            entry.value,
+                            path.display()
+                        )
+                    })?;
+                    entries.push((indicator, entry));
+                }
+            }
+        }
+
+        Ok(Watchlist {
+            entries: Arc::new(entries),
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entries covering `indicator`, our own infrastructure first and then by severity
+    pub fn matches(&self, indicator: &Indicator) -> Vec<&WatchlistEntry> {
+        let mut matches = self
+            .entries
+            .iter()
+            .filter(|(listed, _)| covers(listed, indicator))
+            .map(|(_, entry)| entry)
+            .collect::<Vec<&WatchlistEntry>>();
+
+        matches.sort_by(|a, b| {
+            (b.kind == ListKind::Ours)
+                .cmp(&(a.kind == ListKind::Ours))
+                .then(b.severity.cmp(&a.severity))
+        });
+        matches
+    }
+
+    /// Whether `indicator` is on a list of our own infrastructure
+    pub fn is_ours(&self, indicator: &Indicator) -> bool {
+        self.matches(indicator)
+            .iter()
+            .any(|entry| entry.kind == ListKind::Ours)
+    }
+}
+
+// Networks cover their addresses and domains their subdomains, URLs are matched by their host
+fn covers(listed: &Indicator, indicator: &Indicator) -> bool {
+    match (listed, indicator) {
+        (Indicator::Cidr(network), Indicator::Ip(ip)) => network.contains(ip),
+        (Indicator::Cidr(network), Indicator::Cidr(other)) => network.contains(other),
+        (Indicator::Domain(domain), Indicator::Domain(other)) => {
+            other == domain || other.ends_with(&format!(".{}", domain))
+        }
+        (Indicator::Url(url), Indicator::Url(other)) => url == other,
+        (listed, Indicator::Url(url)) => reqwest::Url::parse(url)
+            .ok()
+            .and_then(|url| {
+                url.host_str().map(|host| {
+                    host.trim_start_matches('[')
+                        .trim_end_matches(']')
+                        .to_owned()
+                })
+            })
+            .and_then(|host| Indicator::parse(&host))
+            .is_some_and(|host| covers(listed, &host)),
+        (listed, indicator) => listed == indicator,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_entries() {
+        let dir = std::env::temp_dir().join(format!("osintui-watchlist-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("corp.toml"),
+            r#"
+            [[entries]]
+            value = "203.0.113.0/24"
+            label = "corp VPN"
+            kind = "ours"
+            severity = "info"
+
+            [[entries]]
+            value = "evil.example"
+            label = "APT-X C2"
+            severity = "critical"
+            "#,
+        )
+        .unwrap();
+
+        let watchlist = Watchlist::load(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let banners = |value: &str| {
+            watchlist
+                .matches(&Indicator::parse(value).unwrap())
+                .iter()
+                .map(|entry| entry.banner())
+                .collect::<Vec<String>>()
+        };
+
+        assert_eq!(banners("203.0.113.7"), vec!["OURS: corp VPN"]);
+        assert_eq!(
+            banners("cdn.evil.example"),
+            vec!["WATCHLIST: APT-X C2 (critical)"]
+        );
+        assert_eq!(
+            banners("https://evil.example/gate.php"),
+            vec!["WATCHLIST: APT-X C2 (critical)"]
+        );
+        assert!(banners("notevil.example").is_empty());
+        assert!(watchlist.is_ours(&Indicator::parse("203.0.113.0/25").unwrap()));
+    }
+}